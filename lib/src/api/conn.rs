@@ -5,6 +5,8 @@ use crate::api::opt::Endpoint;
 use crate::api::ExtraFeatures;
 use crate::api::Result;
 use crate::api::Surreal;
+use crate::dbs::Chunk;
+use crate::dbs::Notification;
 use crate::opt::from_value;
 use crate::sql::Query;
 use crate::sql::Value;
@@ -76,10 +78,8 @@ pub enum Method {
 	/// Invalidates a session
 	Invalidate,
 	/// Kills a live query
-	#[doc(hidden)] // Not supported yet
 	Kill,
 	/// Starts a live query
-	#[doc(hidden)] // Not supported yet
 	Live,
 	/// Perfoms a patch update operation
 	Patch,
@@ -119,6 +119,8 @@ pub struct Param {
 	pub(crate) query: Option<(Query, BTreeMap<String, Value>)>,
 	pub(crate) other: Vec<Value>,
 	pub(crate) file: Option<PathBuf>,
+	pub(crate) notification_sender: Option<Sender<Notification>>,
+	pub(crate) chunk_sender: Option<channel::Sender<Chunk>>,
 }
 
 impl Param {
@@ -127,6 +129,8 @@ impl Param {
 			other,
 			query: None,
 			file: None,
+			notification_sender: None,
+			chunk_sender: None,
 		}
 	}
 
@@ -135,6 +139,8 @@ impl Param {
 			query: Some((query, bindings)),
 			other: Vec::new(),
 			file: None,
+			notification_sender: None,
+			chunk_sender: None,
 		}
 	}
 
@@ -143,6 +149,8 @@ impl Param {
 			query: None,
 			other: Vec::new(),
 			file: Some(file),
+			notification_sender: None,
+			chunk_sender: None,
 		}
 	}
 }
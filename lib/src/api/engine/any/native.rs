@@ -63,6 +63,7 @@ impl Connection for Any {
 				"fdb" => {
 					#[cfg(feature = "kv-fdb")]
 					{
+						features.insert(ExtraFeatures::Auth);
 						features.insert(ExtraFeatures::Backup);
 						engine::local::native::router(address, conn_tx, route_rx);
 						conn_rx.into_recv_async().await??
@@ -77,6 +78,7 @@ impl Connection for Any {
 				"mem" => {
 					#[cfg(feature = "kv-mem")]
 					{
+						features.insert(ExtraFeatures::Auth);
 						features.insert(ExtraFeatures::Backup);
 						engine::local::native::router(address, conn_tx, route_rx);
 						conn_rx.into_recv_async().await??
@@ -91,6 +93,7 @@ impl Connection for Any {
 				"file" | "rocksdb" => {
 					#[cfg(feature = "kv-rocksdb")]
 					{
+						features.insert(ExtraFeatures::Auth);
 						features.insert(ExtraFeatures::Backup);
 						engine::local::native::router(address, conn_tx, route_rx);
 						conn_rx.into_recv_async().await??
@@ -106,6 +109,7 @@ impl Connection for Any {
 				"tikv" => {
 					#[cfg(feature = "kv-tikv")]
 					{
+						features.insert(ExtraFeatures::Auth);
 						features.insert(ExtraFeatures::Backup);
 						engine::local::native::router(address, conn_tx, route_rx);
 						conn_rx.into_recv_async().await??
@@ -51,6 +51,7 @@ impl Connection for Any {
 				"fdb" => {
 					#[cfg(feature = "kv-fdb")]
 					{
+						features.insert(ExtraFeatures::Auth);
 						engine::local::wasm::router(address, conn_tx, route_rx);
 						if let Err(error) = conn_rx.into_recv_async().await? {
 							return Err(error);
@@ -66,6 +67,7 @@ impl Connection for Any {
 				"indxdb" => {
 					#[cfg(feature = "kv-indxdb")]
 					{
+						features.insert(ExtraFeatures::Auth);
 						engine::local::wasm::router(address, conn_tx, route_rx);
 						if let Err(error) = conn_rx.into_recv_async().await? {
 							return Err(error);
@@ -81,6 +83,7 @@ impl Connection for Any {
 				"mem" => {
 					#[cfg(feature = "kv-mem")]
 					{
+						features.insert(ExtraFeatures::Auth);
 						engine::local::wasm::router(address, conn_tx, route_rx);
 						if let Err(error) = conn_rx.into_recv_async().await? {
 							return Err(error);
@@ -96,6 +99,7 @@ impl Connection for Any {
 				"file" | "rocksdb" => {
 					#[cfg(feature = "kv-rocksdb")]
 					{
+						features.insert(ExtraFeatures::Auth);
 						engine::local::wasm::router(address, conn_tx, route_rx);
 						if let Err(error) = conn_rx.into_recv_async().await? {
 							return Err(error);
@@ -112,6 +116,7 @@ impl Connection for Any {
 				"tikv" => {
 					#[cfg(feature = "kv-tikv")]
 					{
+						features.insert(ExtraFeatures::Auth);
 						engine::local::wasm::router(address, conn_tx, route_rx);
 						if let Err(error) = conn_rx.into_recv_async().await? {
 							return Err(error);
@@ -43,6 +43,7 @@ use crate::api::Result;
 use crate::api::Surreal;
 #[cfg(not(target_arch = "wasm32"))]
 use crate::channel;
+use crate::dbs::Notification;
 use crate::dbs::Response;
 use crate::dbs::Session;
 use crate::kvs::Datastore;
@@ -52,9 +53,12 @@ use crate::sql::Query;
 use crate::sql::Statement;
 use crate::sql::Statements;
 use crate::sql::Strand;
+use crate::sql::Uuid;
 use crate::sql::Value;
+use flume::Sender;
 use indexmap::IndexMap;
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::mem;
 #[cfg(not(target_arch = "wasm32"))]
@@ -303,8 +307,9 @@ pub struct FDb;
 
 /// An embedded database
 ///
-/// Authentication methods (`signup`, `signin`, `authentication` and `invalidate`) are not availabe
-/// on `Db`
+/// Namespace, database and scope users can authenticate using `signup`, `signin`, `authenticate`
+/// and `invalidate`. As there is no root user in an embedded database, signing in with `Root`
+/// credentials always fails.
 #[derive(Debug, Clone)]
 pub struct Db {
 	pub(crate) method: crate::api::conn::Method,
@@ -372,6 +377,7 @@ async fn router(
 	kvs: &Datastore,
 	session: &mut Session,
 	vars: &mut BTreeMap<String, Value>,
+	live_queries: &mut HashMap<Uuid, Sender<Notification>>,
 	strict: bool,
 ) -> Result<DbResponse> {
 	let mut params = param.other;
@@ -388,8 +394,34 @@ async fn router(
 			session.db = Some(db);
 			Ok(DbResponse::Other(Value::None))
 		}
-		Method::Signin | Method::Signup | Method::Authenticate | Method::Invalidate => {
-			unreachable!()
+		Method::Signup => {
+			let credentials = match &mut params[..] {
+				[Value::Object(credentials)] => mem::take(credentials),
+				_ => unreachable!(),
+			};
+			let response = crate::iam::signup::signup(kvs, strict, session, credentials).await?;
+			Ok(DbResponse::Other(response.into()))
+		}
+		Method::Signin => {
+			let credentials = match &mut params[..] {
+				[Value::Object(credentials)] => mem::take(credentials),
+				_ => unreachable!(),
+			};
+			let response =
+				crate::iam::signin::signin(kvs, strict, None, session, credentials).await?;
+			Ok(DbResponse::Other(response.into()))
+		}
+		Method::Authenticate => {
+			let token = match &mut params[..] {
+				[Value::Strand(Strand(token))] => mem::take(token),
+				_ => unreachable!(),
+			};
+			crate::iam::verify::token(kvs, session, &token).await?;
+			Ok(DbResponse::Other(Value::None))
+		}
+		Method::Invalidate => {
+			crate::iam::clear::clear(session).await?;
+			Ok(DbResponse::Other(Value::None))
 		}
 		Method::Create => {
 			let statement = create_statement(&mut params);
@@ -434,14 +466,19 @@ async fn router(
 			Ok(DbResponse::Other(value))
 		}
 		Method::Query => {
-			let response = match param.query {
+			let (query, vars) = match param.query {
 				Some((query, mut bindings)) => {
 					let mut vars = vars.clone();
 					vars.append(&mut bindings);
-					kvs.process(query, &*session, Some(vars), strict).await?
+					(query, vars)
 				}
 				None => unreachable!(),
 			};
+			if let Some(sender) = param.chunk_sender {
+				kvs.process_stream(query, &*session, Some(vars), strict, sender).await?;
+				return Ok(DbResponse::Other(Value::None));
+			}
+			let response = kvs.process(query, &*session, Some(vars), strict).await?;
 			let response = process(response)?;
 			Ok(DbResponse::Query(response))
 		}
@@ -577,10 +614,12 @@ async fn router(
 			};
 			let mut vars = BTreeMap::new();
 			vars.insert("table".to_owned(), table);
-			let response = kvs
-				.execute("LIVE SELECT * FROM type::table($table)", &*session, Some(vars), strict)
-				.await?;
+			let response =
+				kvs.execute("LIVE SELECT * FROM $table", &*session, Some(vars), strict).await?;
 			let value = take(true, response).await?;
+			if let (Value::Uuid(id), Some(sender)) = (&value, param.notification_sender) {
+				live_queries.insert(id.clone(), sender);
+			}
 			Ok(DbResponse::Other(value))
 		}
 		Method::Kill => {
@@ -588,10 +627,12 @@ async fn router(
 				[value] => mem::take(value),
 				_ => unreachable!(),
 			};
+			if let Value::Uuid(id) = &id {
+				live_queries.remove(id);
+			}
 			let mut vars = BTreeMap::new();
 			vars.insert("id".to_owned(), id);
-			let response =
-				kvs.execute("KILL type::string($id)", &*session, Some(vars), strict).await?;
+			let response = kvs.execute("KILL $id", &*session, Some(vars), strict).await?;
 			let value = take(true, response).await?;
 			Ok(DbResponse::Other(value))
 		}
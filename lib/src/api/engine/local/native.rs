@@ -10,13 +10,16 @@ use crate::api::opt::Endpoint;
 use crate::api::ExtraFeatures;
 use crate::api::Result;
 use crate::api::Surreal;
+use crate::dbs::Notification;
 use crate::dbs::Session;
 use crate::kvs::Datastore;
 use flume::Receiver;
 use flume::Sender;
 use futures::StreamExt;
+use futures_concurrency::stream::Merge as _;
 use once_cell::sync::OnceCell;
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::future::Future;
 use std::marker::PhantomData;
@@ -24,6 +27,11 @@ use std::pin::Pin;
 use std::sync::atomic::AtomicI64;
 use std::sync::Arc;
 
+pub(crate) enum Either {
+	Request(Option<Route>),
+	Notification(Notification),
+}
+
 impl crate::api::Connection for Db {}
 
 impl Connection for Db {
@@ -50,6 +58,7 @@ impl Connection for Db {
 			conn_rx.into_recv_async().await??;
 
 			let mut features = HashSet::new();
+			features.insert(ExtraFeatures::Auth);
 			features.insert(ExtraFeatures::Backup);
 
 			Ok(Surreal {
@@ -105,7 +114,7 @@ pub(crate) fn router(
 			match Datastore::new(&path).await {
 				Ok(kvs) => {
 					let _ = conn_tx.into_send_async(Ok(())).await;
-					kvs
+					kvs.with_notifications()
 				}
 				Err(error) => {
 					let _ = conn_tx.into_send_async(Err(error.into())).await;
@@ -114,18 +123,45 @@ pub(crate) fn router(
 			}
 		};
 
-		let mut session = Session::for_kv();
+		let mut session = Session::for_kv().with_rt(true);
 		let mut vars = BTreeMap::new();
-		let mut stream = route_rx.into_stream();
+		let mut live_queries = HashMap::new();
+		// The notification channel is enabled above
+		let notifications = kvs.notifications().unwrap();
+
+		let streams =
+			(route_rx.into_stream().map(Either::Request), notifications.map(Either::Notification));
+
+		let mut merged = streams.merge();
 
-		while let Some(Some(route)) = stream.next().await {
-			match super::router(route.request, &kvs, &mut session, &mut vars, address.strict).await
-			{
-				Ok(value) => {
-					let _ = route.response.into_send_async(Ok(value)).await;
+		while let Some(either) = merged.next().await {
+			match either {
+				Either::Request(Some(route)) => {
+					match super::router(
+						route.request,
+						&kvs,
+						&mut session,
+						&mut vars,
+						&mut live_queries,
+						address.strict,
+					)
+					.await
+					{
+						Ok(value) => {
+							let _ = route.response.into_send_async(Ok(value)).await;
+						}
+						Err(error) => {
+							let _ = route.response.into_send_async(Err(error)).await;
+						}
+					}
 				}
-				Err(error) => {
-					let _ = route.response.into_send_async(Err(error)).await;
+				Either::Request(None) => {
+					break;
+				}
+				Either::Notification(notification) => {
+					if let Some(sender) = live_queries.get(&notification.id) {
+						let _ = sender.send_async(notification).await;
+					}
 				}
 			}
 		}
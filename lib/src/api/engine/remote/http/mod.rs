@@ -563,21 +563,7 @@ async fn router(
 			}
 			Ok(DbResponse::Other(Value::None))
 		}
-		Method::Live => {
-			let path = base_url.join(SQL_PATH)?;
-			let table = match &params[..] {
-				[table] => table.to_string(),
-				_ => unreachable!(),
-			};
-			let request = client
-				.post(path)
-				.headers(headers.clone())
-				.auth(auth)
-				.query(&[("table", table)])
-				.body("LIVE SELECT * FROM type::table($table)");
-			let value = take(true, request).await?;
-			Ok(DbResponse::Other(value))
-		}
+		Method::Live => Err(Error::LiveQueriesNotSupported.into()),
 		Method::Kill => {
 			let path = base_url.join(SQL_PATH)?;
 			let id = match &params[..] {
@@ -589,7 +575,7 @@ async fn router(
 				.headers(headers.clone())
 				.auth(auth)
 				.query(&[("id", id)])
-				.body("KILL type::string($id)");
+				.body("KILL $id");
 			let value = take(true, request).await?;
 			Ok(DbResponse::Other(value))
 		}
@@ -13,11 +13,15 @@ use crate::api::Connect;
 use crate::api::Response as QueryResponse;
 use crate::api::Result;
 use crate::api::Surreal;
+use crate::dbs::Notification;
 use crate::opt::IntoEndpoint;
 use crate::sql::Array;
+use crate::sql::Uuid;
 use crate::sql::Value;
+use flume::Sender;
 use futures::Stream;
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::mem;
 use std::pin::Pin;
@@ -38,6 +42,9 @@ const PING_INTERVAL: Duration = Duration::from_secs(5);
 const PING_METHOD: &str = "ping";
 const LOG: &str = "surrealdb::engine::remote::ws";
 
+/// The notification streams of the running live queries
+type LiveQueries = HashMap<Uuid, Sender<Notification>>;
+
 /// The WS scheme used to connect to `ws://` endpoints
 #[derive(Debug)]
 pub struct Ws;
@@ -181,6 +188,26 @@ pub(crate) struct Response {
 	pub(crate) content: Content,
 }
 
+/// Registers the notification stream of a live query started by the server
+fn register(content: &Content, sender: Sender<Notification>, live_queries: &mut LiveQueries) {
+	if let Content::Success(SuccessValue::Other(Value::Uuid(id))) = content {
+		live_queries.insert(id.clone(), sender);
+	}
+}
+
+/// Sends a live query notification received from the server to its stream
+async fn notify(content: Content, live_queries: &LiveQueries) {
+	if let Content::Success(SuccessValue::Other(value)) = content {
+		if let Ok(notification) = Notification::try_from(value) {
+			if let Some(sender) = live_queries.get(&notification.id) {
+				if sender.send_async(notification).await.is_err() {
+					trace!(target: LOG, "Live query stream dropped");
+				}
+			}
+		}
+	}
+}
+
 struct IntervalStream {
 	inner: Interval,
 }
@@ -6,6 +6,8 @@ use crate::api::conn::Method;
 use crate::api::conn::Param;
 use crate::api::conn::Route;
 use crate::api::conn::Router;
+use crate::api::engine::remote::ws::notify;
+use crate::api::engine::remote::ws::register;
 use crate::api::engine::remote::ws::Client;
 use crate::api::engine::remote::ws::Response;
 use crate::api::engine::remote::ws::PING_INTERVAL;
@@ -179,6 +181,7 @@ pub(crate) fn router(
 
 		let mut vars = IndexMap::new();
 		let mut replay = IndexMap::new();
+		let mut live_queries = HashMap::new();
 
 		'router: loop {
 			let (socket_sink, socket_stream) = socket.split();
@@ -190,6 +193,8 @@ pub(crate) fn router(
 					capacity => HashMap::with_capacity(capacity),
 				};
 
+				let mut live_streams = HashMap::new();
+
 				let mut interval = time::interval(PING_INTERVAL);
 				// don't bombard the server with pings if we miss some ticks
 				interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
@@ -231,6 +236,16 @@ pub(crate) fn router(
 										vars.remove(key);
 									}
 								}
+								Method::Live => {
+									if let Some(sender) = param.notification_sender {
+										live_streams.insert(id, sender);
+									}
+								}
+								Method::Kill => {
+									if let [Value::Uuid(id)] = &params[..1] {
+										live_queries.remove(id);
+									}
+								}
 								_ => {}
 							}
 							let method_str = match method {
@@ -295,6 +310,13 @@ pub(crate) fn router(
 												response.id.map(Value::convert_to_i64)
 											{
 												if let Some((method, sender)) = routes.remove(&id) {
+													if let Some(stream) = live_streams.remove(&id) {
+														register(
+															&response.content,
+															stream,
+															&mut live_queries,
+														);
+													}
 													let _res = sender
 														.into_send_async(DbResponse::from((
 															method,
@@ -302,6 +324,8 @@ pub(crate) fn router(
 														)))
 														.await;
 												}
+											} else {
+												notify(response.content, &live_queries).await;
 											}
 										}
 									}
@@ -150,6 +150,11 @@ pub enum Error {
 	/// architecture it's running on
 	#[error("The protocol or storage engine does not support authentication on this architecture")]
 	AuthNotSupported,
+
+	/// The protocol or storage engine being used does not support live queries on the
+	/// architecture it's running on
+	#[error("The protocol or storage engine does not support live queries on this architecture")]
+	LiveQueriesNotSupported,
 }
 
 #[cfg(feature = "protocol-http")]
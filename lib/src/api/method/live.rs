@@ -1,14 +1,21 @@
 use crate::api::conn::Method;
 use crate::api::conn::Param;
+use crate::api::conn::Route;
 use crate::api::conn::Router;
 use crate::api::Connection;
 use crate::api::Result;
+use crate::dbs::Notification;
 use crate::sql::Table;
 use crate::sql::Uuid;
 use crate::sql::Value;
+use flume::r#async::RecvStream;
+use futures::StreamExt;
+use std::fmt;
 use std::future::Future;
 use std::future::IntoFuture;
 use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
 
 /// A live query future
 #[derive(Debug)]
@@ -22,13 +29,80 @@ impl<'r, Client> IntoFuture for Live<'r, Client>
 where
 	Client: Connection,
 {
-	type Output = Result<Uuid>;
+	type Output = Result<Stream<'r, Client>>;
 	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + Sync + 'r>>;
 
 	fn into_future(self) -> Self::IntoFuture {
 		Box::pin(async move {
+			let router = self.router?;
+			let (tx, rx) = flume::unbounded();
+			let mut param = Param::new(vec![Value::Table(Table(self.table_name))]);
+			param.notification_sender = Some(tx);
 			let mut conn = Client::new(Method::Live);
-			conn.execute(self.router?, Param::new(vec![Value::Table(Table(self.table_name))])).await
+			let id = conn.execute(router, param).await?;
+			Ok(Stream {
+				router,
+				id,
+				rx: rx.into_stream(),
+			})
 		})
 	}
 }
+
+/// A stream of live query notifications
+///
+/// The live query is killed when the stream is dropped.
+#[must_use = "streams do nothing unless you poll them"]
+pub struct Stream<'r, C: Connection> {
+	router: &'r Router<C>,
+	id: Uuid,
+	rx: RecvStream<'static, Notification>,
+}
+
+impl<'r, C> Stream<'r, C>
+where
+	C: Connection,
+{
+	/// The id of the live query
+	pub fn id(&self) -> &Uuid {
+		&self.id
+	}
+}
+
+impl<'r, C> fmt::Debug for Stream<'r, C>
+where
+	C: Connection,
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Stream").field("id", &self.id).finish_non_exhaustive()
+	}
+}
+
+impl<'r, C> futures::Stream for Stream<'r, C>
+where
+	C: Connection,
+{
+	type Item = Notification;
+
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		self.rx.poll_next_unpin(cx)
+	}
+}
+
+impl<'r, C> Drop for Stream<'r, C>
+where
+	C: Connection,
+{
+	fn drop(&mut self) {
+		let (response, _) = flume::bounded(1);
+		let route = Route {
+			request: (
+				self.router.next_id(),
+				Method::Kill,
+				Param::new(vec![self.id.clone().into()]),
+			),
+			response,
+		};
+		let _res = self.router.sender.try_send(Some(route));
+	}
+}
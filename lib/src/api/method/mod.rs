@@ -45,13 +45,13 @@ pub use export::Export;
 pub use health::Health;
 pub use import::Import;
 pub use invalidate::Invalidate;
-#[doc(hidden)] // Not supported yet
 pub use kill::Kill;
-#[doc(hidden)] // Not supported yet
 pub use live::Live;
+pub use live::Stream;
 pub use merge::Merge;
 pub use patch::Patch;
 pub use query::Query;
+pub use query::QueryStream;
 pub use select::Select;
 pub use set::Set;
 pub use signin::Signin;
@@ -436,7 +436,7 @@ where
 	/// db.use_ns("namespace").use_db("database").await?;
 	///
 	/// // Define the login
-	/// let sql = "DEFINE LOGIN johndoe ON NAMESPACE PASSWORD 'password123'";
+	/// let sql = "DEFINE USER johndoe ON NAMESPACE PASSWORD 'password123' ROLES OWNER";
 	/// db.query(sql).await?.check()?;
 	///
 	/// // Sign a user in
@@ -472,7 +472,7 @@ where
 	/// db.use_ns("namespace").use_db("database").await?;
 	///
 	/// // Define the login
-	/// let sql = "DEFINE LOGIN johndoe ON DATABASE PASSWORD 'password123'";
+	/// let sql = "DEFINE USER johndoe ON DATABASE PASSWORD 'password123' ROLES OWNER";
 	/// db.query(sql).await?.check()?;
 	///
 	/// // Sign a user in
@@ -933,7 +933,21 @@ where
 		}
 	}
 
-	#[doc(hidden)] // Not supported yet
+	/// Kills a running live query
+	///
+	/// Live queries are also killed when their notification stream is dropped.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// # let query_id = surrealdb::sql::Uuid::new();
+	/// db.kill(query_id).await?;
+	/// # Ok(())
+	/// # }
+	/// ```
 	pub fn kill(&self, query_id: Uuid) -> Kill<C> {
 		Kill {
 			router: self.router.extract(),
@@ -941,7 +955,37 @@ where
 		}
 	}
 
-	#[doc(hidden)] // Not supported yet
+	/// Starts a live query on a table, returning a stream of notifications
+	///
+	/// # Support
+	///
+	/// Currently only supported by WS and the local engines.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use futures::StreamExt;
+	/// use surrealdb::dbs::Action;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// // Listen for changes to all records in a table
+	/// let mut stream = db.live("person").await?;
+	///
+	/// while let Some(notification) = stream.next().await {
+	///     match notification.action {
+	///         Action::Create => println!("created {}", notification.result),
+	///         Action::Update => println!("updated {}", notification.result),
+	///         Action::Delete => println!("deleted {}", notification.result),
+	///     }
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
 	pub fn live(&self, table_name: impl Into<String>) -> Live<C> {
 		Live {
 			router: self.router.extract(),
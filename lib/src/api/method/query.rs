@@ -1,3 +1,4 @@
+use crate::api::conn::DbResponse;
 use crate::api::conn::Method;
 use crate::api::conn::Param;
 use crate::api::conn::Router;
@@ -5,6 +6,7 @@ use crate::api::err::Error;
 use crate::api::opt;
 use crate::api::Connection;
 use crate::api::Result;
+use crate::dbs::Chunk;
 use crate::sql;
 use crate::sql::to_value;
 use crate::sql::Array;
@@ -13,15 +15,21 @@ use crate::sql::Statement;
 use crate::sql::Statements;
 use crate::sql::Strand;
 use crate::sql::Value;
+use futures::FutureExt;
+use futures::StreamExt;
 use indexmap::IndexMap;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::fmt;
 use std::future::Future;
 use std::future::IntoFuture;
 use std::mem;
 use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
 
 /// A query future
 #[derive(Debug)]
@@ -129,6 +137,129 @@ where
 		}
 		self
 	}
+
+	/// Executes the query, returning a stream of the records it outputs
+	///
+	/// Each item holds the position of a statement in the query, along with
+	/// a record output by that statement. When using an embedded database, the
+	/// records output by `SELECT` statements are yielded as they are produced,
+	/// instead of first being collected in memory. Remote engines yield the
+	/// records once the full response has been received.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use futures::StreamExt;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// let mut stream = db.query("SELECT * FROM user").stream().await?;
+	///
+	/// while let Some(result) = stream.next().await {
+	///     let (index, record) = result?;
+	///     println!("{index}: {record}");
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub async fn stream(self) -> Result<QueryStream> {
+		let mut statements = Vec::with_capacity(self.query.len());
+		for query in self.query {
+			statements.extend(query?);
+		}
+		let query = sql::Query(Statements(statements));
+		let (tx, rx) = channel::bounded(STREAM_CAPACITY);
+		let mut param = Param::query(query, self.bindings?);
+		param.chunk_sender = Some(tx);
+		let mut conn = C::new(Method::Query);
+		let response = conn.send(self.router?, param).await?;
+		Ok(QueryStream {
+			chunks: Some(rx),
+			response: Some(response.into_recv_async()),
+			buffer: VecDeque::new(),
+		})
+	}
+}
+
+/// The number of streamed records which can be buffered before the query waits
+const STREAM_CAPACITY: usize = 100;
+
+/// A stream of the records output by a `Surreal::query` request
+#[must_use = "streams do nothing unless you poll them"]
+pub struct QueryStream {
+	chunks: Option<channel::Receiver<Chunk>>,
+	response: Option<flume::r#async::RecvFut<'static, Result<DbResponse>>>,
+	buffer: VecDeque<Result<(usize, Value)>>,
+}
+
+impl QueryStream {
+	fn buffer(&mut self, index: usize, result: Result<Vec<Value>>) {
+		match result {
+			Ok(values) => self.buffer.extend(values.into_iter().map(|v| Ok((index, v)))),
+			Err(error) => self.buffer.push_back(Err(error)),
+		}
+	}
+}
+
+impl fmt::Debug for QueryStream {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("QueryStream").finish_non_exhaustive()
+	}
+}
+
+impl futures::Stream for QueryStream {
+	type Item = Result<(usize, Value)>;
+
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		loop {
+			// Yield any buffered records first
+			if let Some(item) = self.buffer.pop_front() {
+				return Poll::Ready(Some(item));
+			}
+			// Yield the records as they are streamed
+			if let Some(chunks) = self.chunks.as_mut() {
+				match chunks.poll_next_unpin(cx) {
+					Poll::Ready(Some(Chunk::Record(index, value))) => {
+						return Poll::Ready(Some(Ok((index, value))));
+					}
+					Poll::Ready(Some(Chunk::Response(index, response))) => {
+						let result = match response.result {
+							Ok(Value::Array(Array(array))) => Ok(array),
+							Ok(Value::None | Value::Null) => Ok(vec![]),
+							Ok(value) => Ok(vec![value]),
+							Err(error) => Err(error.into()),
+						};
+						self.buffer(index, result);
+						continue;
+					}
+					Poll::Ready(None) => self.chunks = None,
+					Poll::Pending => return Poll::Pending,
+				}
+			}
+			// Yield the records of a response which was not streamed
+			match self.response.as_mut() {
+				Some(response) => {
+					let response = match response.poll_unpin(cx) {
+						Poll::Ready(response) => response,
+						Poll::Pending => return Poll::Pending,
+					};
+					self.response = None;
+					match response {
+						Ok(Ok(DbResponse::Query(response))) => {
+							for (index, result) in response.0 {
+								self.buffer(index, result);
+							}
+						}
+						Ok(Ok(DbResponse::Other(..))) => {}
+						Ok(Err(error)) => self.buffer.push_back(Err(error)),
+						Err(error) => self.buffer.push_back(Err(error.into())),
+					}
+				}
+				None => return Poll::Ready(None),
+			}
+		}
+	}
 }
 
 pub(crate) type QueryResult = Result<Vec<Value>>;
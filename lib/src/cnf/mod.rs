@@ -3,9 +3,30 @@
 /// Specifies how many concurrent jobs can be buffered in the worker channel.
 pub const MAX_CONCURRENT_TASKS: usize = 64;
 
+/// Specifies how many query log entries can be queued before further entries are discarded.
+pub const QUERY_LOG_QUEUE_SIZE: usize = 10_000;
+
+/// Specifies how many times to retry incrementing the definitions version if it is changed concurrently.
+pub const DEFINITIONS_VERSION_RETRIES: u32 = 5;
+
+/// The publicly visible name of the server, used as the issuer of authentication tokens
+pub const SERVER_NAME: &str = "SurrealDB";
+
+/// Specifies how long, in seconds, a JSON Web Key Set is cached before it is loaded again.
+pub const JWKS_CACHE_DURATION: i64 = 3600;
+
+/// Specifies how often, in seconds, a JSON Web Key Set can be loaded again when a key is not found.
+pub const JWKS_REFRESH_INTERVAL: i64 = 10;
+
 /// Specifies how deep various forms of computation will go before the query fails.
 pub const MAX_COMPUTATION_DEPTH: u8 = 30;
 
+/// The default maximum memory size, in bytes, of the runtime for embedded script functions.
+pub const SCRIPTING_MAX_MEMORY_LIMIT: usize = 2_000_000;
+
+/// The default maximum stack size, in bytes, of the runtime for embedded script functions.
+pub const SCRIPTING_MAX_STACK_SIZE: usize = 262_144;
+
 /// Specifies the names of parameters which can not be specified in a query.
 pub const PROTECTED_PARAM_NAMES: &[&str] = &["auth", "scope", "token", "session"];
 
@@ -19,6 +19,26 @@ impl Cancellation {
 		}
 	}
 
+	/// Add a deadline. If the current deadline is sooner than the provided
+	/// deadline, this method does nothing.
+	pub fn with_deadline(mut self, deadline: Instant) -> Cancellation {
+		match self.deadline {
+			Some(current) if current < deadline => (),
+			_ => self.deadline = Some(deadline),
+		}
+		self
+	}
+
+	/// Get the deadline, if there is one.
+	pub fn deadline(&self) -> Option<Instant> {
+		self.deadline
+	}
+
+	/// Get the cancellation flags which are checked.
+	pub fn cancellations(&self) -> &[Arc<AtomicBool>] {
+		&self.cancellations
+	}
+
 	pub fn is_done(&self) -> bool {
 		self.deadline.map(|d| d <= Instant::now()).unwrap_or(false)
 			|| self.cancellations.iter().any(|c| c.load(Ordering::Relaxed))
@@ -1,6 +1,9 @@
 use crate::ctx::canceller::Canceller;
 use crate::ctx::reason::Reason;
+use crate::dbs::Chunk;
+use crate::idx::planner::executor::QueryExecutor;
 use crate::sql::value::Value;
+use channel::Sender;
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt::{self, Debug};
@@ -27,8 +30,14 @@ pub struct Context<'a> {
 	deadline: Option<Instant>,
 	// Whether or not this context is cancelled.
 	cancelled: Arc<AtomicBool>,
+	// The cancellation flags of any detached contexts this context continues.
+	cancellations: Vec<Arc<AtomicBool>>,
 	// A collection of read only values stored in this context.
 	values: HashMap<String, Cow<'a, Value>>,
+	// The results of any full-text searches run by the query planner.
+	query_executor: Option<QueryExecutor>,
+	// An optional channel for streaming the records of the current statement.
+	stream: Option<(usize, Sender<Chunk>)>,
 }
 
 impl<'a> Default for Context<'a> {
@@ -43,6 +52,7 @@ impl<'a> Debug for Context<'a> {
 			.field("parent", &self.parent)
 			.field("deadline", &self.deadline)
 			.field("cancelled", &self.cancelled)
+			.field("cancellations", &self.cancellations)
 			.field("values", &self.values)
 			.finish()
 	}
@@ -56,6 +66,9 @@ impl<'a> Context<'a> {
 			parent: None,
 			deadline: None,
 			cancelled: Arc::new(AtomicBool::new(false)),
+			cancellations: vec![],
+			query_executor: None,
+			stream: None,
 		}
 	}
 
@@ -66,6 +79,9 @@ impl<'a> Context<'a> {
 			parent: Some(parent),
 			deadline: parent.deadline,
 			cancelled: Arc::new(AtomicBool::new(false)),
+			cancellations: vec![],
+			query_executor: None,
+			stream: None,
 		}
 	}
 
@@ -85,6 +101,16 @@ impl<'a> Context<'a> {
 		}
 	}
 
+	/// Add the deadline and cancellation of a detached context to the context,
+	/// so that this context is done once the detached context is done.
+	#[cfg(feature = "scripting")]
+	pub fn add_cancellation(&mut self, cancellation: &crate::ctx::cancellation::Cancellation) {
+		if let Some(deadline) = cancellation.deadline() {
+			self.add_deadline(deadline);
+		}
+		self.cancellations.extend(cancellation.cancellations().iter().cloned());
+	}
+
 	/// Add a timeout to the context. If the current timeout is sooner than
 	/// the provided timeout, this method does nothing.
 	pub fn add_timeout(&mut self, timeout: Duration) {
@@ -100,6 +126,18 @@ impl<'a> Context<'a> {
 		self.values.insert(key, value.into());
 	}
 
+	/// Set the results of the full-text searches for the current query.
+	pub(crate) fn set_query_executor(&mut self, exe: QueryExecutor) {
+		self.query_executor = Some(exe);
+	}
+
+	/// Stream the records output by the statement at this position in the
+	/// query set. This is not inherited by any child contexts, so that the
+	/// records of any subqueries are not streamed.
+	pub(crate) fn add_stream(&mut self, idx: usize, chn: Sender<Chunk>) {
+		self.stream = Some((idx, chn));
+	}
+
 	/// Get the timeout for this operation, if any. This is useful for
 	/// checking if a long job should be started or not.
 	pub fn timeout(&self) -> Option<Duration> {
@@ -112,6 +150,9 @@ impl<'a> Context<'a> {
 		match self.deadline {
 			Some(deadline) if deadline <= Instant::now() => Some(Reason::Timedout),
 			_ if self.cancelled.load(Ordering::Relaxed) => Some(Reason::Canceled),
+			_ if self.cancellations.iter().any(|c| c.load(Ordering::Relaxed)) => {
+				Some(Reason::Canceled)
+			}
 			_ => match self.parent {
 				Some(ctx) => ctx.done(),
 				_ => None,
@@ -149,13 +190,30 @@ impl<'a> Context<'a> {
 		}
 	}
 
+	/// Get the results of the full-text searches for the current query.
+	pub(crate) fn get_query_executor(&self) -> Option<&QueryExecutor> {
+		match &self.query_executor {
+			Some(v) => Some(v),
+			None => match self.parent {
+				Some(p) => p.get_query_executor(),
+				_ => None,
+			},
+		}
+	}
+
+	/// Get the channel for streaming the records of the current statement.
+	pub(crate) fn get_stream(&self) -> Option<&(usize, Sender<Chunk>)> {
+		self.stream.as_ref()
+	}
+
 	/// Get a 'static view into the cancellation status.
 	#[cfg(feature = "scripting")]
 	pub fn cancellation(&self) -> crate::ctx::cancellation::Cancellation {
 		crate::ctx::cancellation::Cancellation::new(
 			self.deadline,
 			std::iter::successors(Some(self), |ctx| ctx.parent)
-				.map(|ctx| ctx.cancelled.clone())
+				.flat_map(|ctx| std::iter::once(&ctx.cancelled).chain(&ctx.cancellations))
+				.cloned()
 				.collect(),
 		)
 	}
@@ -45,6 +45,16 @@ impl Auth {
 	pub fn is_no(&self) -> bool {
 		self.check(Level::Sc)
 	}
+	/// Returns the name of the authentication level
+	pub(crate) fn name(&self) -> &'static str {
+		match self {
+			Auth::No => "NO",
+			Auth::Kv => "KV",
+			Auth::Ns(_) => "NS",
+			Auth::Db(_, _) => "DB",
+			Auth::Sc(_, _, _) => "SC",
+		}
+	}
 	/// Checks whether permissions clauses need to be processed
 	pub(crate) fn perms(&self) -> bool {
 		match self {
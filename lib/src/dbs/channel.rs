@@ -12,6 +12,7 @@ use crate::sql::thing::Thing;
 use crate::sql::value::Value;
 use channel::Sender;
 use std::ops::Bound;
+use std::ops::Range;
 
 impl Iterable {
 	#[allow(dead_code)]
@@ -20,7 +21,7 @@ impl Iterable {
 		ctx: &Context<'_>,
 		opt: &Options,
 		txn: &Transaction,
-		_stm: &Statement<'_>,
+		stm: &Statement<'_>,
 		chn: Sender<(Option<Thing>, Operable)>,
 	) -> Result<(), Error> {
 		if ctx.is_ok() {
@@ -35,8 +36,22 @@ impl Iterable {
 					// Check that the table exists
 					txn.lock().await.check_ns_db_tb(opt.ns(), opt.db(), &v.tb, opt.strict).await?;
 					// Fetch the data from the store
-					let key = thing::new(opt.ns(), opt.db(), &v.tb, &v.id);
-					let val = txn.clone().lock().await.get(key).await?;
+					let val = match stm.version() {
+						// Fetch the record as it was at the specified time
+						Some(ts) => {
+							let ts = ts.to_u64();
+							txn.clone()
+								.lock()
+								.await
+								.get_version(opt.ns(), opt.db(), &v.tb, &v.id, ts)
+								.await?
+						}
+						// Fetch the current version of the record
+						None => {
+							let key = thing::new(opt.ns(), opt.db(), &v.tb, &v.id);
+							txn.clone().lock().await.get(key).await?
+						}
+					};
 					// Parse the data from the store
 					let val = Operable::Value(match val {
 						Some(v) => Value::from(v),
@@ -80,6 +95,12 @@ impl Iterable {
 				Iterable::Table(v) => {
 					// Check that the table exists
 					txn.lock().await.check_ns_db_tb(opt.ns(), opt.db(), &v, opt.strict).await?;
+					// Check if this is a historical query
+					if let Some(ts) = stm.version() {
+						let beg = crate::key::version::prefix(opt.ns(), opt.db(), &v);
+						let end = crate::key::version::suffix(opt.ns(), opt.db(), &v);
+						return Self::channel_version(ctx, txn, &chn, beg..end, ts.to_u64()).await;
+					}
 					// Prepare the start and end keys
 					let beg = thing::prefix(opt.ns(), opt.db(), &v);
 					let end = thing::suffix(opt.ns(), opt.db(), &v);
@@ -140,6 +161,23 @@ impl Iterable {
 				Iterable::Range(v) => {
 					// Check that the table exists
 					txn.lock().await.check_ns_db_tb(opt.ns(), opt.db(), &v.tb, opt.strict).await?;
+					// Check if this is a historical query
+					if let Some(ts) = stm.version() {
+						let (ns, db, tb) = (opt.ns(), opt.db(), &v.tb);
+						// Prepare the version range start key
+						let beg = match &v.beg {
+							Bound::Unbounded => crate::key::version::prefix(ns, db, tb),
+							Bound::Included(id) => crate::key::version::prefix_id(ns, db, tb, id),
+							Bound::Excluded(id) => crate::key::version::suffix_id(ns, db, tb, id),
+						};
+						// Prepare the version range end key
+						let end = match &v.end {
+							Bound::Unbounded => crate::key::version::suffix(ns, db, tb),
+							Bound::Excluded(id) => crate::key::version::prefix_id(ns, db, tb, id),
+							Bound::Included(id) => crate::key::version::suffix_id(ns, db, tb, id),
+						};
+						return Self::channel_version(ctx, txn, &chn, beg..end, ts.to_u64()).await;
+					}
 					// Prepare the range start key
 					let beg = match &v.beg {
 						Bound::Unbounded => thing::prefix(opt.ns(), opt.db(), &v.tb),
@@ -220,7 +258,97 @@ impl Iterable {
 						break;
 					}
 				}
+				Iterable::Index(t, p) => {
+					// Check that the table exists
+					txn.lock().await.check_ns_db_tb(opt.ns(), opt.db(), &t, opt.strict).await?;
+					// Loop over the index key ranges
+					for (beg, end) in p.ranges().iter() {
+						// Prepare the matching record ids
+						let mut ids: Vec<Thing> = Vec::new();
+						// Prepare the next holder key
+						let mut nxt: Option<Vec<u8>> = None;
+						// Loop until no more keys
+						loop {
+							// Check if the context is finished
+							if ctx.is_done() {
+								break;
+							}
+							// Get the next 1000 key-value entries
+							let res = match nxt {
+								None => {
+									let min = beg.clone();
+									let max = end.clone();
+									txn.clone().lock().await.scan(min..max, 1000).await?
+								}
+								Some(ref mut beg) => {
+									beg.push(0x00);
+									let min = beg.clone();
+									let max = end.clone();
+									txn.clone().lock().await.scan(min..max, 1000).await?
+								}
+							};
+							// Check if all keys have been fetched
+							let done = res.is_empty();
+							// Get total results
+							let n = res.len();
+							// Loop over results
+							for (i, (k, v)) in res.into_iter().enumerate() {
+								// Ready the next
+								if n == i + 1 {
+									nxt = Some(k.clone());
+								}
+								// Parse the record id from the index
+								ids.push((&v).into());
+							}
+							// Records being modified may move within the index, so
+							// they are only processed once all keys have been fetched
+							if done || stm.is_select() {
+								for rid in ids.drain(..) {
+									// Check the context
+									if ctx.is_done() {
+										break;
+									}
+									// Fetch the data from the store
+									let key = thing::new(opt.ns(), opt.db(), &rid.tb, &rid.id);
+									let val = txn.clone().lock().await.get(key).await?;
+									// Skip records which no longer exist
+									if let Some(v) = val {
+										// Create a new operable value
+										let val = Operable::Value(Value::from(v));
+										// Process the record
+										chn.send((Some(rid), val)).await?;
+									}
+								}
+							}
+							// Exit when settled
+							if done {
+								break;
+							}
+						}
+					}
+					// Loop over the records matched by a full-text search
+					for rid in p.ids().iter() {
+						// Check the context
+						if ctx.is_done() {
+							break;
+						}
+						// Fetch the data from the store
+						let key = thing::new(opt.ns(), opt.db(), &rid.tb, &rid.id);
+						let val = txn.clone().lock().await.get(key).await?;
+						// Skip records which no longer exist
+						if let Some(v) = val {
+							// Create a new operable value
+							let val = Operable::Value(Value::from(v));
+							// Process the record
+							chn.send((Some(rid.clone()), val)).await?;
+						}
+					}
+				}
 				Iterable::Edges(e) => {
+					// Graph edges are not versioned
+					if stm.version().is_some() {
+						return Err(Error::VersionUnsupported);
+					}
 					// Pull out options
 					let ns = opt.ns();
 					let db = opt.db();
@@ -355,4 +483,40 @@ impl Iterable {
 		}
 		Ok(())
 	}
+
+	/// Sends the records in a range of versions, as they were at a specific point in time
+	async fn channel_version(
+		ctx: &Context<'_>,
+		txn: &Transaction,
+		chn: &Sender<(Option<Thing>, Operable)>,
+		rng: Range<Vec<u8>>,
+		ts: u64,
+	) -> Result<(), Error> {
+		// Prepare the next range to fetch
+		let mut rng = Some(rng);
+		// Loop until no more versions
+		while let Some(r) = rng.take() {
+			// Check if the context is finished
+			if ctx.is_done() {
+				break;
+			}
+			// Get the records from the next 1000 versions
+			let end = r.end.clone();
+			let (res, nxt) = txn.clone().lock().await.scan_version(r, ts, 1000).await?;
+			// Loop over results
+			for (rid, val) in res {
+				// Check the context
+				if ctx.is_done() {
+					break;
+				}
+				// Parse the data from the store
+				let val = Operable::Value(Value::from(val));
+				// Process the record
+				chn.send((Some(rid), val)).await?;
+			}
+			// Continue from the next record
+			rng = nxt.map(|beg| beg..end);
+		}
+		Ok(())
+	}
 }
@@ -1,18 +1,23 @@
 use crate::cnf::PROTECTED_PARAM_NAMES;
 use crate::ctx::Context;
+use crate::dbs::response::QueryType;
 use crate::dbs::response::Response;
 use crate::dbs::Auth;
+use crate::dbs::Chunk;
 use crate::dbs::Level;
 use crate::dbs::Options;
+use crate::dbs::Session;
 use crate::dbs::Transaction;
 use crate::dbs::LOG;
 use crate::err::Error;
+use crate::kvs::metrics::METRICS;
 use crate::kvs::Datastore;
 use crate::sql::paths::DB;
 use crate::sql::paths::NS;
 use crate::sql::query::Query;
 use crate::sql::statement::Statement;
 use crate::sql::value::Value;
+use channel::Sender;
 use futures::lock::Mutex;
 use std::sync::Arc;
 use tracing::instrument;
@@ -22,17 +27,29 @@ pub(crate) struct Executor<'a> {
 	err: bool,
 	kvs: &'a Datastore,
 	txn: Option<Transaction>,
+	sid: Option<&'a str>,
+	chn: Option<Sender<Chunk>>,
+	idx: usize,
 }
 
 impl<'a> Executor<'a> {
-	pub fn new(kvs: &'a Datastore) -> Executor<'a> {
+	pub fn new(kvs: &'a Datastore, sess: &'a Session) -> Executor<'a> {
 		Executor {
 			kvs,
 			txn: None,
 			err: false,
+			sid: sess.id.as_deref(),
+			chn: None,
+			idx: 0,
 		}
 	}
 
+	/// Streams the output of the query set to a channel
+	pub fn with_stream(mut self, chn: Sender<Chunk>) -> Self {
+		self.chn = Some(chn);
+		self
+	}
+
 	fn txn(&self) -> Transaction {
 		match self.txn.as_ref() {
 			Some(txn) => txn.clone(),
@@ -91,6 +108,7 @@ impl<'a> Executor<'a> {
 		Response {
 			time: v.time,
 			result: Err(Error::QueryCancelled),
+			query_type: v.query_type,
 		}
 	}
 
@@ -102,11 +120,24 @@ impl<'a> Executor<'a> {
 					Ok(_) => Err(Error::QueryNotExecuted),
 					Err(e) => Err(e),
 				},
+				query_type: v.query_type,
 			},
 			_ => v,
 		}
 	}
 
+	async fn output(&mut self, out: &mut Vec<Response>, res: Response) {
+		match &self.chn {
+			// Send the response to the stream
+			Some(chn) => {
+				let _ = chn.send(Chunk::Response(self.idx, res)).await;
+				self.idx += 1;
+			}
+			// Add the response to the output
+			None => out.push(res),
+		}
+	}
+
 	async fn set_ns(&self, ctx: &mut Context<'_>, opt: &mut Options, ns: &str) {
 		let mut session = ctx.value("session").unwrap_or(&Value::None).clone();
 		session.put(NS.as_ref(), ns.to_owned().into());
@@ -142,20 +173,27 @@ impl<'a> Executor<'a> {
 			}
 			// Get the statement start time
 			let now = Instant::now();
+			// Get the type of statement
+			let name = stm.name();
 			// Check if this is a RETURN statement
 			let clr = matches!(stm, Statement::Output(_));
+			// Check the type of statement
+			let qt = match stm {
+				Statement::Live(_) => QueryType::Live,
+				_ => QueryType::Other,
+			};
 			// Process a single statement
-			let res = match stm {
+			let res = match &stm {
 				// Specify runtime options
-				Statement::Option(mut stm) => {
+				Statement::Option(stm) => {
 					// Selected DB?
 					opt.needs(Level::Db)?;
 					// Allowed to run?
 					opt.check(Level::Db)?;
 					// Convert to uppercase
-					stm.name.0.make_ascii_uppercase();
+					let name = stm.name.0.to_ascii_uppercase();
 					// Process the option
-					opt = match stm.name.0.as_str() {
+					opt = match name.as_str() {
 						"FIELDS" => opt.fields(stm.what),
 						"EVENTS" => opt.events(stm.what),
 						"TABLES" => opt.tables(stm.what),
@@ -174,16 +212,20 @@ impl<'a> Executor<'a> {
 				// Cancel a running transaction
 				Statement::Cancel(_) => {
 					self.cancel(true).await;
-					buf = buf.into_iter().map(|v| self.buf_cancel(v)).collect();
-					out.append(&mut buf);
+					for v in std::mem::take(&mut buf) {
+						let v = self.buf_cancel(v);
+						self.output(&mut out, v).await;
+					}
 					self.txn = None;
 					continue;
 				}
 				// Commit a running transaction
 				Statement::Commit(_) => {
 					self.commit(true).await;
-					buf = buf.into_iter().map(|v| self.buf_commit(v)).collect();
-					out.append(&mut buf);
+					for v in std::mem::take(&mut buf) {
+						let v = self.buf_commit(v);
+						self.output(&mut out, v).await;
+					}
 					self.txn = None;
 					continue;
 				}
@@ -220,7 +262,7 @@ impl<'a> Executor<'a> {
 					Ok(Value::None)
 				}
 				// Process param definition statements
-				Statement::Set(mut stm) => {
+				Statement::Set(stm) => {
 					// Create a transaction
 					let loc = self.begin(stm.writeable()).await;
 					// Check the transaction
@@ -235,8 +277,7 @@ impl<'a> Executor<'a> {
 								false => stm.compute(&ctx, &opt, &self.txn(), None).await,
 								// The user tried to set a protected variable
 								true => Err(Error::InvalidParam {
-									// Move the parameter name, as we no longer need it
-									name: std::mem::take(&mut stm.name),
+									name: stm.name.to_owned(),
 								}),
 							};
 							// Check the statement
@@ -245,7 +286,7 @@ impl<'a> Executor<'a> {
 									// Check if writeable
 									let writeable = stm.writeable();
 									// Set the parameter
-									ctx.add_value(stm.name, val);
+									ctx.add_value(stm.name.to_owned(), val);
 									// Finalise transaction
 									match writeable {
 										true => self.commit(loc).await,
@@ -278,12 +319,17 @@ impl<'a> Executor<'a> {
 							true => Err(Error::TxFailure),
 							// The transaction began successfully
 							false => {
+								// Create a statement context
+								let mut ctx = Context::new(&ctx);
+								// Stream the records if not in a transaction
+								if let (Some(chn), true) = (&self.chn, loc && stm.streamable()) {
+									ctx.add_stream(self.idx, chn.clone());
+								}
 								// Process the statement
 								let res = match stm.timeout() {
 									// There is a timeout clause
 									Some(timeout) => {
 										// Set statement timeout
-										let mut ctx = Context::new(&ctx);
 										ctx.add_timeout(timeout);
 										// Process the statement
 										let res = stm.compute(&ctx, &opt, &self.txn(), None).await;
@@ -309,16 +355,26 @@ impl<'a> Executor<'a> {
 					}
 				},
 			};
+			// Get the statement end time
+			let time = now.elapsed();
+			// Record the statement
+			METRICS.statement(name, time);
+			// Log the statement
+			if let Some(log) = self.kvs.query_log() {
+				log.record(&opt, self.sid, &stm, time, &res);
+			}
 			// Produce the response
 			let res = Response {
-				// Get the statement end time
-				time: now.elapsed(),
+				// Store the statement duration
+				time,
 				// TODO: Replace with `inspect_err` once stable.
 				result: res.map_err(|e| {
 					// Mark the error.
 					self.err = true;
 					e
 				}),
+				// Store the type of statement
+				query_type: qt,
 			};
 			// Output the response
 			if self.txn.is_some() {
@@ -327,7 +383,7 @@ impl<'a> Executor<'a> {
 				}
 				buf.push(res);
 			} else {
-				out.push(res)
+				self.output(&mut out, res).await;
 			}
 		}
 		// Return responses
@@ -0,0 +1,72 @@
+use crate::dbs::Iterable;
+use crate::sql::value::Value;
+use std::collections::BTreeMap;
+
+/// Describes how the records of a SELECT statement are retrieved,
+/// which is returned instead of the results for an EXPLAIN clause
+#[derive(Default)]
+pub(super) struct Explanation(Vec<Value>);
+
+impl Explanation {
+	/// Describes the iteration strategy for each of the prepared values
+	pub(super) fn new(iterables: &[Iterable]) -> Self {
+		let mut exp = Self::default();
+		for v in iterables {
+			match v {
+				Iterable::Value(v) => exp.add("Iterate Value", "value", v.clone()),
+				Iterable::Table(t) => exp.add("Iterate Table", "table", Value::from(t.0.as_str())),
+				Iterable::Thing(t) => exp.add("Iterate Thing", "thing", t.clone().into()),
+				Iterable::Range(r) => exp.add("Iterate Range", "range", r.clone().into()),
+				Iterable::Edges(e) => exp.add("Iterate Edges", "edges", e.clone().into()),
+				Iterable::Index(t, p) => exp.push(
+					"Iterate Index",
+					map! {
+						String::from("table") => Value::from(t.0.as_str()),
+						String::from("plan") => p.explain(),
+					},
+				),
+				Iterable::Mergeable(t, _) => {
+					exp.add("Iterate Mergeable", "thing", t.clone().into())
+				}
+				Iterable::Relatable(_, t, _) => {
+					exp.add("Iterate Relatable", "thing", t.clone().into())
+				}
+			}
+		}
+		exp
+	}
+
+	/// Adds the number of records which were fetched, and the
+	/// number of those which were filtered out by the query
+	pub(super) fn fetched(&mut self, count: usize, filtered: usize) {
+		self.push(
+			"Fetch",
+			map! {
+				String::from("count") => Value::from(count),
+				String::from("filtered") => Value::from(filtered),
+			},
+		)
+	}
+
+	fn add(&mut self, operation: &str, key: &str, val: Value) {
+		self.push(
+			operation,
+			map! {
+				key.to_owned() => val,
+			},
+		)
+	}
+
+	fn push(&mut self, operation: &str, detail: BTreeMap<String, Value>) {
+		self.0.push(Value::from(map! {
+			String::from("operation") => Value::from(operation),
+			String::from("detail") => Value::from(detail),
+		}))
+	}
+}
+
+impl From<Explanation> for Value {
+	fn from(v: Explanation) -> Self {
+		v.0.into()
+	}
+}
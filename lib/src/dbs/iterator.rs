@@ -1,11 +1,14 @@
 use crate::ctx::Canceller;
 use crate::ctx::Context;
+use crate::dbs::explanation::Explanation;
+use crate::dbs::Chunk;
 use crate::dbs::Options;
 use crate::dbs::Statement;
 use crate::dbs::Transaction;
 use crate::dbs::LOG;
 use crate::doc::Document;
 use crate::err::Error;
+use crate::idx::planner::plan::Plan;
 use crate::sql::array::Array;
 use crate::sql::edges::Edges;
 use crate::sql::field::Field;
@@ -14,6 +17,7 @@ use crate::sql::table::Table;
 use crate::sql::thing::Thing;
 use crate::sql::value::Value;
 use async_recursion::async_recursion;
+use channel::Sender;
 use std::cmp::Ordering;
 use std::collections::BTreeMap;
 use std::mem;
@@ -24,6 +28,7 @@ pub(crate) enum Iterable {
 	Thing(Thing),
 	Range(Range),
 	Edges(Edges),
+	Index(Table, Plan),
 	Mergeable(Thing, Value),
 	Relatable(Thing, Thing, Thing),
 }
@@ -52,8 +57,14 @@ pub(crate) struct Iterator {
 	error: Option<Error>,
 	// Iterator output results
 	results: Vec<Value>,
+	// Iterator ignored record count
+	ignored: usize,
 	// Iterator input values
 	entries: Vec<Iterable>,
+	// Iterator output stream
+	stream: Option<(usize, Sender<Chunk>)>,
+	// Iterator streamed record count
+	streamed: usize,
 }
 
 impl Iterator {
@@ -67,6 +78,11 @@ impl Iterator {
 		self.entries.push(val)
 	}
 
+	/// Streams the output records to a channel
+	pub fn stream(&mut self, chn: (usize, Sender<Chunk>)) {
+		self.stream = Some(chn)
+	}
+
 	/// Process the records and output
 	pub async fn output(
 		&mut self,
@@ -84,6 +100,12 @@ impl Iterator {
 		self.setup_limit(&run, opt, txn, stm).await?;
 		// Process the query START clause
 		self.setup_start(&run, opt, txn, stm).await?;
+		// Process any EXPLAIN clause
+		if let Some(v) = stm.explain() {
+			return self.explain(&run, opt, txn, stm, v.0).await;
+		}
+		// Check if the output can be streamed
+		self.setup_stream(stm);
 		// Process prepared values
 		self.iterate(&run, opt, txn, stm).await?;
 		// Return any document errors
@@ -134,6 +156,39 @@ impl Iterator {
 		Ok(())
 	}
 
+	#[inline]
+	fn setup_stream(&mut self, stm: &Statement<'_>) {
+		if stm.split().is_some() || stm.group().is_some() || stm.order().is_some() {
+			self.stream = None;
+		}
+	}
+
+	#[inline]
+	async fn explain(
+		&mut self,
+		ctx: &Context<'_>,
+		opt: &Options,
+		txn: &Transaction,
+		stm: &Statement<'_>,
+		full: bool,
+	) -> Result<Value, Error> {
+		// Describe the prepared values
+		let mut exp = Explanation::new(&self.entries);
+		// Fetch the records if specified
+		if full {
+			// Process prepared values
+			self.iterate(ctx, opt, txn, stm).await?;
+			// Return any document errors
+			if let Some(e) = self.error.take() {
+				return Err(e);
+			}
+			// Count the fetched records
+			exp.fetched(self.results.len() + self.ignored, self.ignored);
+		}
+		// Output the explanation
+		Ok(exp.into())
+	}
+
 	#[inline]
 	async fn output_split(
 		&mut self,
@@ -428,6 +483,7 @@ impl Iterator {
 					// Process all processed values
 					while let Ok(r) = vals.recv().await {
 						self.result(r, stm);
+						self.flush(ctx, opt, txn, stm).await;
 					}
 					// Shutdown the executor
 					let _ = end.send(()).await;
@@ -478,6 +534,8 @@ impl Iterator {
 		};
 		// Process the result
 		self.result(res, stm);
+		// Stream the result
+		self.flush(ctx, opt, txn, stm).await;
 	}
 
 	/// Accept a processed record result
@@ -485,6 +543,7 @@ impl Iterator {
 		// Process the result
 		match res {
 			Err(Error::Ignore) => {
+				self.ignored += 1;
 				return;
 			}
 			Err(e) => {
@@ -494,17 +553,65 @@ impl Iterator {
 			}
 			Ok(v) => self.results.push(v),
 		}
+		// Count the output records
+		let count = self.results.len() + self.streamed;
 		// Check if we can exit
 		if stm.group().is_none() && stm.order().is_none() {
 			if let Some(l) = self.limit {
 				if let Some(s) = self.start {
-					if self.results.len() == l + s {
+					if count == l + s {
 						self.run.cancel()
 					}
-				} else if self.results.len() == l {
+				} else if count == l {
 					self.run.cancel()
 				}
 			}
 		}
 	}
+
+	/// Send any processed records to the output stream
+	async fn flush(
+		&mut self,
+		ctx: &Context<'_>,
+		opt: &Options,
+		txn: &Transaction,
+		stm: &Statement<'_>,
+	) {
+		// Check if the output is streamed
+		let (idx, chn) = match &self.stream {
+			Some((idx, chn)) => (*idx, chn.clone()),
+			None => return,
+		};
+		// Get the START clause value
+		let start = self.start.unwrap_or(0);
+		// Loop over the processed records
+		for mut obj in mem::take(&mut self.results) {
+			// Get the position of the record
+			let pos = self.streamed;
+			self.streamed += 1;
+			// Skip any records before the START clause
+			if pos < start {
+				continue;
+			}
+			// Skip any records after the LIMIT clause
+			if matches!(self.limit, Some(l) if pos >= l + start) {
+				continue;
+			}
+			// Process any FETCH clause
+			if let Some(fetchs) = stm.fetch() {
+				for fetch in fetchs.iter() {
+					if let Err(e) = obj.fetch(ctx, opt, txn, fetch).await {
+						self.error = Some(e);
+						self.run.cancel();
+						return;
+					}
+				}
+			}
+			// Send the record to the stream
+			if chn.send(Chunk::Record(idx, obj)).await.is_err() {
+				self.run.cancel();
+				return;
+			}
+		}
+	}
 }
@@ -1,17 +1,24 @@
 mod auth;
 mod executor;
+mod explanation;
 mod iterate;
 mod iterator;
+mod notification;
 mod options;
+mod querylog;
 mod response;
+mod script;
 mod session;
 mod statement;
 mod transaction;
 mod variables;
 
 pub use self::auth::*;
+pub use self::notification::*;
 pub use self::options::*;
+pub use self::querylog::*;
 pub use self::response::*;
+pub use self::script::*;
 pub use self::session::*;
 
 pub(crate) use self::executor::*;
@@ -0,0 +1,85 @@
+use crate::sql::uuid::Uuid;
+use crate::sql::value::Value;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A notification sent to the subscriber of a live query.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Notification {
+	/// The id of the live query which this notification is for
+	pub id: Uuid,
+	/// The type of data change which occurred
+	pub action: Action,
+	/// The record data, or the record id if the record was deleted
+	pub result: Value,
+}
+
+impl fmt::Display for Notification {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{{ id: {}, action: {}, result: {} }}", self.id, self.action, self.result)
+	}
+}
+
+impl From<Notification> for Value {
+	fn from(v: Notification) -> Self {
+		map! {
+			String::from("id") => v.id.into(),
+			String::from("action") => v.action.to_string().into(),
+			String::from("result") => v.result,
+		}
+		.into()
+	}
+}
+
+impl TryFrom<Value> for Notification {
+	type Error = Value;
+	fn try_from(v: Value) -> Result<Self, Self::Error> {
+		match v {
+			Value::Object(mut o) => match (o.remove("id"), o.remove("action")) {
+				(Some(Value::Uuid(id)), Some(Value::Strand(action))) => {
+					match Action::try_from(action.as_str()) {
+						Ok(action) => Ok(Notification {
+							id,
+							action,
+							result: o.remove("result").unwrap_or_default(),
+						}),
+						Err(_) => Err(Value::Object(o)),
+					}
+				}
+				_ => Err(Value::Object(o)),
+			},
+			v => Err(v),
+		}
+	}
+}
+
+/// The type of data change which triggered a live query notification.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, Hash)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Action {
+	Create,
+	Update,
+	Delete,
+}
+
+impl TryFrom<&str> for Action {
+	type Error = ();
+	fn try_from(v: &str) -> Result<Self, Self::Error> {
+		match v {
+			"CREATE" => Ok(Action::Create),
+			"UPDATE" => Ok(Action::Update),
+			"DELETE" => Ok(Action::Delete),
+			_ => Err(()),
+		}
+	}
+}
+
+impl fmt::Display for Action {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Action::Create => write!(f, "CREATE"),
+			Action::Update => write!(f, "UPDATE"),
+			Action::Delete => write!(f, "DELETE"),
+		}
+	}
+}
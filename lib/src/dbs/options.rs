@@ -1,7 +1,9 @@
 use crate::cnf;
 use crate::dbs::Auth;
 use crate::dbs::Level;
+use crate::dbs::ScriptOptions;
 use crate::err::Error;
+use crate::sql::role::Role;
 use std::sync::Arc;
 
 /// An Options is passed around when processing a set of query
@@ -19,6 +21,8 @@ pub struct Options {
 	pub db: Option<Arc<str>>,
 	/// Connection authentication data
 	pub auth: Arc<Auth>,
+	/// Connection authentication role
+	pub role: Role,
 	/// Approximately how large is the current call stack?
 	dive: u8,
 	/// Whether live queries are allowed?
@@ -39,6 +43,8 @@ pub struct Options {
 	pub indexes: bool,
 	/// Should we process function futures?
 	pub futures: bool,
+	/// The limits and capabilities of embedded scripts
+	pub script: Arc<ScriptOptions>,
 }
 
 impl Default for Options {
@@ -64,6 +70,8 @@ impl Options {
 			indexes: true,
 			futures: false,
 			auth: Arc::new(auth),
+			role: Role::Owner,
+			script: Arc::new(ScriptOptions::default()),
 		}
 	}
 
@@ -88,6 +96,7 @@ impl Options {
 				auth: self.auth.clone(),
 				ns: self.ns.clone(),
 				db: self.db.clone(),
+				script: self.script.clone(),
 				dive,
 				..*self
 			})
@@ -102,6 +111,7 @@ impl Options {
 			auth: self.auth.clone(),
 			ns: self.ns.clone(),
 			db: self.db.clone(),
+			script: self.script.clone(),
 			force: v,
 			..*self
 		}
@@ -113,6 +123,7 @@ impl Options {
 			auth: self.auth.clone(),
 			ns: self.ns.clone(),
 			db: self.db.clone(),
+			script: self.script.clone(),
 			perms: v,
 			..*self
 		}
@@ -124,6 +135,7 @@ impl Options {
 			auth: self.auth.clone(),
 			ns: self.ns.clone(),
 			db: self.db.clone(),
+			script: self.script.clone(),
 			fields: v,
 			..*self
 		}
@@ -135,6 +147,7 @@ impl Options {
 			auth: self.auth.clone(),
 			ns: self.ns.clone(),
 			db: self.db.clone(),
+			script: self.script.clone(),
 			events: v,
 			..*self
 		}
@@ -146,6 +159,7 @@ impl Options {
 			auth: self.auth.clone(),
 			ns: self.ns.clone(),
 			db: self.db.clone(),
+			script: self.script.clone(),
 			tables: v,
 			..*self
 		}
@@ -157,6 +171,7 @@ impl Options {
 			auth: self.auth.clone(),
 			ns: self.ns.clone(),
 			db: self.db.clone(),
+			script: self.script.clone(),
 			indexes: v,
 			..*self
 		}
@@ -168,6 +183,7 @@ impl Options {
 			auth: self.auth.clone(),
 			ns: self.ns.clone(),
 			db: self.db.clone(),
+			script: self.script.clone(),
 			fields: !v,
 			events: !v,
 			tables: !v,
@@ -181,6 +197,7 @@ impl Options {
 			auth: self.auth.clone(),
 			ns: self.ns.clone(),
 			db: self.db.clone(),
+			script: self.script.clone(),
 			strict: v,
 			..*self
 		}
@@ -192,6 +209,7 @@ impl Options {
 			auth: self.auth.clone(),
 			ns: self.ns.clone(),
 			db: self.db.clone(),
+			script: self.script.clone(),
 			futures: v,
 			..*self
 		}
@@ -213,6 +231,16 @@ impl Options {
 		Ok(())
 	}
 
+	/// Check whether the authentication permissions are ok, and
+	/// whether the authentication role allows the statement to run
+	pub fn check_role(&self, level: Level, role: Role) -> Result<(), Error> {
+		self.check(level)?;
+		if self.role < role {
+			return Err(Error::QueryPermissions);
+		}
+		Ok(())
+	}
+
 	/// Check whether the necessary NS / DB options have been set
 	pub fn needs(&self, level: Level) -> Result<(), Error> {
 		if self.ns.is_none() && matches!(level, Level::Ns | Level::Db) {
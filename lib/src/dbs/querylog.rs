@@ -0,0 +1,169 @@
+use crate::cnf::QUERY_LOG_QUEUE_SIZE;
+use crate::dbs::Options;
+use crate::err::Error;
+use crate::sql::value::Value;
+use chrono::Utc;
+use serde_json::json;
+use std::fmt;
+use std::io::{BufWriter, Write};
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// Writes executed statements to a log, as JSON lines
+///
+/// Statements are logged if they take longer than the slow query
+/// threshold, or for every statement if all statements are logged.
+/// Log entries are written by a background thread, so that queries
+/// are never blocked on the log destination. If the destination can
+/// not keep up, and the queue of log entries is full, then further
+/// log entries are discarded until the queue has space again.
+pub struct QueryLog {
+	/// Log statements which take at least this long to execute
+	slow: Option<Duration>,
+	/// Log every statement which is executed
+	all: bool,
+	/// The queue of log lines waiting to be written
+	queue: Option<SyncSender<String>>,
+	/// The background thread which writes the log lines
+	writer: Option<JoinHandle<()>>,
+}
+
+impl QueryLog {
+	/// Create a query log which writes to the specified destination
+	pub fn new<W>(out: W) -> Self
+	where
+		W: Write + Send + 'static,
+	{
+		let (queue, lines) = sync_channel(QUERY_LOG_QUEUE_SIZE);
+		QueryLog {
+			slow: None,
+			all: false,
+			queue: Some(queue),
+			writer: Some(std::thread::spawn(move || Self::write(out, lines))),
+		}
+	}
+	/// Writes the queued log lines, until the query log is dropped
+	fn write<W: Write>(out: W, lines: Receiver<String>) {
+		let mut out = BufWriter::new(out);
+		while let Ok(line) = lines.recv() {
+			let _ = writeln!(out, "{line}");
+			// Write any other queued lines before flushing
+			while let Ok(line) = lines.try_recv() {
+				let _ = writeln!(out, "{line}");
+			}
+			let _ = out.flush();
+		}
+	}
+	/// Log statements which take at least this long to execute
+	pub fn with_slow(mut self, slow: Duration) -> Self {
+		self.slow = Some(slow);
+		self
+	}
+	/// Log every statement which is executed
+	pub fn with_all(mut self, all: bool) -> Self {
+		self.all = all;
+		self
+	}
+	/// Records an executed statement, if it should be logged
+	pub(crate) fn record(
+		&self,
+		opt: &Options,
+		id: Option<&str>,
+		stm: &impl fmt::Display,
+		time: Duration,
+		res: &Result<Value, Error>,
+	) {
+		// Check if this statement was slow
+		let slow = self.slow.map_or(false, |v| time >= v);
+		// Check if this statement should be logged
+		if !slow && !self.all {
+			return;
+		}
+		// Create the log entry
+		let entry = json!({
+			"time": Utc::now().to_rfc3339(),
+			"ns": opt.ns.as_deref(),
+			"db": opt.db.as_deref(),
+			"auth": opt.auth.name(),
+			"session": id,
+			"statement": stm.to_string(),
+			"duration": time.as_secs_f64(),
+			"rows": match res {
+				Ok(Value::Array(v)) => v.len(),
+				Ok(Value::None) | Err(_) => 0,
+				Ok(_) => 1,
+			},
+			"error": res.as_ref().err().map(|e| e.to_string()),
+			"slow": slow,
+		});
+		// Queue the log entry
+		if let Some(queue) = &self.queue {
+			let _ = queue.try_send(entry.to_string());
+		}
+	}
+}
+
+impl Drop for QueryLog {
+	/// Writes any queued log lines before the query log is dropped
+	fn drop(&mut self) {
+		// Close the queue, so that the writer stops
+		self.queue.take();
+		// Wait for the queued lines to be written
+		if let Some(writer) = self.writer.take() {
+			let _ = writer.join();
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use std::sync::{Arc, Mutex};
+
+	#[derive(Clone, Default)]
+	struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+	impl Write for Buffer {
+		fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+			self.0.lock().unwrap().write(buf)
+		}
+		fn flush(&mut self) -> std::io::Result<()> {
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn record_slow_statements() {
+		let buf = Buffer::default();
+		let log = QueryLog::new(buf.clone()).with_slow(Duration::from_millis(100));
+		let opt = Options::default();
+		let res = Ok(Value::from(vec![Value::from(1), Value::from(2)]));
+		log.record(&opt, Some("abc"), &"SELECT * FROM a", Duration::from_millis(10), &res);
+		log.record(&opt, Some("abc"), &"SELECT * FROM b", Duration::from_millis(200), &res);
+		drop(log);
+		let out = String::from_utf8(buf.0.lock().unwrap().clone()).unwrap();
+		let lines: Vec<serde_json::Value> =
+			out.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+		assert_eq!(lines.len(), 1);
+		assert_eq!(lines[0]["statement"], "SELECT * FROM b");
+		assert_eq!(lines[0]["session"], "abc");
+		assert_eq!(lines[0]["auth"], "NO");
+		assert_eq!(lines[0]["rows"], 2);
+		assert_eq!(lines[0]["slow"], true);
+	}
+
+	#[test]
+	fn skip_formatting_unlogged_statements() {
+		struct Statement;
+		impl fmt::Display for Statement {
+			fn fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
+				panic!("statements which are not logged should not be formatted")
+			}
+		}
+		let log = QueryLog::new(Buffer::default()).with_slow(Duration::from_millis(100));
+		let opt = Options::default();
+		log.record(&opt, None, &Statement, Duration::from_millis(10), &Ok(Value::None));
+	}
+}
@@ -6,11 +6,22 @@ use std::time::Duration;
 
 pub(crate) const TOKEN: &str = "$surrealdb::private::sql::Response";
 
+/// The type of statement which produced a query response.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum QueryType {
+	/// The response was produced by a LIVE statement
+	Live,
+	/// The response was produced by any other statement
+	#[default]
+	Other,
+}
+
 /// The return value when running a query set on the database.
 #[derive(Debug)]
 pub struct Response {
 	pub time: Duration,
 	pub result: Result<Value, Error>,
+	pub query_type: QueryType,
 }
 
 impl Response {
@@ -47,3 +58,39 @@ impl Serialize for Response {
 		}
 	}
 }
+
+/// A part of the output of a query set, when its results are streamed.
+///
+/// The records output by a `SELECT` statement, which is not run within a
+/// transaction, and which has no `SPLIT`, `GROUP` or `ORDER` clause, are
+/// sent as they are produced, before the response of that statement. The
+/// results of all other statements are sent as part of their response.
+#[derive(Debug)]
+pub enum Chunk {
+	/// A record output by the statement at this position in the query set
+	Record(usize, Value),
+	/// The response of the statement at this position in the query set
+	Response(usize, Response),
+}
+
+impl Serialize for Chunk {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		match self {
+			Self::Record(i, v) => {
+				let mut val = serializer.serialize_struct("Chunk", 2)?;
+				val.serialize_field("query", i)?;
+				val.serialize_field("record", v)?;
+				val.end()
+			}
+			Self::Response(i, v) => {
+				let mut val = serializer.serialize_struct("Chunk", 2)?;
+				val.serialize_field("query", i)?;
+				val.serialize_field("response", v)?;
+				val.end()
+			}
+		}
+	}
+}
@@ -0,0 +1,136 @@
+use crate::cnf;
+use std::time::Duration;
+use url::Url;
+
+/// Configures the limits and capabilities of embedded script functions
+///
+/// By default scripts run with the standard memory and stack limits, have
+/// no maximum execution time, and can use the `fetch` function and import
+/// the `os` module. Deployments which run untrusted scripts can lock these
+/// down, and workloads with larger scripts can raise the limits.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScriptOptions {
+	/// The maximum memory size of the script runtime, in bytes
+	memory_limit: usize,
+	/// The maximum stack size of the script runtime, in bytes
+	stack_size: usize,
+	/// The maximum time that a script can run for
+	timeout: Option<Duration>,
+	/// Whether scripts can use the fetch function
+	fetch: bool,
+	/// The hosts which scripts can fetch, or any host if not specified
+	fetch_hosts: Option<Vec<String>>,
+	/// Whether scripts can import the os module
+	os: bool,
+}
+
+impl Default for ScriptOptions {
+	fn default() -> Self {
+		ScriptOptions {
+			memory_limit: cnf::SCRIPTING_MAX_MEMORY_LIMIT,
+			stack_size: cnf::SCRIPTING_MAX_STACK_SIZE,
+			timeout: None,
+			fetch: true,
+			fetch_hosts: None,
+			os: true,
+		}
+	}
+}
+
+impl ScriptOptions {
+	/// Set the maximum memory size of the script runtime, in bytes
+	pub fn with_memory_limit(mut self, limit: usize) -> Self {
+		self.memory_limit = limit;
+		self
+	}
+	/// Set the maximum stack size of the script runtime, in bytes
+	pub fn with_stack_size(mut self, size: usize) -> Self {
+		self.stack_size = size;
+		self
+	}
+	/// Set the maximum time that a script can run for
+	pub fn with_timeout(mut self, timeout: Duration) -> Self {
+		self.timeout = Some(timeout);
+		self
+	}
+	/// Allow or deny the fetch function
+	pub fn with_fetch(mut self, fetch: bool) -> Self {
+		self.fetch = fetch;
+		self
+	}
+	/// Only allow the fetch function to request these hosts
+	pub fn with_fetch_hosts(mut self, hosts: Vec<String>) -> Self {
+		self.fetch_hosts = Some(hosts.into_iter().map(|v| v.to_ascii_lowercase()).collect());
+		self
+	}
+	/// Allow or deny importing the os module
+	pub fn with_os(mut self, os: bool) -> Self {
+		self.os = os;
+		self
+	}
+	/// Get the maximum memory size of the script runtime, in bytes
+	pub fn memory_limit(&self) -> usize {
+		self.memory_limit
+	}
+	/// Get the maximum stack size of the script runtime, in bytes
+	pub fn stack_size(&self) -> usize {
+		self.stack_size
+	}
+	/// Get the maximum time that a script can run for
+	pub fn timeout(&self) -> Option<Duration> {
+		self.timeout
+	}
+	/// Check if scripts can use the fetch function
+	pub fn fetch(&self) -> bool {
+		self.fetch
+	}
+	/// Check if scripts can import the os module
+	pub fn os(&self) -> bool {
+		self.os
+	}
+	/// Check if scripts can fetch the specified url
+	pub fn allows_fetch(&self, url: &str) -> bool {
+		// Check if fetch is allowed at all
+		if !self.fetch {
+			return false;
+		}
+		// Check if the host is in the allowlist
+		match &self.fetch_hosts {
+			None => true,
+			Some(hosts) => match Url::parse(url) {
+				Ok(url) => match url.host_str() {
+					Some(host) => hosts.iter().any(|v| v == host),
+					None => false,
+				},
+				Err(_) => false,
+			},
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn allows_fetch_by_default() {
+		let opt = ScriptOptions::default();
+		assert!(opt.allows_fetch("https://surrealdb.com/docs"));
+	}
+
+	#[test]
+	fn denies_fetch_when_disabled() {
+		let opt = ScriptOptions::default().with_fetch(false);
+		assert!(!opt.allows_fetch("https://surrealdb.com/docs"));
+	}
+
+	#[test]
+	fn allows_fetch_for_allowed_hosts() {
+		let opt = ScriptOptions::default().with_fetch_hosts(vec!["SurrealDB.com".to_owned()]);
+		assert!(opt.allows_fetch("https://surrealdb.com/docs"));
+		assert!(!opt.allows_fetch("https://api.surrealdb.com/docs"));
+		assert!(!opt.allows_fetch("https://example.com"));
+		assert!(!opt.allows_fetch("surrealdb.com"));
+	}
+}
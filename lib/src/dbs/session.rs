@@ -1,5 +1,6 @@
 use crate::ctx::Context;
 use crate::dbs::Auth;
+use crate::sql::role::Role;
 use crate::sql::value::Value;
 use std::sync::Arc;
 
@@ -8,6 +9,10 @@ use std::sync::Arc;
 pub struct Session {
 	/// The current [`Auth`] information
 	pub au: Arc<Auth>,
+	/// The current authentication [`Role`]
+	pub rl: Role,
+	/// The currently authenticated user
+	pub us: Option<String>,
 	/// Whether realtime queries are supported
 	pub rt: bool,
 	/// The current connection IP address
@@ -72,6 +77,11 @@ impl Session {
 			..Session::default()
 		}
 	}
+	/// Set the authentication role for the session
+	pub fn with_rl(mut self, rl: Role) -> Session {
+		self.rl = rl;
+		self
+	}
 	/// Set the selected namespace for the session
 	pub fn with_ns(mut self, ns: &str) -> Session {
 		self.ns = Some(ns.to_owned());
@@ -82,6 +92,11 @@ impl Session {
 		self.db = Some(db.to_owned());
 		self
 	}
+	/// Set whether realtime queries are supported for the session
+	pub fn with_rt(mut self, rt: bool) -> Session {
+		self.rt = rt;
+		self
+	}
 	/// Retrieves the selected namespace
 	pub(crate) fn ns(&self) -> Option<Arc<str>> {
 		self.ns.as_deref().map(Into::into)
@@ -115,6 +130,7 @@ impl Session {
 			"sc".to_string() => self.sc.to_owned().into(),
 			"sd".to_string() => self.sd.to_owned().into(),
 			"tk".to_string() => self.tk.to_owned().into(),
+			"us".to_string() => self.us.to_owned().into(),
 		});
 		ctx.add_value(key, val);
 		// Output context
@@ -1,5 +1,6 @@
 use crate::sql::cond::Cond;
 use crate::sql::data::Data;
+use crate::sql::explain::Explain;
 use crate::sql::fetch::Fetchs;
 use crate::sql::field::Fields;
 use crate::sql::group::Groups;
@@ -15,6 +16,7 @@ use crate::sql::statements::live::LiveStatement;
 use crate::sql::statements::relate::RelateStatement;
 use crate::sql::statements::select::SelectStatement;
 use crate::sql::statements::update::UpdateStatement;
+use crate::sql::version::Version;
 use std::fmt;
 
 #[derive(Clone, Debug)]
@@ -174,6 +176,22 @@ impl<'a> Statement<'a> {
 			_ => None,
 		}
 	}
+	/// Returns any VERSION clause if specified
+	#[inline]
+	pub fn version(&self) -> Option<&Version> {
+		match self {
+			Statement::Select(v) => v.version.as_ref(),
+			_ => None,
+		}
+	}
+	/// Returns any EXPLAIN clause if specified
+	#[inline]
+	pub fn explain(&self) -> Option<&Explain> {
+		match self {
+			Statement::Select(v) => v.explain.as_ref(),
+			_ => None,
+		}
+	}
 	/// Returns any RETURN clause if specified
 	#[inline]
 	pub fn output(&self) -> Option<&Output> {
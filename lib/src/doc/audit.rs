@@ -0,0 +1,60 @@
+use crate::ctx::Context;
+use crate::dbs::Options;
+use crate::doc::Document;
+use crate::err::Error;
+use crate::kvs;
+use crate::sql::idiom::Idiom;
+use crate::sql::statements::DefineTableStatement;
+use crate::sql::value::Value;
+use chrono::{TimeZone, Utc};
+
+impl<'a> Document<'a> {
+	/// Writes an audit record for this document change, if the table is audited
+	pub(super) async fn audit(
+		&self,
+		ctx: &Context<'_>,
+		opt: &Options,
+		run: &mut kvs::Transaction,
+		tb: &DefineTableStatement,
+		ts: u64,
+	) -> Result<(), Error> {
+		// Check if the table is audited
+		if !tb.audit {
+			return Ok(());
+		}
+		// Get the record id
+		let rid = self.id.as_ref().unwrap();
+		// Get the session details
+		let ses = ctx.value("session").cloned().unwrap_or_default();
+		// Get the type of change
+		let action = if self.initial.is_none() {
+			"CREATE"
+		} else if self.current.is_none() {
+			"DELETE"
+		} else {
+			"UPDATE"
+		};
+		// Allocate the position of the audit record
+		let sq = run.next_audit(opt.ns(), opt.db()).await?;
+		// Create the audit record
+		let val = Value::from(map! {
+			String::from("id") => Value::from(rid.clone()),
+			String::from("action") => Value::from(action),
+			String::from("before") => self.initial.as_ref().clone(),
+			String::from("after") => self.current.as_ref().clone(),
+			String::from("patch") => Value::from(self.initial.diff(&self.current, Idiom::default())),
+			String::from("level") => Value::from(opt.auth.name()),
+			String::from("user") => ses.pick(&["us".into()]),
+			String::from("auth") => ctx.value("auth").cloned().unwrap_or_default(),
+			String::from("scope") => ctx.value("scope").cloned().unwrap_or_default(),
+			String::from("ip") => ses.pick(&["ip".into()]),
+			String::from("timestamp") => Value::from(Utc.timestamp_nanos(ts as i64)),
+			String::from("sequence") => Value::from(sq),
+		});
+		// Store the audit record
+		let key = crate::key::au::new(opt.ns(), opt.db(), &rid.tb, sq);
+		run.set(key, val).await?;
+		// Carry on
+		Ok(())
+	}
+}
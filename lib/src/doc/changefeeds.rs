@@ -0,0 +1,57 @@
+use crate::ctx::Context;
+use crate::dbs::Options;
+use crate::dbs::Statement;
+use crate::dbs::Transaction;
+use crate::doc::Document;
+use crate::err::Error;
+use crate::sql::value::Value;
+
+impl<'a> Document<'a> {
+	pub async fn changefeeds(
+		&self,
+		_ctx: &Context<'_>,
+		opt: &Options,
+		txn: &Transaction,
+		stm: &Statement<'_>,
+	) -> Result<(), Error> {
+		// Check if forced
+		if !opt.force && !self.changed() {
+			return Ok(());
+		}
+		// Get the table definition
+		let tb = self.tb(opt, txn).await?;
+		// Check if the table is a view
+		if tb.drop {
+			return Ok(());
+		}
+		// Clone transaction
+		let run = txn.clone();
+		// Claim transaction
+		let mut run = run.lock().await;
+		// Get the database definition
+		let db = run.get_and_cache_db(opt.ns(), opt.db()).await?;
+		// Check if changes are recorded for this table
+		let cf = match tb.changefeed.as_ref().or(db.changefeed.as_ref()) {
+			Some(cf) => cf,
+			None => return Ok(()),
+		};
+		// Get the record id
+		let rid = self.id.as_ref().unwrap();
+		// Check what type of data change this is
+		let change = if stm.is_delete() {
+			map! {
+				String::from("delete") => Value::from(map! {
+					String::from("id") => Value::Thing(rid.clone()),
+				}),
+			}
+		} else {
+			map! {
+				String::from("update") => self.current.as_ref().clone(),
+			}
+		};
+		// Record the change until the transaction commits
+		run.record_change(opt.ns(), opt.db(), &rid.tb, *cf.expiry, change.into());
+		// Carry on
+		Ok(())
+	}
+}
@@ -10,6 +10,7 @@ use crate::sql::statements::define::DefineTableStatement;
 use crate::sql::statements::live::LiveStatement;
 use crate::sql::thing::Thing;
 use crate::sql::value::Value;
+use chrono::Utc;
 use std::borrow::Cow;
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
@@ -53,6 +54,10 @@ impl<'a> Document<'a> {
 	pub fn is_new(&self) -> bool {
 		self.initial.is_none()
 	}
+	/// Get the timestamp at which to version this document
+	pub fn timestamp() -> u64 {
+		u64::try_from(Utc::now().timestamp_nanos()).unwrap_or_default()
+	}
 	/// Get the table for this document
 	pub async fn tb(
 		&self,
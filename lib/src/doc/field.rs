@@ -39,25 +39,6 @@ impl<'a> Document<'a> {
 					},
 					_ => Value::None,
 				};
-				// Check for a TYPE clause
-				if let Some(kind) = &fd.kind {
-					if !val.is_none() {
-						val = val.convert_to(kind).map_err(|e| match e {
-							// There was a conversion error
-							Error::ConvertTo {
-								from,
-								..
-							} => Error::FieldCheck {
-								thing: rid.to_string(),
-								field: fd.name.clone(),
-								value: from.to_string(),
-								check: kind.to_string(),
-							},
-							// There was a different error
-							e => e,
-						})?;
-					}
-				}
 				// Check for a VALUE clause
 				if let Some(expr) = &fd.value {
 					// Configure the context
@@ -69,18 +50,20 @@ impl<'a> Document<'a> {
 					// Process the VALUE clause
 					val = expr.compute(&ctx, opt, txn, Some(&self.current)).await?;
 				}
-				// Check for a TYPE clause
+				// Check for a TYPE clause, once any VALUE clause has been processed,
+				// so that explicit casts in the VALUE clause can be used for input
 				if let Some(kind) = &fd.kind {
-					val = val.convert_to(kind).map_err(|e| match e {
-						// There was a conversion error
-						Error::ConvertTo {
+					val = val.coerce_to(kind).map_err(|e| match e {
+						// The value, or a nested value, does not conform to
+						// the type, or to the nested type which was checked
+						Error::CoerceTo {
 							from,
-							..
+							into,
 						} => Error::FieldCheck {
 							thing: rid.to_string(),
 							field: fd.name.clone(),
 							value: from.to_string(),
-							check: kind.to_string(),
+							check: into.to_string(),
 						},
 						// There was a different error
 						e => e,
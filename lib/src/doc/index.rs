@@ -4,7 +4,11 @@ use crate::dbs::Statement;
 use crate::dbs::Transaction;
 use crate::doc::Document;
 use crate::err::Error;
+use crate::idx::ft::FtIndex;
+use crate::idx::geo::GeoIndex;
+use crate::idx::vector::VectorIndex;
 use crate::sql::array::Array;
+use crate::sql::index::Index;
 
 impl<'a> Document<'a> {
 	pub async fn index(
@@ -48,8 +52,8 @@ impl<'a> Document<'a> {
 			let mut run = run.lock().await;
 			// Update the index entries
 			if opt.force || o != n {
-				match ix.uniq {
-					true => {
+				match &ix.index {
+					Index::Uniq => {
 						// Delete the old index data
 						if self.initial.is_some() {
 							#[rustfmt::skip]
@@ -72,7 +76,7 @@ impl<'a> Document<'a> {
 							}
 						}
 					}
-					false => {
+					Index::Idx => {
 						// Delete the old index data
 						if self.initial.is_some() {
 							#[rustfmt::skip]
@@ -95,6 +99,41 @@ impl<'a> Document<'a> {
 							}
 						}
 					}
+					Index::Search {
+						..
+					} => {
+						// Update the full-text index data
+						let fti = FtIndex::new(&mut run, opt.ns(), opt.db(), ix).await?;
+						let text = match self.current.is_some() {
+							true => FtIndex::text(&n[0]),
+							false => None,
+						};
+						fti.index(&mut run, &rid.id, text).await?;
+					}
+					Index::Vector {
+						..
+					} => {
+						// Update the vector index data
+						let vix = VectorIndex::new(opt.ns(), opt.db(), ix);
+						let v = match self.current.is_some() {
+							true => vix.vector(&n[0])?,
+							false => None,
+						};
+						vix.index(&mut run, &rid.id, v).await?;
+					}
+					Index::Geo => {
+						// Update the geospatial index data
+						let gix = GeoIndex::new(opt.ns(), opt.db(), ix);
+						let o = match self.initial.is_some() {
+							true => GeoIndex::geometry(&o[0]).unwrap_or_default(),
+							false => None,
+						};
+						let n = match self.current.is_some() {
+							true => GeoIndex::geometry(&n[0])?,
+							false => None,
+						};
+						gix.index(&mut run, &rid.id, o, n).await?;
+					}
 				};
 			}
 		}
@@ -20,6 +20,8 @@ impl<'a> Document<'a> {
 			false => {
 				// Check if allowed
 				self.allow(ctx, opt, txn, stm).await?;
+				// Check table type
+				self.relation(ctx, opt, txn, stm).await?;
 				// Merge record data
 				self.merge(ctx, opt, txn, stm).await?;
 				// Merge fields data
@@ -34,6 +36,8 @@ impl<'a> Document<'a> {
 				self.index(ctx, opt, txn, stm).await?;
 				// Store record data
 				self.store(ctx, opt, txn, stm).await?;
+				// Record the data change
+				self.changefeeds(ctx, opt, txn, stm).await?;
 				// Run table queries
 				self.table(ctx, opt, txn, stm).await?;
 				// Run lives queries
@@ -61,6 +65,8 @@ impl<'a> Document<'a> {
 				self.index(ctx, opt, txn, stm).await?;
 				// Store record data
 				self.store(ctx, opt, txn, stm).await?;
+				// Record the data change
+				self.changefeeds(ctx, opt, txn, stm).await?;
 				// Run table queries
 				self.table(ctx, opt, txn, stm).await?;
 				// Run lives queries
@@ -1,9 +1,12 @@
 use crate::ctx::Context;
+use crate::dbs::Action;
+use crate::dbs::Notification;
 use crate::dbs::Options;
 use crate::dbs::Statement;
 use crate::dbs::Transaction;
 use crate::doc::Document;
 use crate::err::Error;
+use crate::sql::value::Value;
 
 impl<'a> Document<'a> {
 	pub async fn lives(
@@ -11,32 +14,48 @@ impl<'a> Document<'a> {
 		ctx: &Context<'_>,
 		opt: &Options,
 		txn: &Transaction,
-		_stm: &Statement<'_>,
+		stm: &Statement<'_>,
 	) -> Result<(), Error> {
 		// Check if forced
 		if !opt.force && !self.changed() {
 			return Ok(());
 		}
 		// Get the record id
-		let _ = self.id.as_ref().unwrap();
-		// Loop through all index statements
+		let rid = self.id.as_ref().unwrap();
+		// Loop through all live query statements
 		for lv in self.lv(opt, txn).await?.iter() {
 			// Create a new statement
-			let stm = Statement::from(lv);
+			let lq = Statement::from(lv);
 			// Check LIVE SELECT where condition
-			if self.check(ctx, opt, txn, &stm).await.is_err() {
-				continue;
+			if let Some(cond) = lq.conds() {
+				// Deleted records are checked as they were before deletion
+				let doc = match stm.is_delete() {
+					true => &self.initial,
+					false => &self.current,
+				};
+				// Check if the expression is truthy
+				match cond.compute(ctx, opt, txn, Some(doc)).await {
+					Ok(v) if v.is_truthy() => {}
+					_ => continue,
+				}
 			}
 			// Check what type of data change this is
-			if stm.is_delete() {
-				// Send a DELETE notification to the WebSocket
+			let (action, result) = if stm.is_delete() {
+				// Process the DELETE notification to send
+				(Action::Delete, Value::Thing(rid.clone()))
 			} else if self.is_new() {
 				// Process the CREATE notification to send
-				let _ = self.pluck(ctx, opt, txn, &stm).await?;
+				(Action::Create, self.pluck(ctx, opt, txn, &lq).await?)
 			} else {
-				// Process the CREATE notification to send
-				let _ = self.pluck(ctx, opt, txn, &stm).await?;
+				// Process the UPDATE notification to send
+				(Action::Update, self.pluck(ctx, opt, txn, &lq).await?)
 			};
+			// Queue the notification until the transaction commits
+			txn.lock().await.notify(Notification {
+				id: lv.id.clone(),
+				action,
+				result,
+			});
 		}
 		// Carry on
 		Ok(())
@@ -14,6 +14,8 @@ mod update; // Processes a UPDATE statement for this document
 
 mod allow; // Checks whether the query can access this document
 mod alter; // Modifies and updates the fields in this document
+mod audit; // Records an audit trail of the changes to this document
+mod changefeeds; // Records any data changes for the table change feeds
 mod check; // Checks whether the WHERE clauses matches this document
 mod clean; // Ensures records adhere to the table schema
 mod edges; // Attempts to store the edge data for this document
@@ -27,6 +29,7 @@ mod lives; // Processes any live queries relevant for this document
 mod merge; // Merges any field changes for an INSERT statement
 mod pluck; // Pulls the projected expressions from the document
 mod purge; // Deletes this document, and any edges or indexes
+mod relation; // Checks whether the table type allows this document
 mod reset; // Resets internal fields which were set for this document
 mod store; // Writes the document content to the storage engine
 mod table; // Processes any foreign tables relevant for this document
@@ -25,8 +25,10 @@ impl<'a> Document<'a> {
 		if !opt.force && !self.changed() {
 			return Ok(());
 		}
+		// Get the table definition
+		let tb = self.tb(opt, txn).await?;
 		// Check if the table is a view
-		if self.tb(opt, txn).await?.drop {
+		if tb.drop {
 			return Ok(());
 		}
 		// Clone transaction
@@ -38,6 +40,17 @@ impl<'a> Document<'a> {
 		// Purge the record data
 		let key = crate::key::thing::new(opt.ns(), opt.db(), &rid.tb, &rid.id);
 		run.del(key).await?;
+		// Mark the record as deleted from this point in time
+		let ts = Document::timestamp();
+		let key = crate::key::version::new(opt.ns(), opt.db(), &rid.tb, &rid.id, ts);
+		run.set(key, vec![]).await?;
+		// Prune any expired record versions
+		if let Some(ret) = &tb.retention {
+			let ts = ts.saturating_sub(u64::try_from(ret.nanos()).unwrap_or(u64::MAX));
+			run.prune_versions(opt.ns(), opt.db(), &rid.tb, &rid.id, ts).await?;
+		}
+		// Store the audit record
+		self.audit(ctx, opt, &mut run, &tb, ts).await?;
 		// Purge the record edges
 		match (self.initial.pick(&*EDGE), self.initial.pick(&*IN), self.initial.pick(&*OUT)) {
 			(Value::Bool(true), Value::Thing(ref l), Value::Thing(ref r)) => {
@@ -16,6 +16,8 @@ impl<'a> Document<'a> {
 	) -> Result<Value, Error> {
 		// Check if allowed
 		self.allow(ctx, opt, txn, stm).await?;
+		// Check table type
+		self.relation(ctx, opt, txn, stm).await?;
 		// Alter record data
 		self.alter(ctx, opt, txn, stm).await?;
 		// Merge fields data
@@ -32,6 +34,8 @@ impl<'a> Document<'a> {
 		self.index(ctx, opt, txn, stm).await?;
 		// Store record data
 		self.store(ctx, opt, txn, stm).await?;
+		// Record the data change
+		self.changefeeds(ctx, opt, txn, stm).await?;
 		// Run table queries
 		self.table(ctx, opt, txn, stm).await?;
 		// Run lives queries
@@ -0,0 +1,88 @@
+use crate::ctx::Context;
+use crate::dbs::Options;
+use crate::dbs::Statement;
+use crate::dbs::Transaction;
+use crate::dbs::Workable;
+use crate::doc::Document;
+use crate::err::Error;
+use crate::sql::fmt::Fmt;
+use crate::sql::paths::IN;
+use crate::sql::paths::OUT;
+use crate::sql::tabletype::TableType;
+use crate::sql::thing::Thing;
+use crate::sql::value::Value;
+use crate::sql::Table;
+
+impl<'a> Document<'a> {
+	pub async fn relation(
+		&mut self,
+		_ctx: &Context<'_>,
+		opt: &Options,
+		txn: &Transaction,
+		_stm: &Statement<'_>,
+	) -> Result<(), Error> {
+		// Get the table
+		let tb = self.tb(opt, txn).await?;
+		// Get the record id
+		let rid = self.id.as_ref().unwrap();
+		// Check the type of the table
+		match &self.extras {
+			// This document is a relation
+			Workable::Relate(l, r) => {
+				// Check if the table stores relations
+				if !tb.kind.allows_relation() {
+					return Err(Error::TableIsNormal {
+						table: tb.name.to_raw(),
+						thing: rid.to_string(),
+					});
+				}
+				// Check the records at either end of the relation
+				if let TableType::Relation(rel) = &tb.kind {
+					Self::check_relation(rid, l, "in", &rel.from)?;
+					Self::check_relation(rid, r, "out", &rel.to)?;
+				}
+			}
+			// This document is a normal record, or an existing relation
+			_ => {
+				// Check if the table stores normal records
+				if self.is_new() && !tb.kind.allows_normal() {
+					return Err(Error::TableIsRelation {
+						table: tb.name.to_raw(),
+						thing: rid.to_string(),
+					});
+				}
+				// The records at either end of an existing relation can not be
+				// changed, but the table may have been defined since it was created
+				if let TableType::Relation(rel) = &tb.kind {
+					if let (Value::Thing(l), Value::Thing(r)) =
+						(self.initial.pick(&*IN), self.initial.pick(&*OUT))
+					{
+						Self::check_relation(rid, &l, "in", &rel.from)?;
+						Self::check_relation(rid, &r, "out", &rel.to)?;
+					}
+				}
+			}
+		}
+		// Carry on
+		Ok(())
+	}
+	/// Checks that a record at one end of a relation is of one of the allowed tables
+	fn check_relation(
+		rid: &Thing,
+		val: &Thing,
+		dir: &str,
+		check: &Option<Vec<Table>>,
+	) -> Result<(), Error> {
+		if let Some(tbs) = check {
+			if !tbs.iter().any(|tb| tb.0 == val.tb) {
+				return Err(Error::RelationCheck {
+					thing: rid.to_string(),
+					value: val.to_string(),
+					dir: dir.to_owned(),
+					check: Fmt::verbar_separated(tbs).to_string(),
+				});
+			}
+		}
+		Ok(())
+	}
+}
@@ -8,7 +8,7 @@ use crate::err::Error;
 impl<'a> Document<'a> {
 	pub async fn store(
 		&self,
-		_ctx: &Context<'_>,
+		ctx: &Context<'_>,
 		opt: &Options,
 		txn: &Transaction,
 		_stm: &Statement<'_>,
@@ -17,8 +17,10 @@ impl<'a> Document<'a> {
 		if !opt.force && !self.changed() {
 			return Ok(());
 		}
+		// Get the table definition
+		let tb = self.tb(opt, txn).await?;
 		// Check if the table is a view
-		if self.tb(opt, txn).await?.drop {
+		if tb.drop {
 			return Ok(());
 		}
 		// Clone transaction
@@ -30,6 +32,17 @@ impl<'a> Document<'a> {
 		// Store the record data
 		let key = crate::key::thing::new(opt.ns(), opt.db(), &rid.tb, &rid.id);
 		run.set(key, self).await?;
+		// Store the record version
+		let ts = Document::timestamp();
+		let key = crate::key::version::new(opt.ns(), opt.db(), &rid.tb, &rid.id, ts);
+		run.set(key, self).await?;
+		// Prune any expired record versions
+		if let Some(ret) = &tb.retention {
+			let ts = ts.saturating_sub(u64::try_from(ret.nanos()).unwrap_or(u64::MAX));
+			run.prune_versions(opt.ns(), opt.db(), &rid.tb, &rid.id, ts).await?;
+		}
+		// Store the audit record
+		self.audit(ctx, opt, &mut run, &tb, ts).await?;
 		// Carry on
 		Ok(())
 	}
@@ -4,9 +4,11 @@ use crate::dbs::Statement;
 use crate::dbs::Transaction;
 use crate::doc::Document;
 use crate::err::Error;
+use crate::sql::array::Array;
 use crate::sql::data::Data;
 use crate::sql::expression::Expression;
 use crate::sql::field::{Field, Fields};
+use crate::sql::function::Function;
 use crate::sql::idiom::Idiom;
 use crate::sql::number::Number;
 use crate::sql::operator::Operator;
@@ -261,68 +263,46 @@ impl<'a> Document<'a> {
 		};
 		//
 		for field in exp.other() {
-			// Process it if it is a normal field
-			if let Field::Alone(v) = field {
-				match v {
-					Value::Function(f) if f.is_rolling() => match f.name() {
-						"count" => {
-							let val = f.compute(ctx, opt, txn, doc).await?;
-							self.chg(&mut ops, &act, v.to_idiom(), val);
-						}
-						"math::sum" => {
-							let val = f.args()[0].compute(ctx, opt, txn, doc).await?;
-							self.chg(&mut ops, &act, v.to_idiom(), val);
-						}
-						"math::min" => {
-							let val = f.args()[0].compute(ctx, opt, txn, doc).await?;
-							self.min(&mut ops, &act, v.to_idiom(), val);
-						}
-						"math::max" => {
-							let val = f.args()[0].compute(ctx, opt, txn, doc).await?;
-							self.max(&mut ops, &act, v.to_idiom(), val);
-						}
-						"math::mean" => {
-							let val = f.args()[0].compute(ctx, opt, txn, doc).await?;
-							self.mean(&mut ops, &act, v.to_idiom(), val);
-						}
-						_ => unreachable!(),
-					},
-					_ => {
-						let val = v.compute(ctx, opt, txn, doc).await?;
-						self.set(&mut ops, v.to_idiom(), val);
+			// Get the field expression and name
+			let (v, i) = match field {
+				Field::Alone(v) => (v, v.to_idiom()),
+				Field::Alias(v, i) => (v, i.to_owned()),
+				Field::All => continue,
+			};
+			match v {
+				// Process it if it can use running totals
+				Value::Function(f) if f.is_rolling() => match f.name() {
+					"count" => {
+						let val = f.compute(ctx, opt, txn, doc).await?;
+						self.chg(&mut ops, &act, i, val);
 					}
-				}
-			}
-			// Process it if it is a aliased field
-			if let Field::Alias(v, i) = field {
-				match v {
-					Value::Function(f) if f.is_rolling() => match f.name() {
-						"count" => {
-							let val = f.compute(ctx, opt, txn, doc).await?;
-							self.chg(&mut ops, &act, i.to_owned(), val);
-						}
-						"math::sum" => {
-							let val = f.args()[0].compute(ctx, opt, txn, doc).await?;
-							self.chg(&mut ops, &act, i.to_owned(), val);
-						}
-						"math::min" => {
-							let val = f.args()[0].compute(ctx, opt, txn, doc).await?;
-							self.min(&mut ops, &act, i.to_owned(), val);
-						}
-						"math::max" => {
-							let val = f.args()[0].compute(ctx, opt, txn, doc).await?;
-							self.max(&mut ops, &act, i.to_owned(), val);
-						}
-						"math::mean" => {
-							let val = f.args()[0].compute(ctx, opt, txn, doc).await?;
-							self.mean(&mut ops, &act, i.to_owned(), val);
-						}
-						_ => unreachable!(),
-					},
-					_ => {
-						let val = v.compute(ctx, opt, txn, doc).await?;
-						self.set(&mut ops, i.to_owned(), val);
+					"math::sum" => {
+						let val = f.args()[0].compute(ctx, opt, txn, doc).await?;
+						self.chg(&mut ops, &act, i, val);
+					}
+					"math::mean" => {
+						let val = f.args()[0].compute(ctx, opt, txn, doc).await?;
+						self.mean(&mut ops, &act, i, val);
+					}
+					"math::stddev" => {
+						let val = f.args()[0].compute(ctx, opt, txn, doc).await?;
+						self.variance(&mut ops, &act, i, val, true);
 					}
+					"math::variance" => {
+						let val = f.args()[0].compute(ctx, opt, txn, doc).await?;
+						self.variance(&mut ops, &act, i, val, false);
+					}
+					_ => unreachable!(),
+				},
+				// Process it if it needs all grouped values
+				Value::Function(f) if f.is_aggregate() => {
+					let val = f.args()[0].compute(ctx, opt, txn, doc).await?;
+					self.values(&mut ops, &act, i, f, val);
+				}
+				// Process it if it is a normal field
+				_ => {
+					let val = v.compute(ctx, opt, txn, doc).await?;
+					self.set(&mut ops, i, val);
 				}
 			}
 		}
@@ -345,52 +325,35 @@ impl<'a> Document<'a> {
 			val,
 		));
 	}
-	/// Set the new minimum value for the field in the foreign table
-	fn min(&self, ops: &mut Ops, act: &Action, key: Idiom, val: Value) {
-		if act == &Action::Update {
-			ops.push((
-				key.clone(),
-				Operator::Equal,
-				Value::Subquery(Box::new(Subquery::Ifelse(IfelseStatement {
-					exprs: vec![(
-						Value::Expression(Box::new(Expression {
-							l: Value::Idiom(key.clone()),
-							o: Operator::MoreThan,
-							r: val.clone(),
-						})),
-						val,
-					)],
-					close: Some(Value::Idiom(key)),
-				}))),
-			));
-		}
-	}
-	/// Set the new maximum value for the field in the foreign table
-	fn max(&self, ops: &mut Ops, act: &Action, key: Idiom, val: Value) {
-		if act == &Action::Update {
-			ops.push((
-				key.clone(),
-				Operator::Equal,
-				Value::Subquery(Box::new(Subquery::Ifelse(IfelseStatement {
-					exprs: vec![(
-						Value::Expression(Box::new(Expression {
-							l: Value::Idiom(key.clone()),
-							o: Operator::LessThan,
-							r: val.clone(),
-						})),
-						val,
-					)],
-					close: Some(Value::Idiom(key)),
-				}))),
-			));
-		}
+	/// Store the grouped value, and recompute the aggregate for the field in the foreign table
+	fn values(&self, ops: &mut Ops, act: &Action, key: Idiom, f: &Function, val: Value) {
+		//
+		let key_v = Self::hidden(&key, "v");
+		//
+		ops.push((
+			key_v.clone(),
+			match act {
+				Action::Delete => Operator::Dec,
+				Action::Update => Operator::Inc,
+				_ => unreachable!(),
+			},
+			Value::from(vec![val]),
+		));
+		//
+		ops.push((
+			key,
+			Operator::Equal,
+			Value::Function(Box::new(f.aggregate(Self::expr(
+				Value::Idiom(key_v),
+				Operator::Nco,
+				Value::from(Array::new()),
+			)))),
+		));
 	}
 	/// Set the new average value for the field in the foreign table
 	fn mean(&self, ops: &mut Ops, act: &Action, key: Idiom, val: Value) {
 		//
-		let mut key_c = Idiom::from(vec![Part::from("__")]);
-		key_c.0.push(Part::from(key.to_hash()));
-		key_c.0.push(Part::from("c"));
+		let key_c = Self::hidden(&key, "c");
 		//
 		ops.push((
 			key.clone(),
@@ -456,4 +419,69 @@ impl<'a> Document<'a> {
 			Value::from(1),
 		));
 	}
+	/// Set the new variance or standard deviation for the field in the foreign table
+	fn variance(&self, ops: &mut Ops, act: &Action, key: Idiom, val: Value, sqrt: bool) {
+		// Only numbers are included
+		let val = match val {
+			Value::Number(v) => v.to_float(),
+			_ => return,
+		};
+		//
+		let key_c = Self::hidden(&key, "c");
+		let key_s = Self::hidden(&key, "s");
+		let key_q = Self::hidden(&key, "q");
+		//
+		let op = match act {
+			Action::Delete => Operator::Dec,
+			Action::Update => Operator::Inc,
+			_ => unreachable!(),
+		};
+		ops.push((key_c.clone(), op.clone(), Value::from(1)));
+		ops.push((key_s.clone(), op.clone(), Value::from(val)));
+		ops.push((key_q.clone(), op, Value::from(val * val)));
+		// The sample variance is (q - s * s / c) / (c - 1)
+		let c = Value::Idiom(key_c);
+		let s = Value::Idiom(key_s);
+		let q = Value::Idiom(key_q);
+		let var = Self::expr(
+			Self::expr(
+				q,
+				Operator::Sub,
+				Self::expr(Self::expr(s.clone(), Operator::Mul, s), Operator::Div, c.clone()),
+			),
+			Operator::Div,
+			Self::expr(c.clone(), Operator::Sub, Value::from(1)),
+		);
+		let var = Value::Subquery(Box::new(Subquery::Ifelse(IfelseStatement {
+			exprs: vec![
+				(Self::expr(c.clone(), Operator::MoreThan, Value::from(1)), var),
+				(Self::expr(c, Operator::Equal, Value::from(1)), Value::from(0)),
+			],
+			close: Some(Value::from(Number::NAN)),
+		})));
+		//
+		ops.push((
+			key,
+			Operator::Equal,
+			match sqrt {
+				true => Value::Function(Box::new(Function::Normal("math::sqrt".into(), vec![var]))),
+				false => var,
+			},
+		));
+	}
+	/// Get the path of a hidden field used to maintain a field in the foreign table
+	fn hidden(key: &Idiom, name: &str) -> Idiom {
+		let mut key_h = Idiom::from(vec![Part::from("__")]);
+		key_h.0.push(Part::from(key.to_hash()));
+		key_h.0.push(Part::from(name));
+		key_h
+	}
+	/// Build a bracketed expression for a field in the foreign table
+	fn expr(l: Value, o: Operator, r: Value) -> Value {
+		Value::Subquery(Box::new(Subquery::Value(Value::Expression(Box::new(Expression {
+			l,
+			o,
+			r,
+		})))))
+	}
 }
@@ -120,12 +120,32 @@ pub enum Error {
 		value: String,
 	},
 
+	/// The AFTER clause must evaluate to a record id
+	#[error("Found {value} but the AFTER clause must evaluate to a record id")]
+	InvalidAfter {
+		value: String,
+	},
+
+	/// The AFTER clause can not be used with a clause which reorders the records
+	#[error("The AFTER clause can not be used together with {clause}")]
+	AfterUnsupported {
+		clause: String,
+	},
+
 	/// There was an error with the provided JavaScript code
 	#[error("Problem with embedded script function. {message}")]
 	InvalidScript {
 		message: String,
 	},
 
+	/// The statement can not be run from within an embedded script
+	#[error(
+		"Found '{value}' but this statement can not be run from within an embedded script function"
+	)]
+	InvalidScriptStatement {
+		value: String,
+	},
+
 	/// There was a problem running the specified function
 	#[error("There was a problem running the {name}() function. {message}")]
 	InvalidFunction {
@@ -180,8 +200,14 @@ pub enum Error {
 		value: String,
 	},
 
-	/// The requested namespace login does not exist
-	#[error("The namespace login '{value}' does not exist")]
+	/// The requested root user does not exist
+	#[error("The root user '{value}' does not exist")]
+	UsNotFound {
+		value: String,
+	},
+
+	/// The requested namespace user does not exist
+	#[error("The namespace user '{value}' does not exist")]
 	NlNotFound {
 		value: String,
 	},
@@ -198,8 +224,8 @@ pub enum Error {
 		value: String,
 	},
 
-	/// The requested database login does not exist
-	#[error("The database login '{value}' does not exist")]
+	/// The requested database user does not exist
+	#[error("The database user '{value}' does not exist")]
 	DlNotFound {
 		value: String,
 	},
@@ -222,6 +248,12 @@ pub enum Error {
 		value: String,
 	},
 
+	/// The requested analyzer does not exist
+	#[error("The analyzer '{value}' does not exist")]
+	AzNotFound {
+		value: String,
+	},
+
 	/// The requested param does not exist
 	#[error("The param '${value}' does not exist")]
 	PaNotFound {
@@ -284,6 +316,24 @@ pub enum Error {
 		value: String,
 	},
 
+	/// Can not execute FOR loop using the specified value
+	#[error("Can not execute FOR loop using value '{value}'")]
+	ForeachStatement {
+		value: String,
+	},
+
+	/// A BREAK statement was run outside of a FOR loop
+	#[error("The BREAK statement can only be used within a FOR loop")]
+	Break,
+
+	/// A CONTINUE statement was run outside of a FOR loop
+	#[error("The CONTINUE statement can only be used within a FOR loop")]
+	Continue,
+
+	/// An error was thrown using a THROW statement
+	#[error("An error occurred: {0}")]
+	Thrown(String),
+
 	/// The permissions do not allow this query to be run on this table
 	#[error("You don't have permission to run this query on the `{table}` table")]
 	TablePermissions {
@@ -296,6 +346,31 @@ pub enum Error {
 		table: String,
 	},
 
+	/// The specified table only stores relations, and can not store a normal record
+	#[error(
+		"Found record `{thing}` which is not a relation, but table `{table}` is of type relation"
+	)]
+	TableIsRelation {
+		table: String,
+		thing: String,
+	},
+
+	/// The specified table only stores normal records, and can not store a relation
+	#[error("Found record `{thing}` which is a relation, but table `{table}` is of type normal")]
+	TableIsNormal {
+		table: String,
+		thing: String,
+	},
+
+	/// The record at one end of a relation is not of one of the allowed tables
+	#[error("Found record `{value}` for the `{dir}` of relation `{thing}`, but expected a record from `{check}`")]
+	RelationCheck {
+		thing: String,
+		value: String,
+		dir: String,
+		check: String,
+	},
+
 	/// A database entry for the specified record already exists
 	#[error("Database record `{thing}` already exists")]
 	RecordExists {
@@ -310,6 +385,41 @@ pub enum Error {
 		value: String,
 	},
 
+	/// The search index does not store the offsets needed for highlighting
+	#[error("Database index `{value}` can not be used for highlighting, as it was not defined with HIGHLIGHTS")]
+	HighlightUnsupported {
+		value: String,
+	},
+
+	/// The vector does not have the dimension of the vector index
+	#[error("Incorrect vector dimension ({current}). Expected a vector of {expected} dimension.")]
+	InvalidVectorDimension {
+		current: usize,
+		expected: usize,
+	},
+
+	/// The value can not be used as a vector
+	#[error("The value '{value}' is not a valid vector")]
+	InvalidVectorValue {
+		value: String,
+	},
+
+	/// The value can not be used as a geometry in a geospatial index
+	#[error("The value '{value}' is not a valid geometry")]
+	InvalidGeometryValue {
+		value: String,
+	},
+
+	/// The k-nearest-neighbour search was not run by the query planner
+	#[error("The KNN operator can not be used on `{value}`, as it can only be used in the WHERE clause when selecting from a table")]
+	KnnUnsupported {
+		value: String,
+	},
+
+	/// The VERSION clause can not be used with graph edges, as they are not versioned
+	#[error("The VERSION clause can not be used when selecting graph edges")]
+	VersionUnsupported,
+
 	/// The specified field did not conform to the field type check
 	#[error("Found {value} for field `{field}`, with record `{thing}`, but expected a {check}")]
 	FieldCheck {
@@ -341,6 +451,13 @@ pub enum Error {
 		into: Cow<'static, str>,
 	},
 
+	/// The value could not be coerced into the specified type without losing information
+	#[error("Expected a {into} but found {from}")]
+	CoerceTo {
+		from: Value,
+		into: Cow<'static, str>,
+	},
+
 	/// The requested function does not exist
 	#[error("Cannot perform addition with '{0}' and '{1}'")]
 	TryAdd(String, String),
@@ -365,6 +482,10 @@ pub enum Error {
 	#[error("Cannot convert from '{0}' to '{1}'")]
 	TryFrom(String, &'static str),
 
+	/// There was a problem with authentication
+	#[error("There was a problem with authentication")]
+	InvalidAuth,
+
 	/// There was an error processing a remote HTTP request
 	#[error("There was an error processing a remote HTTP request")]
 	Http(String),
@@ -373,6 +494,10 @@ pub enum Error {
 	#[error("There was an error processing a value in parallel")]
 	Channel(String),
 
+	/// The sync data was invalid or incomplete
+	#[error("The sync data is invalid or incomplete")]
+	InvalidSync,
+
 	/// Represents an underlying error with Serde encoding / decoding
 	#[error("Serde error: {0}")]
 	Serde(#[from] SerdeError),
@@ -397,6 +522,7 @@ impl From<echodb::err::Error> for Error {
 	fn from(e: echodb::err::Error) -> Error {
 		match e {
 			echodb::err::Error::KeyAlreadyExists => Error::TxKeyAlreadyExists,
+			echodb::err::Error::ValNotExpectedValue => Error::TxConditionNotMet,
 			_ => Error::Tx(e.to_string()),
 		}
 	}
@@ -429,6 +555,24 @@ impl From<rocksdb::Error> for Error {
 	}
 }
 
+impl From<base64::DecodeError> for Error {
+	fn from(_: base64::DecodeError) -> Error {
+		Error::InvalidAuth
+	}
+}
+
+impl From<std::string::FromUtf8Error> for Error {
+	fn from(_: std::string::FromUtf8Error) -> Error {
+		Error::InvalidAuth
+	}
+}
+
+impl From<jsonwebtoken::errors::Error> for Error {
+	fn from(_: jsonwebtoken::errors::Error) -> Error {
+		Error::InvalidAuth
+	}
+}
+
 impl From<channel::RecvError> for Error {
 	fn from(e: channel::RecvError) -> Error {
 		Error::Channel(e.to_string())
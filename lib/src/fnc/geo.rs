@@ -2,9 +2,22 @@ use crate::err::Error;
 use crate::sql::geometry::Geometry;
 use crate::sql::value::Value;
 use geo::algorithm::bearing::HaversineBearing;
+use geo::algorithm::bool_ops::BooleanOps;
+use geo::algorithm::bounding_rect::BoundingRect;
 use geo::algorithm::centroid::Centroid;
 use geo::algorithm::chamberlain_duquette_area::ChamberlainDuquetteArea;
+use geo::algorithm::convex_hull::ConvexHull;
+use geo::algorithm::haversine_destination::HaversineDestination;
 use geo::algorithm::haversine_distance::HaversineDistance;
+use geo::algorithm::haversine_length::HaversineLength;
+use geo::algorithm::simplify::Simplify;
+use geo::{Coord, LineString, MultiPoint, MultiPolygon, Point, Polygon};
+
+/// The number of segments used to approximate a circle
+const CIRCLE_SEGMENTS: usize = 32;
+
+/// The approximate number of metres in a degree of latitude
+const METRES_PER_DEGREE: f64 = 111_195.0;
 
 pub fn area((arg,): (Value,)) -> Result<Value, Error> {
 	match arg {
@@ -34,6 +47,31 @@ pub fn bearing(points: (Value, Value)) -> Result<Value, Error> {
 	})
 }
 
+pub fn bounding_box((arg,): (Value,)) -> Result<Value, Error> {
+	Ok(match arg {
+		Value::Geometry(v) => match geo::Geometry::from(v).bounding_rect() {
+			Some(v) => Geometry::Polygon(v.to_polygon()).into(),
+			None => Value::None,
+		},
+		_ => Value::None,
+	})
+}
+
+pub fn buffer((arg, distance): (Value, f64)) -> Result<Value, Error> {
+	if distance.is_nan() || distance <= 0.0 {
+		return Err(Error::InvalidArguments {
+			name: String::from("geo::buffer"),
+			message: String::from(
+				"The second argument must be a distance in metres, greater than 0.",
+			),
+		});
+	}
+	Ok(match arg {
+		Value::Geometry(v) => combined("geo::buffer", || buffered(&v, distance))?,
+		_ => Value::None,
+	})
+}
+
 pub fn centroid((arg,): (Value,)) -> Result<Value, Error> {
 	let centroid = match arg {
 		Value::Geometry(v) => match v {
@@ -50,6 +88,20 @@ pub fn centroid((arg,): (Value,)) -> Result<Value, Error> {
 	Ok(centroid.map(Into::into).unwrap_or(Value::None))
 }
 
+pub fn contains((a, b): (Value, Value)) -> Result<Value, Error> {
+	Ok(match (a, b) {
+		(Value::Geometry(a), Value::Geometry(b)) => a.contains(&b).into(),
+		_ => Value::None,
+	})
+}
+
+pub fn convex_hull((arg,): (Value,)) -> Result<Value, Error> {
+	Ok(match arg {
+		Value::Geometry(v) => Geometry::Polygon(geo::Geometry::from(v).convex_hull()).into(),
+		_ => Value::None,
+	})
+}
+
 pub fn distance(points: (Value, Value)) -> Result<Value, Error> {
 	Ok(match points {
 		(Value::Geometry(Geometry::Point(v)), Value::Geometry(Geometry::Point(w))) => {
@@ -59,6 +111,166 @@ pub fn distance(points: (Value, Value)) -> Result<Value, Error> {
 	})
 }
 
+pub fn intersection((a, b): (Value, Value)) -> Result<Value, Error> {
+	Ok(match (polygons(a), polygons(b)) {
+		(Some(a), Some(b)) => combined("geo::intersection", || a.intersection(&b))?,
+		_ => Value::None,
+	})
+}
+
+pub fn length((arg,): (Value,)) -> Result<Value, Error> {
+	Ok(match arg {
+		Value::Geometry(v) => measured(&v).into(),
+		_ => Value::None,
+	})
+}
+
+pub fn simplify((arg, tolerance): (Value, f64)) -> Result<Value, Error> {
+	if tolerance.is_nan() || tolerance < 0.0 {
+		return Err(Error::InvalidArguments {
+			name: String::from("geo::simplify"),
+			message: String::from(
+				"The second argument must be a tolerance in metres, greater than or equal to 0.",
+			),
+		});
+	}
+	Ok(match arg {
+		Value::Geometry(v) => simplified(v, tolerance / METRES_PER_DEGREE).into(),
+		_ => Value::None,
+	})
+}
+
+pub fn union((a, b): (Value, Value)) -> Result<Value, Error> {
+	Ok(match (polygons(a), polygons(b)) {
+		(Some(a), Some(b)) => combined("geo::union", || a.union(&b))?,
+		_ => Value::None,
+	})
+}
+
+pub fn within_distance((a, b, distance): (Value, Value, f64)) -> Result<Value, Error> {
+	Ok(match (a, b) {
+		(Value::Geometry(a), Value::Geometry(b)) => {
+			let d = crate::fnc::util::geo::distance(&a.into(), &b.into());
+			(d <= distance).into()
+		}
+		_ => Value::None,
+	})
+}
+
+/// Converts a polygon or multipolygon into a multipolygon
+fn polygons(v: Value) -> Option<MultiPolygon<f64>> {
+	match v {
+		Value::Geometry(Geometry::Polygon(v)) => Some(MultiPolygon(vec![v])),
+		Value::Geometry(Geometry::MultiPolygon(v)) => Some(v),
+		_ => None,
+	}
+}
+
+/// Returns a single polygon as a polygon, and any other number as a multipolygon
+fn areal(mut v: MultiPolygon<f64>) -> Value {
+	match v.0.len() {
+		1 => Geometry::Polygon(v.0.remove(0)).into(),
+		_ => Geometry::MultiPolygon(v).into(),
+	}
+}
+
+/// Runs a polygon boolean operation, which can panic on degenerate polygons
+fn combined(name: &str, op: impl FnOnce() -> MultiPolygon<f64>) -> Result<Value, Error> {
+	match std::panic::catch_unwind(std::panic::AssertUnwindSafe(op)) {
+		Ok(v) => Ok(areal(v)),
+		Err(_) => Err(Error::InvalidArguments {
+			name: name.to_owned(),
+			message: String::from("The polygons could not be combined, as they are degenerate."),
+		}),
+	}
+}
+
+/// Computes the union of a number of polygons
+fn merged(v: impl IntoIterator<Item = MultiPolygon<f64>>) -> MultiPolygon<f64> {
+	v.into_iter().fold(MultiPolygon(vec![]), |a, b| match a.0.is_empty() {
+		true => b,
+		false => a.union(&b),
+	})
+}
+
+/// Computes the area within a distance, in metres, of a geometry
+fn buffered(v: &Geometry, distance: f64) -> MultiPolygon<f64> {
+	match v {
+		Geometry::Point(v) => MultiPolygon(vec![circle(v, distance)]),
+		Geometry::Line(v) => merged(capsules(v, distance)),
+		Geometry::Polygon(v) => merged(
+			std::iter::once(MultiPolygon(vec![v.to_owned()]))
+				.chain(capsules(v.exterior(), distance))
+				.chain(v.interiors().iter().flat_map(|v| capsules(v, distance))),
+		),
+		Geometry::MultiPoint(v) => {
+			merged(v.iter().map(|v| MultiPolygon(vec![circle(v, distance)])))
+		}
+		Geometry::MultiLine(v) => merged(v.iter().flat_map(|v| capsules(v, distance))),
+		Geometry::MultiPolygon(v) => {
+			merged(v.iter().map(|v| buffered(&Geometry::Polygon(v.to_owned()), distance)))
+		}
+		Geometry::Collection(v) => merged(v.iter().map(|v| buffered(v, distance))),
+	}
+}
+
+/// Approximates the area within a distance, in metres, of a point
+fn circle(point: &Point<f64>, distance: f64) -> Polygon<f64> {
+	let v = (0..CIRCLE_SEGMENTS).map(|i| {
+		let bearing = 360.0 * i as f64 / CIRCLE_SEGMENTS as f64;
+		point.haversine_destination(bearing, distance).0
+	});
+	Polygon::new(LineString(v.collect()), vec![])
+}
+
+/// Approximates the area within a distance, in metres, of each segment of a line
+fn capsules(line: &LineString<f64>, distance: f64) -> Vec<MultiPolygon<f64>> {
+	match line.0.len() {
+		0 => vec![],
+		1 => vec![MultiPolygon(vec![circle(&line.0[0].into(), distance)])],
+		_ => line
+			.lines()
+			.map(|v| {
+				let a = circle(&v.start.into(), distance);
+				let b = circle(&v.end.into(), distance);
+				let v: Vec<Coord<f64>> =
+					a.exterior().coords().chain(b.exterior().coords()).copied().collect();
+				MultiPolygon(vec![MultiPoint::from(v).convex_hull()])
+			})
+			.collect(),
+	}
+}
+
+/// Computes the length in metres of any lines, and the perimeter of any polygons
+fn measured(v: &Geometry) -> f64 {
+	let rings = |v: &Polygon<f64>| -> f64 {
+		v.exterior().haversine_length()
+			+ v.interiors().iter().map(|v| v.haversine_length()).sum::<f64>()
+	};
+	match v {
+		Geometry::Point(_) | Geometry::MultiPoint(_) => 0.0,
+		Geometry::Line(v) => v.haversine_length(),
+		Geometry::Polygon(v) => rings(v),
+		Geometry::MultiLine(v) => v.haversine_length(),
+		Geometry::MultiPolygon(v) => v.iter().map(rings).sum(),
+		Geometry::Collection(v) => v.iter().map(measured).sum(),
+	}
+}
+
+/// Simplifies any lines and polygons, removing points within the tolerance in degrees
+fn simplified(v: Geometry, tolerance: f64) -> Geometry {
+	match v {
+		Geometry::Line(v) => Geometry::Line(v.simplify(&tolerance)),
+		Geometry::Polygon(v) => Geometry::Polygon(v.simplify(&tolerance)),
+		Geometry::MultiLine(v) => Geometry::MultiLine(v.simplify(&tolerance)),
+		Geometry::MultiPolygon(v) => Geometry::MultiPolygon(v.simplify(&tolerance)),
+		Geometry::Collection(v) => {
+			Geometry::Collection(v.into_iter().map(|v| simplified(v, tolerance)).collect())
+		}
+		v => v,
+	}
+}
+
 pub mod hash {
 
 	use crate::err::Error;
@@ -89,3 +301,58 @@ pub mod hash {
 		}
 	}
 }
+
+pub mod wkb {
+
+	use crate::err::Error;
+	use crate::sql::geometry::Geometry;
+	use crate::sql::value::Value;
+	use crate::sql::Bytes;
+
+	pub fn encode((arg,): (Value,)) -> Result<Value, Error> {
+		Ok(match arg {
+			Value::Geometry(v) => Bytes::from(v.to_wkb()).into(),
+			_ => Value::None,
+		})
+	}
+
+	pub fn decode((arg,): (Value,)) -> Result<Value, Error> {
+		match arg {
+			Value::Bytes(v) => match Geometry::from_wkb(&v.into_inner()) {
+				Some(v) => Ok(v.into()),
+				None => Err(Error::InvalidArguments {
+					name: String::from("geo::wkb::decode"),
+					message: String::from("The argument must be a valid WKB geometry."),
+				}),
+			},
+			_ => Ok(Value::None),
+		}
+	}
+}
+
+pub mod wkt {
+
+	use crate::err::Error;
+	use crate::sql::geometry::Geometry;
+	use crate::sql::value::Value;
+
+	pub fn encode((arg,): (Value,)) -> Result<Value, Error> {
+		Ok(match arg {
+			Value::Geometry(v) => v.to_wkt().into(),
+			_ => Value::None,
+		})
+	}
+
+	pub fn decode((arg,): (Value,)) -> Result<Value, Error> {
+		match arg {
+			Value::Strand(v) => match Geometry::from_wkt(&v) {
+				Some(v) => Ok(v.into()),
+				None => Err(Error::InvalidArguments {
+					name: String::from("geo::wkt::decode"),
+					message: String::from("The argument must be a valid WKT geometry."),
+				}),
+			},
+			_ => Ok(Value::None),
+		}
+	}
+}
@@ -17,15 +17,22 @@ pub mod operate;
 pub mod parse;
 pub mod rand;
 pub mod script;
+pub mod search;
 pub mod session;
 pub mod sleep;
 pub mod string;
 pub mod time;
 pub mod r#type;
 pub mod util;
+pub mod vector;
 
 /// Attempts to run any function
-pub async fn run(ctx: &Context<'_>, name: &str, args: Vec<Value>) -> Result<Value, Error> {
+pub async fn run(
+	ctx: &Context<'_>,
+	doc: Option<&Value>,
+	name: &str,
+	args: Vec<Value>,
+) -> Result<Value, Error> {
 	if name.eq("sleep")
 		|| name.starts_with("http")
 		|| name.starts_with("crypto::argon2")
@@ -35,7 +42,7 @@ pub async fn run(ctx: &Context<'_>, name: &str, args: Vec<Value>) -> Result<Valu
 	{
 		asynchronous(ctx, name, args).await
 	} else {
-		synchronous(ctx, name, args)
+		synchronous(ctx, doc, name, args)
 	}
 }
 
@@ -60,7 +67,12 @@ macro_rules! dispatch {
 }
 
 /// Attempts to run any synchronous function.
-pub fn synchronous(ctx: &Context<'_>, name: &str, args: Vec<Value>) -> Result<Value, Error> {
+pub fn synchronous(
+	ctx: &Context<'_>,
+	doc: Option<&Value>,
+	name: &str,
+	args: Vec<Value>,
+) -> Result<Value, Error> {
 	dispatch!(
 		name,
 		args,
@@ -118,10 +130,23 @@ pub fn synchronous(ctx: &Context<'_>, name: &str, args: Vec<Value>) -> Result<Va
 		//
 		"geo::area" => geo::area,
 		"geo::bearing" => geo::bearing,
+		"geo::bounding_box" => geo::bounding_box,
+		"geo::buffer" => geo::buffer,
 		"geo::centroid" => geo::centroid,
+		"geo::contains" => geo::contains,
+		"geo::convex_hull" => geo::convex_hull,
 		"geo::distance" => geo::distance,
+		"geo::intersection" => geo::intersection,
+		"geo::length" => geo::length,
+		"geo::simplify" => geo::simplify,
+		"geo::union" => geo::union,
+		"geo::within_distance" => geo::within_distance,
 		"geo::hash::decode" => geo::hash::decode,
 		"geo::hash::encode" => geo::hash::encode,
+		"geo::wkb::decode" => geo::wkb::decode,
+		"geo::wkb::encode" => geo::wkb::encode,
+		"geo::wkt::decode" => geo::wkt::decode,
+		"geo::wkt::encode" => geo::wkt::encode,
 		//
 		"is::alphanum" => is::alphanum,
 		"is::alpha" => is::alpha,
@@ -191,6 +216,9 @@ pub fn synchronous(ctx: &Context<'_>, name: &str, args: Vec<Value>) -> Result<Va
 		"rand::uuid::v7" => rand::uuid::v7,
 		"rand::uuid" => rand::uuid,
 		//
+		"search::highlight" => search::highlight(ctx)(doc),
+		"search::score" => search::score(ctx)(doc),
+		//
 		"session::db" => session::db(ctx),
 		"session::id" => session::id(ctx),
 		"session::ip" => session::ip(ctx),
@@ -221,6 +249,8 @@ pub fn synchronous(ctx: &Context<'_>, name: &str, args: Vec<Value>) -> Result<Va
 		"time::format" => time::format,
 		"time::group" => time::group,
 		"time::hour" => time::hour,
+		"time::max" => time::max,
+		"time::min" => time::min,
 		"time::minute" => time::minute,
 		"time::month" => time::month,
 		"time::nano" => time::nano,
@@ -249,6 +279,13 @@ pub fn synchronous(ctx: &Context<'_>, name: &str, args: Vec<Value>) -> Result<Va
 		"type::string" => r#type::string,
 		"type::table" => r#type::table,
 		"type::thing" => r#type::thing,
+		//
+		"vector::distance::euclidean" => vector::distance::euclidean,
+		"vector::distance::manhattan" => vector::distance::manhattan,
+		"vector::dot" => vector::dot,
+		"vector::magnitude" => vector::magnitude,
+		"vector::normalize" => vector::normalize,
+		"vector::similarity::cosine" => vector::similarity::cosine,
 	)
 }
 
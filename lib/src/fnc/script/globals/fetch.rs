@@ -1,10 +1,30 @@
+use crate::dbs::ScriptOptions;
 use crate::sql::value::Value;
+use js::Func;
+use js::Object;
 use js::Rest;
 use js::Result;
+use std::sync::Arc;
 
-#[js::bind(object, public)]
-#[quickjs(rename = "fetch")]
-#[allow(unused_variables)]
-pub fn fetch(args: Rest<Value>) -> Result<Value> {
-	Ok(Value::None)
+/// Register the fetch function, restricted to the allowed hosts
+pub fn init(global: &Object<'_>, opt: Arc<ScriptOptions>) -> Result<()> {
+	global.set("fetch", Func::from(move |args: Rest<Value>| fetch(&opt, args.0)))
+}
+
+fn fetch(opt: &ScriptOptions, args: Vec<Value>) -> Result<Value> {
+	// Get the requested url
+	let url = match args.first() {
+		Some(Value::Strand(v)) => v.as_str(),
+		_ => "",
+	};
+	// Check if the url can be fetched
+	match opt.allows_fetch(url) {
+		true => Ok(Value::None),
+		false => Err(js::Error::Exception {
+			message: format!("Fetching '{url}' is not allowed"),
+			file: String::from(""),
+			line: -1,
+			stack: String::from(""),
+		}),
+	}
 }
@@ -4,6 +4,7 @@ use super::globals;
 use super::modules;
 use super::modules::loader;
 use super::modules::resolver;
+use super::modules::surrealdb::query::QueryContext;
 use crate::ctx::Context;
 use crate::dbs::Options;
 use crate::dbs::Transaction;
@@ -11,14 +12,16 @@ use crate::err::Error;
 use crate::sql::value::Value;
 use js::Function;
 use js::Module;
+use js::Object;
 use js::Promise;
 use js::Rest;
 use js::This;
+use trice::Instant;
 
 pub async fn run(
 	ctx: &Context<'_>,
-	_opt: &Options,
-	_txn: &Transaction,
+	opt: &Options,
+	txn: &Transaction,
 	doc: Option<&Value>,
 	src: &str,
 	arg: Vec<Value>,
@@ -27,21 +30,31 @@ pub async fn run(
 	if ctx.is_done() {
 		return Ok(Value::None);
 	}
+	// Get the script limits and capabilities
+	let cnf = opt.script.clone();
+	// Calculate any maximum execution time
+	let deadline = cnf.timeout().map(|v| Instant::now() + v);
+	// Ensure scripts are cancelled with context
+	let cancellation = match deadline {
+		Some(v) => ctx.cancellation().with_deadline(v),
+		None => ctx.cancellation(),
+	};
 	// Create a new agent
 	let exe = Executor::default();
+	// Allow queries in the calling transaction
+	let qry = QueryContext::new(ctx, opt, txn, cancellation.clone());
 	// Create an JavaScript context
 	let run = js::Runtime::new().unwrap();
-	// Explicitly set max stack size to 256 KiB
-	run.set_max_stack_size(262_144);
-	// Explicitly set max memory size to 2 MB
-	run.set_memory_limit(2_000_000);
-	// Ensure scripts are cancelled with context
-	let cancellation = ctx.cancellation();
+	// Explicitly set the max stack size
+	run.set_max_stack_size(cnf.stack_size());
+	// Explicitly set the max memory size
+	run.set_memory_limit(cnf.memory_limit());
+	// Ensure scripts are interrupted when cancelled
 	run.set_interrupt_handler(Some(Box::new(move || cancellation.is_done())));
 	// Create an execution context
 	let ctx = js::Context::full(&run).unwrap();
 	// Set the module resolver and loader
-	run.set_loader(resolver(), loader());
+	run.set_loader(resolver(&cnf), loader(&cnf));
 	// Enable async code in the runtime
 	run.spawn_executor(&exe).detach();
 	// Create the main function structure
@@ -52,15 +65,18 @@ pub async fn run(
 	let res: Result<Promise<Value>, js::Error> = ctx.with(|ctx| {
 		// Get the context global object
 		let global = ctx.globals();
+		// Load the surrealdb module default export
+		let surrealdb = Module::new_def::<modules::surrealdb::Package, _>(ctx, "surrealdb")?
+			.eval()?
+			.get::<_, Object>("default")?;
+		// Register the database query functions
+		modules::surrealdb::query::init(&surrealdb, qry)?;
 		// Register the surrealdb module as a global object
-		global.set(
-			"surrealdb",
-			Module::new_def::<modules::surrealdb::Package, _>(ctx, "surrealdb")?
-				.eval()?
-				.get::<_, js::Value>("default")?,
-		)?;
+		global.set("surrealdb", surrealdb)?;
 		// Register the fetch function to the globals
-		global.init_def::<globals::fetch::Fetch>()?;
+		if cnf.fetch() {
+			globals::fetch::init(&global, cnf.clone())?;
+		}
 		// Register the console function to the globals
 		global.init_def::<globals::console::Console>()?;
 		// Register the special SurrealDB types as classes
@@ -74,16 +90,28 @@ pub async fn run(
 		// Execute the main function
 		fnc.call((This(doc), Rest(arg)))
 	});
+	// Check if the script ran for too long
+	let timedout = || deadline.map_or(false, |v| v <= Instant::now());
 	// Return the script result
 	match res {
 		// The script executed successfully
 		Ok(v) => match exe.run(v).await {
 			// The promise fulfilled successfully
 			Ok(v) => Ok(v),
+			// The script exceeded the maximum execution time
+			Err(_) if timedout() => Err(timeout()),
 			// There was an error awaiting the promise
 			Err(e) => Err(Error::from(e)),
 		},
+		// The script exceeded the maximum execution time
+		Err(_) if timedout() => Err(timeout()),
 		// There was an error running the script
 		Err(e) => Err(Error::from(e)),
 	}
 }
+
+fn timeout() -> Error {
+	Error::InvalidScript {
+		message: String::from("The script exceeded the maximum execution time"),
+	}
+}
@@ -1,15 +1,22 @@
 pub mod os;
 pub mod surrealdb;
 
+use crate::dbs::ScriptOptions;
 use js::BuiltinResolver;
 use js::ModuleLoader;
 
-pub fn resolver() -> BuiltinResolver {
-	BuiltinResolver::default().with_module("os").with_module("surrealdb")
+pub fn resolver(opt: &ScriptOptions) -> BuiltinResolver {
+	let res = BuiltinResolver::default().with_module("surrealdb");
+	match opt.os() {
+		true => res.with_module("os"),
+		false => res,
+	}
 }
 
-pub fn loader() -> ModuleLoader {
-	ModuleLoader::default()
-		.with_module("os", os::Package)
-		.with_module("surrealdb", surrealdb::Package)
+pub fn loader(opt: &ScriptOptions) -> ModuleLoader {
+	let res = ModuleLoader::default().with_module("surrealdb", surrealdb::Package);
+	match opt.os() {
+		true => res.with_module("os", os::Package),
+		false => res,
+	}
 }
@@ -13,6 +13,8 @@ use js::Rest;
 use js::Result;
 
 mod hash;
+mod wkb;
+mod wkt;
 
 pub struct Package;
 
@@ -23,9 +25,20 @@ impl ModuleDef for Package {
 		module.add("default")?;
 		module.add("area")?;
 		module.add("bearing")?;
+		module.add("bounding_box")?;
+		module.add("buffer")?;
 		module.add("centroid")?;
+		module.add("contains")?;
+		module.add("convex_hull")?;
 		module.add("distance")?;
+		module.add("intersection")?;
+		module.add("length")?;
+		module.add("simplify")?;
+		module.add("union")?;
+		module.add("within_distance")?;
 		module.add("hash")?;
+		module.add("wkb")?;
+		module.add("wkt")?;
 		Ok(())
 	}
 
@@ -33,16 +46,38 @@ impl ModuleDef for Package {
 		// Set specific exports
 		module.set("area", Func::from(|v: Any| run("geo::area", v.0)))?;
 		module.set("bearing", Func::from(|v: Any| run("geo::bearing", v.0)))?;
+		module.set("bounding_box", Func::from(|v: Any| run("geo::bounding_box", v.0)))?;
+		module.set("buffer", Func::from(|v: Any| run("geo::buffer", v.0)))?;
 		module.set("centroid", Func::from(|v: Any| run("geo::centroid", v.0)))?;
+		module.set("contains", Func::from(|v: Any| run("geo::contains", v.0)))?;
+		module.set("convex_hull", Func::from(|v: Any| run("geo::convex_hull", v.0)))?;
 		module.set("distance", Func::from(|v: Any| run("geo::distance", v.0)))?;
+		module.set("intersection", Func::from(|v: Any| run("geo::intersection", v.0)))?;
+		module.set("length", Func::from(|v: Any| run("geo::length", v.0)))?;
+		module.set("simplify", Func::from(|v: Any| run("geo::simplify", v.0)))?;
+		module.set("union", Func::from(|v: Any| run("geo::union", v.0)))?;
+		module.set("within_distance", Func::from(|v: Any| run("geo::within_distance", v.0)))?;
 		module.set("hash", pkg::<hash::Package>(ctx, "hash"))?;
+		module.set("wkb", pkg::<wkb::Package>(ctx, "wkb"))?;
+		module.set("wkt", pkg::<wkt::Package>(ctx, "wkt"))?;
 		// Set default exports
 		let default = Object::new(ctx)?;
 		default.set("area", Func::from(|v: Any| run("geo::area", v.0)))?;
 		default.set("bearing", Func::from(|v: Any| run("geo::bearing", v.0)))?;
+		default.set("bounding_box", Func::from(|v: Any| run("geo::bounding_box", v.0)))?;
+		default.set("buffer", Func::from(|v: Any| run("geo::buffer", v.0)))?;
 		default.set("centroid", Func::from(|v: Any| run("geo::centroid", v.0)))?;
+		default.set("contains", Func::from(|v: Any| run("geo::contains", v.0)))?;
+		default.set("convex_hull", Func::from(|v: Any| run("geo::convex_hull", v.0)))?;
 		default.set("distance", Func::from(|v: Any| run("geo::distance", v.0)))?;
+		default.set("intersection", Func::from(|v: Any| run("geo::intersection", v.0)))?;
+		default.set("length", Func::from(|v: Any| run("geo::length", v.0)))?;
+		default.set("simplify", Func::from(|v: Any| run("geo::simplify", v.0)))?;
+		default.set("union", Func::from(|v: Any| run("geo::union", v.0)))?;
+		default.set("within_distance", Func::from(|v: Any| run("geo::within_distance", v.0)))?;
 		default.set("hash", pkg::<hash::Package>(ctx, "hash"))?;
+		default.set("wkb", pkg::<wkb::Package>(ctx, "wkb"))?;
+		default.set("wkt", pkg::<wkt::Package>(ctx, "wkt"))?;
 		module.set("default", default)?;
 		// Everything ok
 		Ok(())
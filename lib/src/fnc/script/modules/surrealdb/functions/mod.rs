@@ -27,6 +27,7 @@ mod session;
 mod string;
 mod time;
 mod r#type;
+mod vector;
 
 pub struct Package;
 
@@ -50,6 +51,7 @@ impl ModuleDef for Package {
 		module.add("string")?;
 		module.add("time")?;
 		module.add("type")?;
+		module.add("vector")?;
 		Ok(())
 	}
 
@@ -70,6 +72,7 @@ impl ModuleDef for Package {
 		module.set("string", pkg::<string::Package>(ctx, "string"))?;
 		module.set("time", pkg::<time::Package>(ctx, "time"))?;
 		module.set("type", pkg::<r#type::Package>(ctx, "type"))?;
+		module.set("vector", pkg::<vector::Package>(ctx, "vector"))?;
 		// Set default exports
 		let default = Object::new(ctx)?;
 		default.set("array", pkg::<array::Package>(ctx, "array"))?;
@@ -87,6 +90,7 @@ impl ModuleDef for Package {
 		default.set("string", pkg::<string::Package>(ctx, "string"))?;
 		default.set("time", pkg::<time::Package>(ctx, "time"))?;
 		default.set("type", pkg::<r#type::Package>(ctx, "type"))?;
+		default.set("vector", pkg::<vector::Package>(ctx, "vector"))?;
 		module.set("default", default)?;
 		// Everything ok
 		Ok(())
@@ -97,7 +101,7 @@ fn run(name: &str, args: Vec<Value>) -> Result<Value> {
 	// Create a default context
 	let ctx = Context::background();
 	// Process the called function
-	let res = fnc::synchronous(&ctx, name, args);
+	let res = fnc::synchronous(&ctx, None, name, args);
 	// Convert any response error
 	res.map_err(|err| js::Error::Exception {
 		message: err.to_string(),
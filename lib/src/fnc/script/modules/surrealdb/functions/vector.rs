@@ -0,0 +1,51 @@
+use super::super::pkg;
+use super::run;
+use crate::sql::value::Value;
+use js::Created;
+use js::Ctx;
+use js::Func;
+use js::Loaded;
+use js::Module;
+use js::ModuleDef;
+use js::Native;
+use js::Object;
+use js::Rest;
+use js::Result;
+
+mod distance;
+mod similarity;
+
+pub struct Package;
+
+type Any = Rest<Value>;
+
+impl ModuleDef for Package {
+	fn load<'js>(_ctx: Ctx<'js>, module: &Module<'js, Created>) -> Result<()> {
+		module.add("default")?;
+		module.add("dot")?;
+		module.add("magnitude")?;
+		module.add("normalize")?;
+		module.add("distance")?;
+		module.add("similarity")?;
+		Ok(())
+	}
+
+	fn eval<'js>(ctx: Ctx<'js>, module: &Module<'js, Loaded<Native>>) -> Result<()> {
+		// Set specific exports
+		module.set("dot", Func::from(|v: Any| run("vector::dot", v.0)))?;
+		module.set("magnitude", Func::from(|v: Any| run("vector::magnitude", v.0)))?;
+		module.set("normalize", Func::from(|v: Any| run("vector::normalize", v.0)))?;
+		module.set("distance", pkg::<distance::Package>(ctx, "distance"))?;
+		module.set("similarity", pkg::<similarity::Package>(ctx, "similarity"))?;
+		// Set default exports
+		let default = Object::new(ctx)?;
+		default.set("dot", Func::from(|v: Any| run("vector::dot", v.0)))?;
+		default.set("magnitude", Func::from(|v: Any| run("vector::magnitude", v.0)))?;
+		default.set("normalize", Func::from(|v: Any| run("vector::normalize", v.0)))?;
+		default.set("distance", pkg::<distance::Package>(ctx, "distance"))?;
+		default.set("similarity", pkg::<similarity::Package>(ctx, "similarity"))?;
+		module.set("default", default)?;
+		// Everything ok
+		Ok(())
+	}
+}
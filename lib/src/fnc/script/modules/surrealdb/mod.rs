@@ -10,6 +10,8 @@ use js::Value;
 
 mod functions;
 
+pub mod query;
+
 pub struct Package;
 
 impl ModuleDef for Package {
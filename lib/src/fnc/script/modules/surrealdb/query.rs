@@ -0,0 +1,177 @@
+use crate::cnf::PROTECTED_PARAM_NAMES;
+use crate::ctx::cancellation::Cancellation;
+use crate::ctx::Context;
+use crate::dbs::Attach;
+use crate::dbs::Options;
+use crate::dbs::Transaction;
+use crate::err::Error;
+use crate::sql::field::Fields;
+use crate::sql::statement::Statement;
+use crate::sql::statements::SelectStatement;
+use crate::sql::value::Value;
+use crate::sql::value::Values;
+use js::Async;
+use js::Func;
+use js::Object;
+use js::Opt;
+
+/// The state needed to run queries from within an embedded script, in the
+/// transaction, and with the options and permissions, of the calling statement.
+#[derive(Clone)]
+pub struct QueryContext {
+	opt: Options,
+	txn: Transaction,
+	values: Vec<(String, Value)>,
+	cancellation: Cancellation,
+}
+
+impl QueryContext {
+	pub fn new(
+		ctx: &Context<'_>,
+		opt: &Options,
+		txn: &Transaction,
+		cancellation: Cancellation,
+	) -> Self {
+		QueryContext {
+			opt: opt.clone(),
+			txn: txn.clone(),
+			values: PROTECTED_PARAM_NAMES
+				.iter()
+				.filter_map(|k| ctx.value(k).map(|v| (k.to_string(), v.clone())))
+				.collect(),
+			cancellation,
+		}
+	}
+
+	/// Run a SurrealQL query, returning the result of the last statement
+	pub async fn query(self, sql: String, vars: Option<Value>) -> Result<Value, Error> {
+		// Parse the SurrealQL query
+		let ast = crate::sql::parse(&sql)?;
+		// Create a new context which stops with the script
+		let mut ctx = Context::background();
+		ctx.add_cancellation(&self.cancellation);
+		// Attach the session values
+		for (k, v) in self.values {
+			ctx.add_value(k, v);
+		}
+		// Attach any query variables
+		let mut ctx = match vars {
+			Some(Value::Object(v)) => Some(v.0).attach(ctx)?,
+			Some(Value::None) | Some(Value::Null) | None => ctx,
+			Some(v) => {
+				return Err(Error::InvalidScript {
+					message: format!("Expected the query variables to be an object, but found {v}"),
+				})
+			}
+		};
+		// Process the query statements
+		let mut out = Value::None;
+		for stm in ast.0 .0 {
+			// Check if the script has been cancelled
+			if ctx.is_done() {
+				return Err(Error::QueryCancelled);
+			}
+			// Process the statement
+			out = match stm {
+				// Transaction and session statements are not allowed
+				Statement::Begin(_)
+				| Statement::Cancel(_)
+				| Statement::Commit(_)
+				| Statement::Option(_)
+				| Statement::Use(_) => {
+					return Err(Error::InvalidScriptStatement {
+						value: stm.to_string(),
+					})
+				}
+				// Store any query parameters
+				Statement::Set(stm) => {
+					// Check if the variable is a protected variable
+					if PROTECTED_PARAM_NAMES.contains(&stm.name.as_str()) {
+						return Err(Error::InvalidParam {
+							name: stm.name,
+						});
+					}
+					// Compute and set the parameter
+					let val = stm.compute(&ctx, &self.opt, &self.txn, None).await?;
+					ctx.add_value(stm.name, val);
+					Value::None
+				}
+				// Process all other statements
+				stm => stm.compute(&ctx, &self.opt, &self.txn, None).await?,
+			};
+		}
+		// Return the last result
+		Ok(out)
+	}
+
+	/// Select a record, or all of the records in a table or range
+	pub async fn select(self, what: Value) -> Result<Value, Error> {
+		// Check if the script has been cancelled
+		if self.cancellation.is_done() {
+			return Err(Error::QueryCancelled);
+		}
+		// Allow record ids to be specified as strings
+		let what = match what {
+			Value::Strand(v) => match crate::sql::thing(&v) {
+				Ok(v) => Value::Thing(v),
+				Err(_) => Value::Strand(v),
+			},
+			v => v,
+		};
+		// Only a single record is returned for a record id
+		let one = what.is_thing();
+		// Create the select statement
+		let stm = SelectStatement {
+			expr: Fields::all(),
+			what: Values(vec![what]),
+			..Default::default()
+		};
+		// Create a new context which stops with the script
+		let mut ctx = Context::background();
+		ctx.add_cancellation(&self.cancellation);
+		// Attach the session values
+		for (k, v) in self.values {
+			ctx.add_value(k, v);
+		}
+		// Process the select statement
+		let res = stm.compute(&ctx, &self.opt, &self.txn, None).await?;
+		// Return the selected records
+		match one {
+			true => Ok(res.first()),
+			false => Ok(res),
+		}
+	}
+}
+
+/// Register the database functions on the `surrealdb` object
+pub fn init(obj: &Object<'_>, qry: QueryContext) -> js::Result<()> {
+	// Run a SurrealQL query
+	let q = qry.clone();
+	obj.set(
+		"query",
+		Func::from(Async(move |sql: String, vars: Opt<Value>| {
+			let q = q.clone();
+			async move { q.query(sql, vars.0).await.map_err(err) }
+		})),
+	)?;
+	// Select one or more records
+	let q = qry;
+	obj.set(
+		"select",
+		Func::from(Async(move |what: Value| {
+			let q = q.clone();
+			async move { q.select(what).await.map_err(err) }
+		})),
+	)?;
+	// Everything ok
+	Ok(())
+}
+
+fn err(err: Error) -> js::Error {
+	js::Error::Exception {
+		message: err.to_string(),
+		file: String::from(""),
+		line: -1,
+		stack: String::from(""),
+	}
+}
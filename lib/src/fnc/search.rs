@@ -0,0 +1,41 @@
+use crate::ctx::Context;
+use crate::err::Error;
+use crate::sql::paths::ID;
+use crate::sql::value::Value;
+
+pub fn score(ctx: &Context, doc: Option<&Value>, (mr,): (Option<i64>,)) -> Result<Value, Error> {
+	match (ctx.get_query_executor(), doc.map(|v| v.pick(ID.as_ref()))) {
+		(Some(exe), Some(Value::Thing(rid))) => {
+			Ok(exe.score(&rid, reference("search::score", mr)?))
+		}
+		_ => Ok(Value::None),
+	}
+}
+
+pub fn highlight(
+	ctx: &Context,
+	doc: Option<&Value>,
+	(prefix, suffix, mr): (String, String, Option<i64>),
+) -> Result<Value, Error> {
+	match (ctx.get_query_executor(), doc) {
+		(Some(exe), Some(doc)) => match doc.pick(ID.as_ref()) {
+			Value::Thing(rid) => {
+				let mr = reference("search::highlight", mr)?;
+				exe.highlight(&rid, mr, doc, &prefix, &suffix)
+			}
+			_ => Ok(Value::None),
+		},
+		_ => Ok(Value::None),
+	}
+}
+
+/// Checks that a match reference argument is within the range of match references
+fn reference(name: &str, mr: Option<i64>) -> Result<u8, Error> {
+	match mr {
+		None => Ok(0),
+		Some(v) => u8::try_from(v).map_err(|_| Error::InvalidArguments {
+			name: name.to_owned(),
+			message: String::from("The match reference must be a number from 0 to 255."),
+		}),
+	}
+}
@@ -82,6 +82,20 @@ pub fn hour((val,): (Option<Datetime>,)) -> Result<Value, Error> {
 	})
 }
 
+pub fn max((array,): (Vec<Datetime>,)) -> Result<Value, Error> {
+	Ok(match array.into_iter().max_by(|a, b| a.0.cmp(&b.0)) {
+		Some(v) => v.into(),
+		None => Value::None,
+	})
+}
+
+pub fn min((array,): (Vec<Datetime>,)) -> Result<Value, Error> {
+	Ok(match array.into_iter().min_by(|a, b| a.0.cmp(&b.0)) {
+		Some(v) => v.into(),
+		None => Value::None,
+	})
+}
+
 pub fn minute((val,): (Option<Datetime>,)) -> Result<Value, Error> {
 	Ok(match val {
 		Some(v) => v.minute().into(),
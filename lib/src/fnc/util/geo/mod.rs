@@ -1,8 +1,12 @@
 use crate::sql::geometry::Geometry;
 use crate::sql::strand::Strand;
-use geo::Point;
+use geo::algorithm::closest_point::ClosestPoint;
+use geo::algorithm::coords_iter::CoordsIter;
+use geo::algorithm::haversine_distance::HaversineDistance;
+use geo::algorithm::intersects::Intersects;
+use geo::{Closest, Point};
 
-static BASE32: &[char] = &[
+pub static BASE32: &[char] = &[
 	'0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'j', 'k',
 	'm', 'n', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
 ];
@@ -99,3 +103,23 @@ pub fn decode(v: Strand) -> Geometry {
 
 	(x, y).into()
 }
+
+/// Computes the distance in metres between the closest points of two geometries
+pub fn distance(a: &geo::Geometry<f64>, b: &geo::Geometry<f64>) -> f64 {
+	// Intersecting geometries are no distance apart
+	if a.intersects(b) {
+		return 0.0;
+	}
+	// Find the closest point of each geometry to the vertices of the other
+	let ab = a.coords_iter().map(|v| closest(b, Point(v)));
+	let ba = b.coords_iter().map(|v| closest(a, Point(v)));
+	ab.chain(ba).fold(f64::INFINITY, f64::min)
+}
+
+fn closest(g: &geo::Geometry<f64>, p: Point<f64>) -> f64 {
+	match g.closest_point(&p) {
+		Closest::Intersection(_) => 0.0,
+		Closest::SinglePoint(v) => p.haversine_distance(&v),
+		Closest::Indeterminate => f64::INFINITY,
+	}
+}
@@ -0,0 +1,48 @@
+pub trait Vector {
+	/// Calculates the dot product of two vectors of the same dimension
+	fn dot(&self, other: &[f64]) -> f64;
+	/// Calculates the length of a vector
+	fn magnitude(&self) -> f64;
+	/// Scales a vector to a length of 1
+	fn normalize(&self) -> Vec<f64>;
+	/// Calculates the cosine of the angle between two vectors
+	fn cosine_similarity(&self, other: &[f64]) -> f64;
+	/// Calculates the straight-line distance between two vectors
+	fn euclidean_distance(&self, other: &[f64]) -> f64;
+	/// Calculates the sum of the absolute differences between two vectors
+	fn manhattan_distance(&self, other: &[f64]) -> f64;
+}
+
+impl Vector for [f64] {
+	fn dot(&self, other: &[f64]) -> f64 {
+		self.iter().zip(other).map(|(a, b)| a * b).sum()
+	}
+
+	fn magnitude(&self) -> f64 {
+		self.dot(self).sqrt()
+	}
+
+	fn normalize(&self) -> Vec<f64> {
+		let m = self.magnitude();
+		if m == 0.0 {
+			return self.to_vec();
+		}
+		self.iter().map(|v| v / m).collect()
+	}
+
+	fn cosine_similarity(&self, other: &[f64]) -> f64 {
+		let m = self.magnitude() * other.magnitude();
+		if m == 0.0 {
+			return 0.0;
+		}
+		self.dot(other) / m
+	}
+
+	fn euclidean_distance(&self, other: &[f64]) -> f64 {
+		self.iter().zip(other).map(|(a, b)| (a - b).powi(2)).sum::<f64>().sqrt()
+	}
+
+	fn manhattan_distance(&self, other: &[f64]) -> f64 {
+		self.iter().zip(other).map(|(a, b)| (a - b).abs()).sum()
+	}
+}
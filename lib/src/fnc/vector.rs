@@ -0,0 +1,63 @@
+use crate::err::Error;
+use crate::fnc::util::math::vector::Vector;
+use crate::sql::number::Number;
+use crate::sql::value::Value;
+
+/// Converts the arguments to vectors, checking that they have the same dimension
+fn vectors(name: &str, a: Vec<Number>, b: Vec<Number>) -> Result<(Vec<f64>, Vec<f64>), Error> {
+	if a.len() != b.len() {
+		return Err(Error::InvalidArguments {
+			name: String::from(name),
+			message: String::from("The two vectors must be of the same dimension."),
+		});
+	}
+	Ok((a.iter().map(Number::to_float).collect(), b.iter().map(Number::to_float).collect()))
+}
+
+pub fn dot((a, b): (Vec<Number>, Vec<Number>)) -> Result<Value, Error> {
+	let (a, b) = vectors("vector::dot", a, b)?;
+	Ok(a.dot(&b).into())
+}
+
+pub fn magnitude((a,): (Vec<Number>,)) -> Result<Value, Error> {
+	let a: Vec<f64> = a.iter().map(Number::to_float).collect();
+	Ok(a.magnitude().into())
+}
+
+pub fn normalize((a,): (Vec<Number>,)) -> Result<Value, Error> {
+	let a: Vec<f64> = a.iter().map(Number::to_float).collect();
+	Ok(a.normalize().into_iter().map(Value::from).collect::<Vec<_>>().into())
+}
+
+pub mod distance {
+
+	use super::vectors;
+	use crate::err::Error;
+	use crate::fnc::util::math::vector::Vector;
+	use crate::sql::number::Number;
+	use crate::sql::value::Value;
+
+	pub fn euclidean((a, b): (Vec<Number>, Vec<Number>)) -> Result<Value, Error> {
+		let (a, b) = vectors("vector::distance::euclidean", a, b)?;
+		Ok(a.euclidean_distance(&b).into())
+	}
+
+	pub fn manhattan((a, b): (Vec<Number>, Vec<Number>)) -> Result<Value, Error> {
+		let (a, b) = vectors("vector::distance::manhattan", a, b)?;
+		Ok(a.manhattan_distance(&b).into())
+	}
+}
+
+pub mod similarity {
+
+	use super::vectors;
+	use crate::err::Error;
+	use crate::fnc::util::math::vector::Vector;
+	use crate::sql::number::Number;
+	use crate::sql::value::Value;
+
+	pub fn cosine((a, b): (Vec<Number>, Vec<Number>)) -> Result<Value, Error> {
+		let (a, b) = vectors("vector::similarity::cosine", a, b)?;
+		Ok(a.cosine_similarity(&b).into())
+	}
+}
@@ -1,4 +1,4 @@
-use base64::alphabet::STANDARD;
+use base64::alphabet::{STANDARD, URL_SAFE};
 use base64::engine::general_purpose::GeneralPurpose;
 use base64::engine::general_purpose::GeneralPurposeConfig;
 use base64::engine::DecodePaddingMode;
@@ -7,6 +7,8 @@ pub use base64::Engine;
 
 pub const BASE64: GeneralPurpose = GeneralPurpose::new(&STANDARD, CONFIG);
 
+pub const BASE64_URL: GeneralPurpose = GeneralPurpose::new(&URL_SAFE, CONFIG);
+
 pub const CONFIG: GeneralPurposeConfig = GeneralPurposeConfig::new()
 	.with_encode_padding(false)
 	.with_decode_padding_mode(DecodePaddingMode::Indifferent);
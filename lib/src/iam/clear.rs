@@ -0,0 +1,15 @@
+use crate::dbs::Auth;
+use crate::dbs::Session;
+use crate::err::Error;
+use crate::sql::Role;
+use std::sync::Arc;
+
+pub async fn clear(session: &mut Session) -> Result<(), Error> {
+	session.au = Arc::new(Auth::No);
+	session.rl = Role::Owner;
+	session.us = None;
+	session.tk = None;
+	session.sc = None;
+	session.sd = None;
+	Ok(())
+}
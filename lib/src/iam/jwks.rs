@@ -0,0 +1,246 @@
+use crate::cnf::{JWKS_CACHE_DURATION, JWKS_REFRESH_INTERVAL};
+use crate::err::Error;
+use crate::iam::base::{Engine, BASE64_URL};
+use crate::iam::LOG;
+use chrono::Utc;
+use jsonwebtoken::jwk::{AlgorithmParameters, Jwk, JwkSet};
+use jsonwebtoken::{DecodingKey, Header, Validation};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// A key set, along with the time it was loaded
+type Cached = (i64, Arc<JwkSet>);
+
+/// The key sets which have been loaded
+static CACHE: Lazy<Mutex<HashMap<String, Cached>>> = Lazy::new(Default::default);
+
+/// Finds the key with which a token was signed, in the key set specified
+/// by a JWKS token definition, returning the key and the validation rules
+///
+/// If the key set does not contain the key identified by the `kid` header of
+/// the token, then the key set is loaded again, as the keys may have rotated.
+/// Key sets can only be loaded from a URL or a local file if the source is
+/// one of the allowed sources configured on the datastore.
+pub(super) async fn config(
+	allowed: &[String],
+	src: &str,
+	header: &Header,
+) -> Result<(DecodingKey, Validation), Error> {
+	find(allowed, src, header, Utc::now().timestamp()).await
+}
+
+async fn find(
+	allowed: &[String],
+	src: &str,
+	header: &Header,
+	now: i64,
+) -> Result<(DecodingKey, Validation), Error> {
+	// The key id is required to select a key
+	let kid = match &header.kid {
+		Some(kid) => kid,
+		None => {
+			trace!(target: LOG, "The token does not specify a 'kid' header");
+			return Err(Error::InvalidAuth);
+		}
+	};
+	// Check if the key set can be loaded from this source
+	if !allows(allowed, src) {
+		warn!(target: LOG, "The JSON Web Key Set source `{}` is not allowed", src);
+		return Err(Error::InvalidAuth);
+	}
+	// Check for the key in the cached key set
+	let (time, set) = match cached(src, now) {
+		Some(v) => v,
+		None => (now, refresh(src, now).await?),
+	};
+	let set = match set.find(kid) {
+		Some(_) => set,
+		// Load the key set again if the key might have been rotated
+		None if now - time >= JWKS_REFRESH_INTERVAL => refresh(src, now).await?,
+		None => set,
+	};
+	let jwk = match set.find(kid) {
+		Some(jwk) => jwk,
+		None => {
+			trace!(target: LOG, "The key `{}` was not found in the JSON Web Key Set", kid);
+			return Err(Error::InvalidAuth);
+		}
+	};
+	// Prefer the algorithm specified by the key
+	let alg = jwk.common.algorithm.unwrap_or(header.alg);
+	Ok((decoding_key(jwk)?, Validation::new(alg)))
+}
+
+/// Converts a JSON Web Key into a decoding key
+fn decoding_key(jwk: &Jwk) -> Result<DecodingKey, Error> {
+	match &jwk.algorithm {
+		// Symmetric keys are encoded using unpadded base64url
+		AlgorithmParameters::OctetKey(v) => {
+			Ok(DecodingKey::from_secret(&BASE64_URL.decode(&v.value)?))
+		}
+		_ => Ok(DecodingKey::from_jwk(jwk)?),
+	}
+}
+
+/// Returns the cached key set, if it has not expired
+fn cached(src: &str, now: i64) -> Option<Cached> {
+	let cache = CACHE.lock().unwrap_or_else(|e| e.into_inner());
+	match cache.get(src) {
+		Some((time, set)) if now - time < JWKS_CACHE_DURATION => Some((*time, set.clone())),
+		_ => None,
+	}
+}
+
+/// Loads the key set, and stores it in the cache
+async fn refresh(src: &str, now: i64) -> Result<Arc<JwkSet>, Error> {
+	let set = match load(src).await {
+		Ok(set) => Arc::new(set),
+		Err(e) => {
+			warn!(target: LOG, "Unable to load the JSON Web Key Set: {}", e);
+			return Err(Error::InvalidAuth);
+		}
+	};
+	let mut cache = CACHE.lock().unwrap_or_else(|e| e.into_inner());
+	cache.insert(src.to_owned(), (now, set.clone()));
+	Ok(set)
+}
+
+/// Loads a key set from inline JSON, a URL, or a local file
+async fn load(src: &str) -> Result<JwkSet, String> {
+	let src = src.trim();
+	// Check if this is an inline key set
+	if src.starts_with('{') {
+		return serde_json::from_str(src).map_err(|e| e.to_string());
+	}
+	// Check if this is a remote key set
+	if src.starts_with("http://") || src.starts_with("https://") {
+		return fetch(src).await;
+	}
+	// Otherwise this is a local file
+	let path = src.strip_prefix("file://").unwrap_or(src);
+	let txt = read(path).await?;
+	serde_json::from_str(&txt).map_err(|e| e.to_string())
+}
+
+/// Checks if a key set can be loaded from a source, either because it is
+/// inline, because it is one of the allowed sources, or because it is
+/// inside an allowed source ending with a `/`
+fn allows(allowed: &[String], src: &str) -> bool {
+	let src = src.trim();
+	// Inline key sets are always allowed
+	if src.starts_with('{') {
+		return true;
+	}
+	// Don't allow escaping an allowed directory
+	if src.split('/').any(|v| v == "..") {
+		return false;
+	}
+	allowed.iter().any(|v| src == v || (v.ends_with('/') && src.starts_with(v.as_str())))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn read(path: &str) -> Result<String, String> {
+	tokio::fs::read_to_string(path).await.map_err(|e| e.to_string())
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn read(_: &str) -> Result<String, String> {
+	Err("loading a key set from a local file is not supported".to_owned())
+}
+
+#[cfg(feature = "http")]
+async fn fetch(url: &str) -> Result<JwkSet, String> {
+	let res = reqwest::get(url).await.map_err(|e| e.to_string())?;
+	let res = res.error_for_status().map_err(|e| e.to_string())?;
+	res.json().await.map_err(|e| e.to_string())
+}
+
+#[cfg(not(feature = "http"))]
+async fn fetch(_: &str) -> Result<JwkSet, String> {
+	Err("fetching a key set from a URL requires the `http` feature".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use jsonwebtoken::Algorithm;
+
+	fn jwks(kids: &[&str]) -> String {
+		let keys: Vec<String> = kids
+			.iter()
+			.map(|kid| {
+				format!(r#"{{ "kty": "oct", "kid": "{kid}", "alg": "HS256", "k": "c2VjcmV0" }}"#)
+			})
+			.collect();
+		format!(r#"{{ "keys": [{}] }}"#, keys.join(","))
+	}
+
+	fn header(kid: Option<&str>) -> Header {
+		let mut header = Header::new(Algorithm::HS256);
+		header.kid = kid.map(str::to_owned);
+		header
+	}
+
+	#[tokio::test]
+	async fn finds_key_by_kid() {
+		let src = jwks(&["first"]);
+		assert!(config(&[], &src, &header(Some("first"))).await.is_ok());
+		assert!(config(&[], &src, &header(Some("other"))).await.is_err());
+		assert!(config(&[], &src, &header(None)).await.is_err());
+	}
+
+	#[tokio::test]
+	async fn rejects_invalid_source() {
+		let allowed = ["/this/file/does/not/exist.json".to_owned()];
+		assert!(config(&allowed, "{ invalid }", &header(Some("first"))).await.is_err());
+		let src = "/this/file/does/not/exist.json";
+		assert!(config(&allowed, src, &header(Some("first"))).await.is_err());
+	}
+
+	#[tokio::test]
+	async fn rejects_sources_not_allowed() {
+		let path = std::env::temp_dir().join("surrealdb-jwks-allowed-test.json");
+		let src = format!("file://{}", path.display());
+		std::fs::write(&path, jwks(&["first"])).unwrap();
+		// Sources must be allowed explicitly
+		assert!(config(&[], &src, &header(Some("first"))).await.is_err());
+		let allowed = [src.clone()];
+		assert!(config(&allowed, &src, &header(Some("first"))).await.is_ok());
+		// Cached key sets must be allowed too
+		assert!(config(&[], &src, &header(Some("first"))).await.is_err());
+		// Sources can be allowed by directory
+		let dir = [format!("file://{}/", std::env::temp_dir().display())];
+		assert!(allows(&dir, &src));
+		assert!(!allows(&dir, &format!("{}../etc/passwd", dir[0])));
+		assert!(!allows(&[], "https://example.com/jwks.json"));
+		assert!(!allows(&["https://example.com".to_owned()], "https://example.com.evil.com"));
+		assert!(allows(&[], &jwks(&["first"])));
+		std::fs::remove_file(&path).unwrap();
+	}
+
+	#[tokio::test]
+	async fn reloads_rotated_keys() {
+		let path = std::env::temp_dir().join("surrealdb-jwks-rotation-test.json");
+		let src = format!("file://{}", path.display());
+		let allowed = [src.clone()];
+		std::fs::write(&path, jwks(&["first"])).unwrap();
+		assert!(find(&allowed, &src, &header(Some("first")), 1000).await.is_ok());
+		// The key set is rotated
+		std::fs::write(&path, jwks(&["second"])).unwrap();
+		// The cached key set is used for known keys
+		assert!(find(&allowed, &src, &header(Some("first")), 1005).await.is_ok());
+		// The key set is not loaded again too often
+		assert!(find(&allowed, &src, &header(Some("second")), 1005).await.is_err());
+		// The key set is loaded again for an unknown key
+		assert!(find(&allowed, &src, &header(Some("second")), 1010).await.is_ok());
+		assert!(find(&allowed, &src, &header(Some("first")), 1010).await.is_err());
+		// The key set is loaded again once the cache expires
+		std::fs::write(&path, jwks(&["first"])).unwrap();
+		assert!(find(&allowed, &src, &header(Some("first")), 1010 + JWKS_CACHE_DURATION)
+			.await
+			.is_ok());
+		std::fs::remove_file(&path).unwrap();
+	}
+}
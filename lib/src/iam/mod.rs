@@ -0,0 +1,20 @@
+//! Authentication of sessions, using root, namespace, database,
+//! and scope credentials, or a JSON Web Token
+
+pub mod base;
+pub mod clear;
+mod jwks;
+pub mod parse;
+pub mod signin;
+pub mod signup;
+pub mod token;
+pub mod verify;
+
+pub(crate) const LOG: &str = "surrealdb::iam";
+
+/// The credentials of the root user, when root authentication is enabled
+#[derive(Clone, Copy, Debug)]
+pub struct Root<'a> {
+	pub user: &'a str,
+	pub pass: &'a str,
+}
@@ -1,8 +1,8 @@
 use crate::err::Error;
 use crate::iam::base::{Engine, BASE64};
+use crate::sql::json;
+use crate::sql::Value;
 use std::str;
-use surrealdb::sql::json;
-use surrealdb::sql::Value;
 
 pub fn parse(value: &str) -> Result<Value, Error> {
 	// Extract the middle part of the token
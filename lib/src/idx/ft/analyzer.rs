@@ -0,0 +1,209 @@
+use crate::idx::ft::stemmer;
+use crate::sql::filter::{Filter, Language};
+use crate::sql::statements::DefineAnalyzerStatement;
+use crate::sql::tokenizer::Tokenizer;
+
+/// A term produced by an analyzer, along with the
+/// character offsets of the text it was produced from
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Term {
+	pub(crate) text: String,
+	pub(crate) start: u32,
+	pub(crate) end: u32,
+}
+
+/// An Analyzer splits a text into tokens, and then
+/// passes each token through a chain of filters
+pub(crate) struct Analyzer {
+	tokenizers: Vec<Tokenizer>,
+	filters: Vec<Filter>,
+}
+
+impl Default for Analyzer {
+	/// The analyzer used when a text is not indexed
+	fn default() -> Self {
+		Self {
+			tokenizers: vec![Tokenizer::Blank, Tokenizer::Class],
+			filters: vec![Filter::Lowercase],
+		}
+	}
+}
+
+impl From<&DefineAnalyzerStatement> for Analyzer {
+	fn from(az: &DefineAnalyzerStatement) -> Self {
+		Self {
+			tokenizers: az.tokenizers.clone().unwrap_or_default(),
+			filters: az.filters.clone().unwrap_or_default(),
+		}
+	}
+}
+
+#[derive(PartialEq)]
+enum Class {
+	Alpha,
+	Digit,
+	Punct,
+	Other,
+}
+
+impl Analyzer {
+	/// Splits a text into terms
+	pub(crate) fn analyze(&self, text: &str) -> Vec<Term> {
+		let mut out = Vec::new();
+		for t in self.tokenize(text) {
+			self.filter(t, 0, &mut out);
+		}
+		out
+	}
+
+	/// Splits a text into tokens
+	fn tokenize(&self, text: &str) -> Vec<Term> {
+		let blank = self.tokenizers.contains(&Tokenizer::Blank);
+		let camel = self.tokenizers.contains(&Tokenizer::Camel);
+		let class = self.tokenizers.contains(&Tokenizer::Class);
+		let punct = self.tokenizers.contains(&Tokenizer::Punct);
+		let mut res = Vec::new();
+		let mut cur = String::new();
+		let mut beg = 0;
+		let mut prev: Option<char> = None;
+		for (i, c) in text.chars().enumerate() {
+			let i = i as u32;
+			// Check if this character separates tokens
+			let skip = (blank || class) && c.is_whitespace() || punct && Self::is_punct(c);
+			// Check if a new token starts at this character
+			let split = skip
+				|| prev.map_or(false, |p| {
+					class && Self::class(p) != Self::class(c)
+						|| camel && p.is_lowercase() && c.is_uppercase()
+				});
+			if split && !cur.is_empty() {
+				res.push(Term {
+					text: std::mem::take(&mut cur),
+					start: beg,
+					end: i,
+				});
+			}
+			if skip {
+				prev = None;
+				continue;
+			}
+			if cur.is_empty() {
+				beg = i;
+			}
+			cur.push(c);
+			prev = Some(c);
+		}
+		if !cur.is_empty() {
+			res.push(Term {
+				text: cur,
+				start: beg,
+				end: text.chars().count() as u32,
+			});
+		}
+		res
+	}
+
+	/// Passes a token through the filters, starting at the specified filter
+	fn filter(&self, mut t: Term, from: usize, out: &mut Vec<Term>) {
+		for (i, f) in self.filters.iter().enumerate().skip(from) {
+			match f {
+				Filter::Ascii => t.text = ascii::any_ascii(&t.text),
+				Filter::Lowercase => t.text = t.text.to_lowercase(),
+				Filter::Uppercase => t.text = t.text.to_uppercase(),
+				Filter::Snowball(Language::English) => t.text = stemmer::english(&t.text),
+				Filter::EdgeNgram(min, max) => {
+					let chars: Vec<char> = t.text.chars().collect();
+					let min = (*min as usize).max(1);
+					let max = (*max as usize).min(chars.len());
+					for n in min..=max {
+						let g = Term {
+							text: chars[..n].iter().collect(),
+							..t.clone()
+						};
+						self.filter(g, i + 1, out);
+					}
+					return;
+				}
+			}
+			if t.text.is_empty() {
+				return;
+			}
+		}
+		out.push(t);
+	}
+
+	fn is_punct(c: char) -> bool {
+		c.is_ascii_punctuation() || matches!(c, '‘' | '’' | '“' | '”' | '…' | '–' | '—')
+	}
+
+	fn class(c: char) -> Class {
+		if c.is_alphabetic() {
+			Class::Alpha
+		} else if c.is_numeric() {
+			Class::Digit
+		} else if Self::is_punct(c) {
+			Class::Punct
+		} else {
+			Class::Other
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	fn terms(az: &Analyzer, text: &str) -> Vec<String> {
+		az.analyze(text).into_iter().map(|t| t.text).collect()
+	}
+
+	#[test]
+	fn analyze_default() {
+		let az = Analyzer::default();
+		let res = az.analyze("Hello World2023");
+		assert_eq!(
+			res,
+			vec![
+				Term {
+					text: "hello".into(),
+					start: 0,
+					end: 5,
+				},
+				Term {
+					text: "world".into(),
+					start: 6,
+					end: 11,
+				},
+				Term {
+					text: "2023".into(),
+					start: 11,
+					end: 15,
+				},
+			]
+		);
+	}
+
+	#[test]
+	fn analyze_camel_punct() {
+		let az = Analyzer {
+			tokenizers: vec![Tokenizer::Camel, Tokenizer::Punct],
+			filters: vec![Filter::Lowercase],
+		};
+		assert_eq!(terms(&az, "helloWorld,fooBar"), vec!["hello", "world", "foo", "bar"]);
+	}
+
+	#[test]
+	fn analyze_filters() {
+		let az = Analyzer {
+			tokenizers: vec![Tokenizer::Blank],
+			filters: vec![Filter::Ascii, Filter::Lowercase, Filter::Snowball(Language::English)],
+		};
+		assert_eq!(terms(&az, "Café RUNNING Ponies"), vec!["cafe", "run", "poni"]);
+		let az = Analyzer {
+			tokenizers: vec![Tokenizer::Blank],
+			filters: vec![Filter::EdgeNgram(2, 3), Filter::Uppercase],
+		};
+		assert_eq!(terms(&az, "abcd e"), vec!["AB", "ABC"]);
+	}
+}
@@ -0,0 +1,265 @@
+pub(crate) mod analyzer;
+mod stemmer;
+
+use crate::err::Error;
+use crate::idx::ft::analyzer::Analyzer;
+use crate::key::{bd, bf, bs};
+use crate::kvs;
+use crate::sql::array::Array;
+use crate::sql::id::Id;
+use crate::sql::index::{Index, Scoring};
+use crate::sql::statements::DefineIndexStatement;
+use crate::sql::value::Value;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A record which matched a full-text search
+pub(crate) struct Hit {
+	/// The BM25 relevance score of the record
+	pub(crate) score: f64,
+	/// The character offsets of the matched terms
+	pub(crate) offsets: Vec<(u32, u32)>,
+}
+
+/// The idf and tf of each matched term, and the offsets of the terms
+type Matched = (Vec<(f64, f64)>, Vec<(u32, u32)>);
+
+/// A full-text search index, storing the postings of every
+/// term found in the indexed field, along with the document
+/// lengths and statistics needed to score the search results
+pub(crate) struct FtIndex<'a> {
+	ns: &'a str,
+	db: &'a str,
+	ix: &'a DefineIndexStatement,
+	az: Analyzer,
+	k1: f64,
+	b: f64,
+	hl: bool,
+}
+
+impl<'a> FtIndex<'a> {
+	/// Loads the analyzer for a search index
+	pub(crate) async fn new(
+		run: &mut kvs::Transaction,
+		ns: &'a str,
+		db: &'a str,
+		ix: &'a DefineIndexStatement,
+	) -> Result<FtIndex<'a>, Error> {
+		match &ix.index {
+			Index::Search {
+				az,
+				sc: Scoring::Bm {
+					k1,
+					b,
+				},
+				hl,
+			} => Ok(FtIndex {
+				ns,
+				db,
+				ix,
+				az: Analyzer::from(&run.get_az(ns, db, az).await?),
+				k1: k1.to_float(),
+				b: b.to_float(),
+				hl: *hl,
+			}),
+			_ => unreachable!(),
+		}
+	}
+
+	/// Returns the text to be indexed from a field value
+	pub(crate) fn text(v: &Value) -> Option<String> {
+		match v {
+			Value::Strand(v) => Some(v.as_str().to_owned()),
+			Value::Array(v) => {
+				let v: Vec<&str> = v
+					.iter()
+					.filter_map(|v| match v {
+						Value::Strand(v) => Some(v.as_str()),
+						_ => None,
+					})
+					.collect();
+				Some(v.join(" "))
+			}
+			_ => None,
+		}
+	}
+
+	/// Indexes the text of a record, replacing any previously indexed text
+	pub(crate) async fn index(
+		&self,
+		run: &mut kvs::Transaction,
+		id: &Id,
+		text: Option<String>,
+	) -> Result<(), Error> {
+		let tb = self.ix.what.as_str();
+		let ix = self.ix.name.as_str();
+		// Load the index statistics
+		let (mut docs, mut len) = self.stats(run).await?;
+		// Remove the previously indexed terms
+		let key = bd::new(self.ns, self.db, tb, ix, id);
+		if let Some(v) = run.get(key.clone()).await? {
+			let v = Value::from(v);
+			if let Value::Array(terms) = v.pick(&["terms".into()]) {
+				for tm in terms.iter() {
+					let tm = tm.to_raw_string();
+					run.del(bf::new(self.ns, self.db, tb, ix, &tm, id)).await?;
+				}
+			}
+			docs = docs.saturating_sub(1);
+			len = len.saturating_sub(Self::len(&v));
+			run.del(key.clone()).await?;
+		}
+		// Index the new terms
+		if let Some(text) = text {
+			// Collect the offsets of each term
+			let mut terms: BTreeMap<String, Vec<(u32, u32)>> = BTreeMap::new();
+			let found = self.az.analyze(&text);
+			let count = found.len() as u64;
+			for t in found {
+				terms.entry(t.text).or_default().push((t.start, t.end));
+			}
+			// Store the postings for each term
+			for (tm, offsets) in terms.iter() {
+				let val = match self.hl {
+					true => Value::from(
+						offsets
+							.iter()
+							.map(|(s, e)| Value::from(vec![Value::from(*s), Value::from(*e)]))
+							.collect::<Vec<_>>(),
+					),
+					false => Value::from(offsets.len()),
+				};
+				run.set(bf::new(self.ns, self.db, tb, ix, tm, id), val).await?;
+			}
+			// Store the terms of the document
+			let val = Value::from(map! {
+				String::from("len") => Value::from(count),
+				String::from("terms") => Value::from(terms.into_keys().map(Value::from).collect::<Vec<_>>()),
+			});
+			run.set(key, val).await?;
+			docs += 1;
+			len += count;
+		}
+		// Store the index statistics
+		let val = Value::from(map! {
+			String::from("docs") => Value::from(docs),
+			String::from("len") => Value::from(len),
+		});
+		run.set(bs::new(self.ns, self.db, tb, ix), val).await?;
+		Ok(())
+	}
+
+	/// Finds the records which contain every term of the query
+	pub(crate) async fn search(
+		&self,
+		run: &mut kvs::Transaction,
+		query: &str,
+	) -> Result<BTreeMap<Id, Hit>, Error> {
+		let tb = self.ix.what.as_str();
+		let ix = self.ix.name.as_str();
+		// Analyze the query
+		let terms: BTreeSet<String> = self.az.analyze(query).into_iter().map(|t| t.text).collect();
+		// Load the index statistics
+		let (docs, len) = self.stats(run).await?;
+		let avg = match docs {
+			0 => 1.0,
+			_ => len as f64 / docs as f64,
+		};
+		// Collect the records matching every term
+		let mut res: Option<BTreeMap<Id, Matched>> = None;
+		for tm in terms.iter() {
+			let beg = bf::prefix(self.ns, self.db, tb, ix, tm);
+			let end = bf::suffix(self.ns, self.db, tb, ix, tm);
+			let postings = run.getr(beg..end, u32::MAX).await?;
+			// Compute the inverse document frequency of the term
+			let n = postings.len() as f64;
+			let idf = ((docs as f64 - n + 0.5) / (n + 0.5) + 1.0).ln();
+			// Collect the term frequency of each record
+			let mut found = BTreeMap::new();
+			for (k, v) in postings {
+				let key: bf::Bf = (&k).into();
+				let (tf, offsets) = match Value::from(v) {
+					Value::Array(v) => (v.len(), Self::offsets(v)),
+					v => (Self::num(&v) as usize, vec![]),
+				};
+				found.insert(key.id, (idf, tf as f64, offsets));
+			}
+			// Keep the records which matched the previous terms
+			res = Some(match res {
+				None => found
+					.into_iter()
+					.map(|(id, (idf, tf, offsets))| (id, (vec![(idf, tf)], offsets)))
+					.collect(),
+				Some(mut prev) => {
+					prev.retain(|id, _| found.contains_key(id));
+					for (id, (scores, offsets)) in prev.iter_mut() {
+						if let Some((idf, tf, mut o)) = found.remove(id) {
+							scores.push((idf, tf));
+							offsets.append(&mut o);
+						}
+					}
+					prev
+				}
+			});
+		}
+		// Score each of the matching records
+		let mut hits = BTreeMap::new();
+		for (id, (scores, mut offsets)) in res.unwrap_or_default() {
+			let key = bd::new(self.ns, self.db, tb, ix, &id);
+			let dl = match run.get(key).await? {
+				Some(v) => Self::len(&Value::from(v)) as f64,
+				None => continue,
+			};
+			let score = scores
+				.iter()
+				.map(|(idf, tf)| {
+					idf * (tf * (self.k1 + 1.0))
+						/ (tf + self.k1 * (1.0 - self.b + self.b * dl / avg))
+				})
+				.sum();
+			offsets.sort_unstable();
+			offsets.dedup();
+			hits.insert(
+				id,
+				Hit {
+					score,
+					offsets,
+				},
+			);
+		}
+		Ok(hits)
+	}
+
+	/// Returns the number of documents, and the total number of terms in the index
+	async fn stats(&self, run: &mut kvs::Transaction) -> Result<(u64, u64), Error> {
+		let key = bs::new(self.ns, self.db, &self.ix.what, &self.ix.name);
+		Ok(match run.get(key).await? {
+			Some(v) => {
+				let v = Value::from(v);
+				(Self::num(&v.pick(&["docs".into()])), Self::len(&v))
+			}
+			None => (0, 0),
+		})
+	}
+
+	fn len(v: &Value) -> u64 {
+		Self::num(&v.pick(&["len".into()]))
+	}
+
+	fn num(v: &Value) -> u64 {
+		match v {
+			Value::Number(v) => v.to_int() as u64,
+			_ => 0,
+		}
+	}
+
+	fn offsets(v: Array) -> Vec<(u32, u32)> {
+		v.into_iter()
+			.filter_map(|v| match v {
+				Value::Array(v) if v.len() == 2 => {
+					Some((Self::num(&v[0]) as u32, Self::num(&v[1]) as u32))
+				}
+				_ => None,
+			})
+			.collect()
+	}
+}
@@ -0,0 +1,336 @@
+//! An implementation of the English (Porter2) snowball stemmer,
+//! as described at https://snowballstem.org/algorithms/english/stemmer.html
+
+/// Returns the stem of a lowercase English word
+pub(super) fn english(word: &str) -> String {
+	// Short words are left unchanged
+	if word.chars().count() <= 2 {
+		return word.to_owned();
+	}
+	// Remove any initial apostrophe
+	let word = word.replace('’', "'");
+	let word = word.strip_prefix('\'').unwrap_or(&word);
+	// Check for exceptional forms
+	if let Some(v) = exception1(word) {
+		return v.to_owned();
+	}
+	// Mark the y characters used as consonants
+	let mut w = mark_ys(word);
+	// Find the R1 and R2 regions
+	let r1 = region1(&w);
+	let r2 = region(&w, r1);
+	// Run each of the steps
+	step0(&mut w);
+	step1a(&mut w);
+	if !matches!(
+		w.as_str(),
+		"inning" | "outing" | "canning" | "herring" | "earring" | "proceed" | "exceed" | "succeed"
+	) {
+		step1b(&mut w, r1);
+		step1c(&mut w);
+		step2(&mut w, r1);
+		step3(&mut w, r1, r2);
+		step4(&mut w, r2);
+		step5(&mut w, r1, r2);
+	}
+	// Revert the marked y characters
+	w.replace('Y', "y")
+}
+
+fn exception1(w: &str) -> Option<&'static str> {
+	Some(match w {
+		"skis" => "ski",
+		"skies" => "sky",
+		"dying" => "die",
+		"lying" => "lie",
+		"tying" => "tie",
+		"idly" => "idl",
+		"gently" => "gentl",
+		"ugly" => "ugli",
+		"early" => "earli",
+		"only" => "onli",
+		"singly" => "singl",
+		"sky" => "sky",
+		"news" => "news",
+		"howe" => "howe",
+		"atlas" => "atlas",
+		"cosmos" => "cosmos",
+		"bias" => "bias",
+		"andes" => "andes",
+		_ => return None,
+	})
+}
+
+fn is_vowel(c: u8) -> bool {
+	matches!(c, b'a' | b'e' | b'i' | b'o' | b'u' | b'y')
+}
+
+fn is_double(w: &[u8]) -> bool {
+	match w {
+		[.., a, b] if a == b => {
+			matches!(a, b'b' | b'd' | b'f' | b'g' | b'm' | b'n' | b'p' | b'r' | b't')
+		}
+		_ => false,
+	}
+}
+
+fn is_li_ending(c: u8) -> bool {
+	matches!(c, b'c' | b'd' | b'e' | b'g' | b'h' | b'k' | b'm' | b'n' | b'r' | b't')
+}
+
+fn mark_ys(w: &str) -> String {
+	let mut out = Vec::with_capacity(w.len());
+	for (i, &c) in w.as_bytes().iter().enumerate() {
+		if c == b'y' && (i == 0 || is_vowel(out[i - 1])) {
+			out.push(b'Y');
+		} else {
+			out.push(c);
+		}
+	}
+	String::from_utf8(out).unwrap_or_else(|_| w.to_owned())
+}
+
+/// The region after the first non-vowel following a vowel
+fn region(w: &str, from: usize) -> usize {
+	let b = w.as_bytes();
+	for i in from + 1..b.len() {
+		if !is_vowel(b[i]) && is_vowel(b[i - 1]) {
+			return i + 1;
+		}
+	}
+	b.len()
+}
+
+fn region1(w: &str) -> usize {
+	for p in ["gener", "commun", "arsen"] {
+		if w.starts_with(p) {
+			return p.len();
+		}
+	}
+	region(w, 0)
+}
+
+/// Checks if the word ends with a short syllable
+fn ends_short(w: &[u8]) -> bool {
+	match w {
+		[a, b] => is_vowel(*a) && !is_vowel(*b),
+		[.., a, b, c] => {
+			!is_vowel(*a) && is_vowel(*b) && !is_vowel(*c) && !matches!(c, b'w' | b'x' | b'Y')
+		}
+		_ => false,
+	}
+}
+
+/// Finds the longest of the suffixes which the word ends with
+fn longest<'a>(w: &str, suffixes: &[(&'a str, &'a str)]) -> Option<(&'a str, &'a str)> {
+	suffixes.iter().filter(|(s, _)| w.ends_with(s)).max_by_key(|(s, _)| s.len()).copied()
+}
+
+fn replace(w: &mut String, suffix: &str, with: &str) {
+	w.truncate(w.len() - suffix.len());
+	w.push_str(with);
+}
+
+fn step0(w: &mut String) {
+	if let Some((s, r)) = longest(w, &[("'s'", ""), ("'s", ""), ("'", "")]) {
+		replace(w, s, r);
+	}
+}
+
+fn step1a(w: &mut String) {
+	if w.ends_with("sses") {
+		replace(w, "sses", "ss");
+	} else if w.ends_with("ied") || w.ends_with("ies") {
+		let r = if w.len() > 4 {
+			"i"
+		} else {
+			"ie"
+		};
+		w.truncate(w.len() - 3);
+		w.push_str(r);
+	} else if w.ends_with("us") || w.ends_with("ss") {
+		// Leave the word unchanged
+	} else if w.ends_with('s') && w.as_bytes()[..w.len() - 2].iter().any(|c| is_vowel(*c)) {
+		w.pop();
+	}
+}
+
+fn step1b(w: &mut String, r1: usize) {
+	let suffixes =
+		[("eedly", ""), ("ingly", ""), ("edly", ""), ("eed", ""), ("ing", ""), ("ed", "")];
+	if let Some((s, _)) = longest(w, &suffixes) {
+		let pos = w.len() - s.len();
+		match s {
+			"eed" | "eedly" => {
+				if pos >= r1 {
+					replace(w, s, "ee");
+				}
+			}
+			_ => {
+				if w.as_bytes()[..pos].iter().any(|c| is_vowel(*c)) {
+					w.truncate(pos);
+					if w.ends_with("at") || w.ends_with("bl") || w.ends_with("iz") {
+						w.push('e');
+					} else if is_double(w.as_bytes()) {
+						w.pop();
+					} else if ends_short(w.as_bytes()) && r1 >= w.len() {
+						w.push('e');
+					}
+				}
+			}
+		}
+	}
+}
+
+fn step1c(w: &mut String) {
+	let b = w.as_bytes();
+	let n = b.len();
+	if n > 2 && matches!(b[n - 1], b'y' | b'Y') && !is_vowel(b[n - 2]) {
+		w.pop();
+		w.push('i');
+	}
+}
+
+fn step2(w: &mut String, r1: usize) {
+	let suffixes = [
+		("ization", "ize"),
+		("ational", "ate"),
+		("fulness", "ful"),
+		("ousness", "ous"),
+		("iveness", "ive"),
+		("tional", "tion"),
+		("biliti", "ble"),
+		("lessli", "less"),
+		("entli", "ent"),
+		("ation", "ate"),
+		("alism", "al"),
+		("aliti", "al"),
+		("ousli", "ous"),
+		("iviti", "ive"),
+		("fulli", "ful"),
+		("enci", "ence"),
+		("anci", "ance"),
+		("abli", "able"),
+		("izer", "ize"),
+		("ator", "ate"),
+		("alli", "al"),
+		("bli", "ble"),
+		("ogi", "og"),
+		("li", ""),
+	];
+	if let Some((s, r)) = longest(w, &suffixes) {
+		let pos = w.len() - s.len();
+		if pos < r1 {
+			return;
+		}
+		let ok = match s {
+			"ogi" => pos > 0 && w.as_bytes()[pos - 1] == b'l',
+			"li" => pos > 0 && is_li_ending(w.as_bytes()[pos - 1]),
+			_ => true,
+		};
+		if ok {
+			replace(w, s, r);
+		}
+	}
+}
+
+fn step3(w: &mut String, r1: usize, r2: usize) {
+	let suffixes = [
+		("ational", "ate"),
+		("tional", "tion"),
+		("alize", "al"),
+		("icate", "ic"),
+		("iciti", "ic"),
+		("ative", ""),
+		("ical", "ic"),
+		("ness", ""),
+		("ful", ""),
+	];
+	if let Some((s, r)) = longest(w, &suffixes) {
+		let pos = w.len() - s.len();
+		if pos >= r1 && (s != "ative" || pos >= r2) {
+			replace(w, s, r);
+		}
+	}
+}
+
+fn step4(w: &mut String, r2: usize) {
+	let suffixes = [
+		("ement", ""),
+		("ance", ""),
+		("ence", ""),
+		("able", ""),
+		("ible", ""),
+		("ment", ""),
+		("ant", ""),
+		("ent", ""),
+		("ism", ""),
+		("ate", ""),
+		("iti", ""),
+		("ous", ""),
+		("ive", ""),
+		("ize", ""),
+		("ion", ""),
+		("al", ""),
+		("er", ""),
+		("ic", ""),
+	];
+	if let Some((s, r)) = longest(w, &suffixes) {
+		let pos = w.len() - s.len();
+		if pos < r2 {
+			return;
+		}
+		let ok = match s {
+			"ion" => pos > 0 && matches!(w.as_bytes()[pos - 1], b's' | b't'),
+			_ => true,
+		};
+		if ok {
+			replace(w, s, r);
+		}
+	}
+}
+
+fn step5(w: &mut String, r1: usize, r2: usize) {
+	let n = w.len();
+	if w.ends_with('e') {
+		let pos = n - 1;
+		if pos >= r2 || (pos >= r1 && !ends_short(&w.as_bytes()[..pos])) {
+			w.pop();
+		}
+	} else if w.ends_with('l') {
+		let pos = n - 1;
+		if pos >= r2 && pos > 0 && w.as_bytes()[pos - 1] == b'l' {
+			w.pop();
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn stem_english_words() {
+		let words = [
+			("caresses", "caress"),
+			("ponies", "poni"),
+			("ties", "tie"),
+			("cats", "cat"),
+			("running", "run"),
+			("hoping", "hope"),
+			("hopping", "hop"),
+			("happily", "happili"),
+			("generously", "generous"),
+			("consistency", "consist"),
+			("relational", "relat"),
+			("knightly", "knight"),
+			("skies", "sky"),
+			("succeeding", "succeed"),
+			("by", "by"),
+		];
+		for (word, stem) in words {
+			assert_eq!(english(word), stem, "stemming '{word}'");
+		}
+	}
+}
@@ -0,0 +1,364 @@
+use crate::err::Error;
+use crate::fnc::util::geo::BASE32;
+use crate::key::gh;
+use crate::kvs;
+use crate::kvs::Key;
+use crate::sql::geometry::Geometry;
+use crate::sql::id::Id;
+use crate::sql::statements::DefineIndexStatement;
+use crate::sql::value::Value;
+use geo::algorithm::bounding_rect::BoundingRect;
+use geo::{Point, Rect};
+use std::collections::{BTreeMap, BTreeSet};
+use std::f64::consts::FRAC_PI_2;
+
+/// The length of the geohash of the smallest cell
+const PRECISION: usize = 12;
+
+/// The number of longitude bits of the smallest cell
+const LON_BITS: u32 = (PRECISION as u32 * 5 + 1) / 2;
+
+/// The number of latitude bits of the smallest cell
+const LAT_BITS: u32 = PRECISION as u32 * 5 / 2;
+
+/// The maximum number of cells used to index a geometry
+const INDEX_CELLS: usize = 8;
+
+/// The maximum number of cells used to search for a geometry
+const SEARCH_CELLS: usize = 32;
+
+/// The mean radius of the earth, in metres, as used by `geo::distance`
+const EARTH_RADIUS: f64 = 6371008.8;
+
+/// A geospatial index, storing every indexed geometry under the geohash
+/// cells which cover its bounding box. Larger geometries are stored under
+/// fewer, larger cells, so that each geometry is only stored a few times.
+/// A search looks up the cells covering the searched area, along with any
+/// larger cells containing them, and returns the records which might match,
+/// which are then checked against the WHERE clause.
+pub(crate) struct GeoIndex<'a> {
+	ns: &'a str,
+	db: &'a str,
+	ix: &'a DefineIndexStatement,
+}
+
+impl<'a> GeoIndex<'a> {
+	pub(crate) fn new(ns: &'a str, db: &'a str, ix: &'a DefineIndexStatement) -> GeoIndex<'a> {
+		GeoIndex {
+			ns,
+			db,
+			ix,
+		}
+	}
+
+	/// Converts a field value into a geometry which can be indexed
+	pub(crate) fn geometry(v: &Value) -> Result<Option<Geometry>, Error> {
+		match v {
+			Value::None | Value::Null => Ok(None),
+			Value::Geometry(v) => Ok(Some(v.to_owned())),
+			v => Err(Error::InvalidGeometryValue {
+				value: v.to_string(),
+			}),
+		}
+	}
+
+	/// Indexes the geometry of a record, replacing the previously indexed geometry
+	pub(crate) async fn index(
+		&self,
+		run: &mut kvs::Transaction,
+		id: &Id,
+		old: Option<Geometry>,
+		new: Option<Geometry>,
+	) -> Result<(), Error> {
+		// Remove the previous index entries
+		if let Some(g) = old {
+			for cell in cover(&g, INDEX_CELLS) {
+				run.del(gh::new(self.ns, self.db, &self.ix.what, &self.ix.name, &cell, id)).await?;
+			}
+		}
+		// Store the new index entries
+		if let Some(g) = new {
+			for cell in cover(&g, INDEX_CELLS) {
+				let key = gh::new(self.ns, self.db, &self.ix.what, &self.ix.name, &cell, id);
+				run.set(key, Value::Geometry(g.to_owned())).await?;
+			}
+		}
+		Ok(())
+	}
+
+	/// Finds the records which might be inside, or intersect, a geometry
+	pub(crate) async fn search(
+		&self,
+		run: &mut kvs::Transaction,
+		area: &Geometry,
+	) -> Result<BTreeSet<Id>, Error> {
+		let found = self.scan(run, &cover(area, SEARCH_CELLS)).await?;
+		Ok(found.into_keys().collect())
+	}
+
+	/// Finds the records which might be within a distance, in metres, of a point
+	pub(crate) async fn within(
+		&self,
+		run: &mut kvs::Transaction,
+		point: &Point<f64>,
+		distance: f64,
+	) -> Result<BTreeSet<Id>, Error> {
+		let cells = cells(&radius(point, distance), SEARCH_CELLS);
+		let found = self.scan(run, &cells).await?;
+		Ok(found.into_keys().collect())
+	}
+
+	/// Finds the k records nearest to a point, ordered by distance
+	pub(crate) async fn knn(
+		&self,
+		run: &mut kvs::Transaction,
+		point: &Value,
+		k: u32,
+	) -> Result<Vec<(Id, f64)>, Error> {
+		// Check the query point
+		let point = match point {
+			Value::Geometry(Geometry::Point(v)) => *v,
+			v => {
+				return Err(Error::InvalidGeometryValue {
+					value: v.to_string(),
+				})
+			}
+		};
+		let k = k as usize;
+		// Search increasingly larger cells around the point
+		let mut found = BTreeMap::new();
+		for precision in (0..=PRECISION).rev() {
+			found = self.scan(run, &around(&point, precision)).await?;
+			if found.len() >= k {
+				break;
+			}
+		}
+		// Find the distance which contains k records
+		let mut res = distances(&point, found);
+		if let Some((_, d)) = res.get(k.saturating_sub(1)) {
+			// Search every record within this distance
+			let cells = cells(&radius(&point, *d), SEARCH_CELLS);
+			res = distances(&point, self.scan(run, &cells).await?);
+		}
+		// Keep the nearest records
+		res.truncate(k);
+		Ok(res)
+	}
+
+	/// Retrieves the geometries stored in these cells, or in
+	/// any of the larger cells or smaller cells overlapping them
+	async fn scan(
+		&self,
+		run: &mut kvs::Transaction,
+		cells: &[String],
+	) -> Result<BTreeMap<Id, Geometry>, Error> {
+		let (ns, db, tb, ix) = (self.ns, self.db, self.ix.what.as_str(), self.ix.name.as_str());
+		// Collect the key ranges to scan
+		let mut rng: BTreeSet<(Key, Key)> = BTreeSet::new();
+		for cell in cells.iter() {
+			// The cell and any smaller cells within it
+			rng.insert((
+				gh::prefix_cell(ns, db, tb, ix, cell),
+				gh::suffix_cell(ns, db, tb, ix, cell),
+			));
+			// Any larger cells containing the cell
+			for i in 0..cell.len() {
+				let cell = &cell[..i];
+				rng.insert((
+					gh::prefix_exact(ns, db, tb, ix, cell),
+					gh::suffix_exact(ns, db, tb, ix, cell),
+				));
+			}
+		}
+		// Retrieve the indexed geometries
+		let mut res = BTreeMap::new();
+		for (beg, end) in rng.into_iter() {
+			for (key, val) in run.getr(beg..end, u32::MAX).await? {
+				let key: gh::Gh = (&key).into();
+				if let Value::Geometry(v) = Value::from(val) {
+					res.insert(key.id, v);
+				}
+			}
+		}
+		Ok(res)
+	}
+}
+
+/// Computes the distance from the point to every geometry, ordered by distance
+fn distances(point: &Point<f64>, found: BTreeMap<Id, Geometry>) -> Vec<(Id, f64)> {
+	let mut res: Vec<(Id, f64)> =
+		found.into_iter().map(|(id, g)| (id, distance(point, g))).collect();
+	res.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+	res
+}
+
+/// Computes the distance in metres from a point to the closest point of a geometry
+fn distance(point: &Point<f64>, g: Geometry) -> f64 {
+	crate::fnc::util::geo::distance(&(*point).into(), &g.into())
+}
+
+/// Computes the bounding box of the area within a distance, in metres, of a point
+fn radius(point: &Point<f64>, distance: f64) -> Rect<f64> {
+	let world = Rect::new((-180.0, -90.0), (180.0, 90.0));
+	// Calculate the angular distance
+	let angle = distance / EARTH_RADIUS;
+	if angle.is_nan() || angle >= FRAC_PI_2 {
+		return world;
+	}
+	// Calculate the latitude bounds
+	let dlat = angle.to_degrees();
+	let (min_y, max_y) = (point.y() - dlat, point.y() + dlat);
+	if min_y <= -90.0 || max_y >= 90.0 {
+		return Rect::new((-180.0, min_y.max(-90.0)), (180.0, max_y.min(90.0)));
+	}
+	// Calculate the longitude bounds
+	let dlon = (angle.sin() / point.y().to_radians().cos()).asin().to_degrees();
+	let (min_x, max_x) = (point.x() - dlon, point.x() + dlon);
+	if dlon.is_nan() || min_x < -180.0 || max_x > 180.0 {
+		return Rect::new((-180.0, min_y), (180.0, max_y));
+	}
+	Rect::new((min_x, min_y), (max_x, max_y))
+}
+
+/// Computes the cells covering the bounding box of a geometry
+fn cover(g: &Geometry, limit: usize) -> Vec<String> {
+	match geo::Geometry::from(g.to_owned()).bounding_rect() {
+		Some(rect) => cells(&rect, limit),
+		None => vec![],
+	}
+}
+
+/// Computes the smallest cells covering a bounding box, using at most `limit`
+/// cells. The whole world is covered by a single cell with an empty geohash.
+fn cells(rect: &Rect<f64>, limit: usize) -> Vec<String> {
+	let (x1, y1) = (lon(rect.min().x), lat(rect.min().y));
+	let (x2, y2) = (lon(rect.max().x), lat(rect.max().y));
+	for precision in (1..=PRECISION).rev() {
+		let (sx, sy) = shift(precision);
+		let (x1, x2, y1, y2) = (x1 >> sx, x2 >> sx, y1 >> sy, y2 >> sy);
+		if (x2 - x1 + 1) * (y2 - y1 + 1) <= limit as u64 {
+			return grid(x1..=x2, y1..=y2, precision);
+		}
+	}
+	vec![String::new()]
+}
+
+/// Computes the cells of a point, and the cells around it, at a precision
+fn around(point: &Point<f64>, precision: usize) -> Vec<String> {
+	if precision == 0 {
+		return vec![String::new()];
+	}
+	let (sx, sy) = shift(precision);
+	let (x, y) = (lon(point.x()) >> sx, lat(point.y()) >> sy);
+	let (mx, my) = ((1 << (LON_BITS - sx)) - 1, (1 << (LAT_BITS - sy)) - 1);
+	grid(x.saturating_sub(1)..=(x + 1).min(mx), y.saturating_sub(1)..=(y + 1).min(my), precision)
+}
+
+/// Computes the geohashes of a grid of cells at a precision
+fn grid(
+	xs: std::ops::RangeInclusive<u64>,
+	ys: std::ops::RangeInclusive<u64>,
+	precision: usize,
+) -> Vec<String> {
+	let mut res = Vec::new();
+	for x in xs {
+		for y in ys.clone() {
+			res.push(hash(x, y, precision));
+		}
+	}
+	res
+}
+
+/// Computes the geohash of a cell, by interleaving the bits of its
+/// longitude and latitude positions, starting with the longitude
+fn hash(x: u64, y: u64, precision: usize) -> String {
+	let (sx, sy) = shift(precision);
+	let (mut x, mut y) = (x << (64 - LON_BITS + sx), y << (64 - LAT_BITS + sy));
+	let mut out = String::with_capacity(precision);
+	let mut v = 0;
+	for i in 0..precision * 5 {
+		let axis = match i % 2 {
+			0 => &mut x,
+			_ => &mut y,
+		};
+		v = (v << 1) | (*axis >> 63) as usize;
+		*axis <<= 1;
+		if i % 5 == 4 {
+			out.push(BASE32[v]);
+			v = 0;
+		}
+	}
+	out
+}
+
+/// The number of bits to drop from each axis position, for a precision
+fn shift(precision: usize) -> (u32, u32) {
+	let bits = precision as u32 * 5;
+	(LON_BITS - (bits + 1) / 2, LAT_BITS - bits / 2)
+}
+
+/// Computes the position of a longitude on the grid of the smallest cells
+fn lon(x: f64) -> u64 {
+	let max = (1u64 << LON_BITS) - 1;
+	(((x + 180.0) / 360.0) * (1u64 << LON_BITS) as f64).clamp(0.0, max as f64) as u64
+}
+
+/// Computes the position of a latitude on the grid of the smallest cells
+fn lat(y: f64) -> u64 {
+	let max = (1u64 << LAT_BITS) - 1;
+	(((y + 90.0) / 180.0) * (1u64 << LAT_BITS) as f64).clamp(0.0, max as f64) as u64
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::fnc::util::geo::encode;
+	use geo::algorithm::haversine_distance::HaversineDistance;
+
+	#[test]
+	fn geohash() {
+		for (x, y) in [(-0.1275, 51.5072), (151.2093, -33.8688), (-122.4194, 37.7749)] {
+			for precision in 1..=PRECISION {
+				let (sx, sy) = shift(precision);
+				let cell = hash(lon(x) >> sx, lat(y) >> sy, precision);
+				assert_eq!(cell, encode((x, y).into(), precision).as_str());
+			}
+		}
+	}
+
+	#[test]
+	fn cover_point() {
+		let cells = cover(&Geometry::Point((-0.1275, 51.5072).into()), INDEX_CELLS);
+		assert_eq!(cells, vec![encode((-0.1275, 51.5072).into(), PRECISION).as_str().to_owned()]);
+	}
+
+	#[test]
+	fn cover_world() {
+		let rect = Rect::new((-180.0, -90.0), (180.0, 90.0));
+		assert_eq!(cells(&rect, INDEX_CELLS), vec![String::new()]);
+	}
+
+	#[test]
+	fn cover_rect() {
+		let rect = Rect::new((-0.2, 51.4), (0.0, 51.6));
+		let res = cells(&rect, INDEX_CELLS);
+		assert!(!res.is_empty() && res.len() <= INDEX_CELLS);
+		let point = encode((-0.1275, 51.5072).into(), PRECISION);
+		assert!(res.iter().any(|c| point.as_str().starts_with(c.as_str())));
+	}
+
+	#[test]
+	fn radius_contains_distance() {
+		let point = Point::new(-0.1275, 51.5072);
+		let rect = radius(&point, 10000.0);
+		for other in [
+			Point::new(rect.min().x, point.y()),
+			Point::new(rect.max().x, point.y()),
+			Point::new(point.x(), rect.min().y),
+			Point::new(point.x(), rect.max().y),
+		] {
+			assert!(point.haversine_distance(&other) >= 9999.0);
+		}
+	}
+}
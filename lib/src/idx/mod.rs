@@ -0,0 +1,4 @@
+pub(crate) mod ft;
+pub(crate) mod geo;
+pub(crate) mod planner;
+pub(crate) mod vector;
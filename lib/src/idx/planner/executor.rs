@@ -0,0 +1,113 @@
+use crate::err::Error;
+use crate::idx::ft::{FtIndex, Hit};
+use crate::sql::id::Id;
+use crate::sql::idiom::Idiom;
+use crate::sql::thing::Thing;
+use crate::sql::value::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// The results of a full-text search on a table
+pub(super) struct Search {
+	/// The name of the index used for the search
+	pub(super) ix: String,
+	/// The field which was searched
+	pub(super) idiom: Idiom,
+	/// Whether the index stores the term offsets
+	pub(super) hl: bool,
+	/// The records which matched the search
+	pub(super) hits: BTreeMap<Id, Hit>,
+}
+
+/// The QueryExecutor holds the results of the full-text and vector searches
+/// run by the query planner, so that the matches and knn operators, and the
+/// search functions, can look up the results for each record being processed
+#[derive(Default)]
+pub(crate) struct QueryExecutor {
+	/// The searches, by table and match reference
+	searches: HashMap<(String, u8), Search>,
+	/// The nearest neighbours found, by table and field
+	knns: HashMap<(String, Idiom), BTreeSet<Id>>,
+}
+
+impl QueryExecutor {
+	pub(super) fn add(&mut self, tb: &str, mr: u8, search: Search) {
+		self.searches.insert((tb.to_owned(), mr), search);
+	}
+
+	pub(super) fn add_knn(&mut self, tb: &str, idiom: &Idiom, ids: BTreeSet<Id>) {
+		self.knns.insert((tb.to_owned(), idiom.to_owned()), ids);
+	}
+
+	pub(super) fn is_empty(&self) -> bool {
+		self.searches.is_empty() && self.knns.is_empty()
+	}
+
+	fn hit(&self, rid: &Thing, mr: u8) -> Option<(&Search, Option<&Hit>)> {
+		self.searches.get(&(rid.tb.to_owned(), mr)).map(|s| (s, s.hits.get(&rid.id)))
+	}
+
+	/// Checks if a record matched a full-text search, if the
+	/// search was run using an index defined on the table
+	pub(crate) fn matches(&self, rid: &Thing, mr: u8) -> Option<bool> {
+		self.hit(rid, mr).map(|(_, h)| h.is_some())
+	}
+
+	/// Checks if a record is one of the nearest neighbours
+	/// found by a k-nearest-neighbour search on a field
+	pub(crate) fn knn(&self, rid: &Thing, idiom: &Idiom) -> Option<bool> {
+		self.knns.get(&(rid.tb.to_owned(), idiom.to_owned())).map(|ids| ids.contains(&rid.id))
+	}
+
+	/// Returns the relevance score of a record
+	pub(crate) fn score(&self, rid: &Thing, mr: u8) -> Value {
+		match self.hit(rid, mr) {
+			Some((_, Some(h))) => h.score.into(),
+			_ => Value::None,
+		}
+	}
+
+	/// Returns the searched field of a record, with
+	/// each of the matched terms wrapped in the
+	/// specified prefix and suffix strings
+	pub(crate) fn highlight(
+		&self,
+		rid: &Thing,
+		mr: u8,
+		doc: &Value,
+		prefix: &str,
+		suffix: &str,
+	) -> Result<Value, Error> {
+		match self.hit(rid, mr) {
+			Some((s, _)) if !s.hl => Err(Error::HighlightUnsupported {
+				value: s.ix.to_owned(),
+			}),
+			Some((s, Some(h))) => match FtIndex::text(&doc.pick(&s.idiom)) {
+				Some(text) => {
+					let mut out = String::with_capacity(text.len());
+					let mut pos = 0;
+					for (i, c) in text.chars().enumerate() {
+						let i = i as u32;
+						// Close the previous matched term
+						if pos > 0 && h.offsets[pos - 1].1 == i {
+							out.push_str(suffix);
+						}
+						// Open the next matched term
+						if let Some((s, _)) = h.offsets.get(pos) {
+							if *s == i {
+								out.push_str(prefix);
+								pos += 1;
+							}
+						}
+						out.push(c);
+					}
+					if pos > 0 && h.offsets[pos - 1].1 as usize == text.chars().count() {
+						out.push_str(suffix);
+					}
+					Ok(out.into())
+				}
+				None => Ok(Value::None),
+			},
+			_ => Ok(Value::None),
+		}
+	}
+}
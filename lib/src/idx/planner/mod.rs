@@ -0,0 +1,213 @@
+pub(crate) mod executor;
+pub(crate) mod plan;
+mod tree;
+
+use crate::ctx::Context;
+use crate::dbs::Iterable;
+use crate::dbs::Options;
+use crate::dbs::Transaction;
+use crate::err::Error;
+use crate::idx::ft::FtIndex;
+use crate::idx::geo::GeoIndex;
+use crate::idx::planner::executor::{QueryExecutor, Search};
+use crate::idx::planner::plan::Plan;
+use crate::idx::planner::tree::{Predicate, Tree};
+use crate::idx::vector;
+use crate::idx::vector::VectorIndex;
+use crate::sql::cond::Cond;
+use crate::sql::geometry::Geometry;
+use crate::sql::index::Index;
+use crate::sql::operator::Operator;
+use crate::sql::statements::DefineIndexStatement;
+use crate::sql::table::Table;
+use crate::sql::thing::Thing;
+use crate::sql::value::Value;
+
+/// The QueryPlanner inspects the WHERE clause of a statement, and
+/// decides whether the records of a table can be retrieved using
+/// one of the indexes defined on the table, instead of scanning
+/// every record in the table.
+pub(crate) struct QueryPlanner<'a> {
+	opt: &'a Options,
+	cond: &'a Option<Cond>,
+	executor: QueryExecutor,
+}
+
+impl<'a> QueryPlanner<'a> {
+	pub(crate) fn new(opt: &'a Options, cond: &'a Option<Cond>) -> Self {
+		Self {
+			opt,
+			cond,
+			executor: QueryExecutor::default(),
+		}
+	}
+
+	/// Returns the best iteration strategy for the specified table
+	pub(crate) async fn get_iterable(
+		&mut self,
+		ctx: &Context<'_>,
+		txn: &Transaction,
+		t: Table,
+	) -> Result<Iterable, Error> {
+		if let Some(cond) = self.cond {
+			// Collect the indexable predicates
+			let preds = Tree::build(ctx, self.opt, txn, cond).await?;
+			// Check if any predicates were found
+			if !preds.is_empty() {
+				// Get the indexes defined on this table
+				let ixs = txn.lock().await.all_ix(self.opt.ns(), self.opt.db(), &t).await?;
+				// Run any full-text, vector or geospatial searches
+				if let Some(p) = self.search(txn, &t, &ixs, &preds).await? {
+					return Ok(Iterable::Index(t, p));
+				}
+				// Select the best index for these predicates
+				if let Some(p) = Plan::new(self.opt, &t, &ixs, &preds) {
+					return Ok(Iterable::Index(t, p));
+				}
+			}
+		}
+		Ok(Iterable::Table(t))
+	}
+
+	/// Returns the results of the full-text, vector or geospatial searches, if any were run
+	pub(crate) fn executor(self) -> Option<QueryExecutor> {
+		match self.executor.is_empty() {
+			true => None,
+			false => Some(self.executor),
+		}
+	}
+
+	/// Runs the full-text, vector and geospatial searches which can use an
+	/// index, returning a plan for the records matched by the first one
+	async fn search(
+		&mut self,
+		txn: &Transaction,
+		tb: &str,
+		ixs: &[DefineIndexStatement],
+		preds: &[Predicate],
+	) -> Result<Option<Plan>, Error> {
+		let mut plan = None;
+		for p in preds.iter() {
+			// Find a geospatial index on the field
+			let gix = ixs.iter().find(|ix| {
+				matches!(ix.index, Index::Geo) && ix.cols.len() == 1 && ix.cols[0] == p.idiom
+			});
+			if let Some(ix) = gix {
+				let gix = GeoIndex::new(self.opt.ns(), self.opt.db(), ix);
+				let found = match (&p.op, &p.value, &p.radius) {
+					// Find the nearest records to a point
+					(Operator::Knn(k), v, None) => {
+						let mut run = txn.lock().await;
+						let found = gix.knn(&mut run, v, *k).await?;
+						let ids: Vec<_> = found.into_iter().map(|(id, _)| id).collect();
+						// Store the nearest neighbours
+						self.executor.add_knn(tb, &p.idiom, ids.iter().cloned().collect());
+						Some(ids)
+					}
+					// Find the records inside or intersecting a geometry
+					(Operator::Inside | Operator::Intersects, Value::Geometry(v), None) => {
+						let mut run = txn.lock().await;
+						Some(gix.search(&mut run, v).await?.into_iter().collect())
+					}
+					// Find the records within a distance of a point
+					(
+						Operator::LessThan | Operator::LessThanOrEqual,
+						Value::Geometry(Geometry::Point(v)),
+						Some(Value::Number(r)),
+					) => {
+						let mut run = txn.lock().await;
+						Some(gix.within(&mut run, v, r.to_float()).await?.into_iter().collect())
+					}
+					_ => None,
+				};
+				// Only the first search restricts the records
+				if let (None, Some(found)) = (&plan, found) {
+					let ids = found.into_iter().map(|id| Thing::from((tb.to_owned(), id)));
+					plan = Some(Plan::search(&ix.name, ids.collect()));
+				}
+				continue;
+			}
+			if let Operator::Knn(k) = p.op {
+				// Find a vector index on the field
+				let ix = ixs.iter().find(|ix| {
+					matches!(ix.index, Index::Vector { .. })
+						&& ix.cols.len() == 1
+						&& ix.cols[0] == p.idiom
+				});
+				let mut run = txn.lock().await;
+				match ix {
+					// Search the index
+					Some(ix) => {
+						let vix = VectorIndex::new(self.opt.ns(), self.opt.db(), ix);
+						let found = vix.knn(&mut run, &p.value, k).await?;
+						// Only the first search restricts the records
+						if plan.is_none() {
+							let ids = found
+								.iter()
+								.map(|(id, _)| Thing::from((tb.to_owned(), id.to_owned())));
+							plan = Some(Plan::search(&ix.name, ids.collect()));
+						}
+						// Store the nearest neighbours
+						self.executor.add_knn(
+							tb,
+							&p.idiom,
+							found.into_iter().map(|(id, _)| id).collect(),
+						);
+					}
+					// Otherwise compare the field of every record in the table
+					None => {
+						let ns = (self.opt.ns(), self.opt.db(), tb);
+						let found = vector::scan(&mut run, ns, &p.idiom, &p.value, k).await?;
+						// Store the nearest neighbours
+						self.executor.add_knn(
+							tb,
+							&p.idiom,
+							found.into_iter().map(|(id, _)| id).collect(),
+						);
+					}
+				}
+			}
+			if let Operator::Matches(mr) = p.op {
+				// Find a search index on the field
+				let ix = ixs.iter().find(|ix| {
+					matches!(ix.index, Index::Search { .. })
+						&& ix.cols.len() == 1
+						&& ix.cols[0] == p.idiom
+				});
+				if let Some(ix) = ix {
+					// Search the index
+					let mut run = txn.lock().await;
+					let fti = FtIndex::new(&mut run, self.opt.ns(), self.opt.db(), ix).await?;
+					let hits = fti.search(&mut run, &p.value.to_raw_string()).await?;
+					// Only the first search restricts the records
+					if plan.is_none() {
+						let mut ids: Vec<Thing> = hits
+							.keys()
+							.map(|id| Thing::from((tb.to_owned(), id.to_owned())))
+							.collect();
+						ids.sort_unstable();
+						plan = Some(Plan::search(&ix.name, ids));
+					}
+					// Store the search results
+					self.executor.add(
+						tb,
+						mr.unwrap_or(0),
+						Search {
+							ix: ix.name.to_string(),
+							idiom: p.idiom.clone(),
+							hl: matches!(
+								ix.index,
+								Index::Search {
+									hl: true,
+									..
+								}
+							),
+							hits,
+						},
+					);
+				}
+			}
+		}
+		Ok(plan)
+	}
+}
@@ -0,0 +1,322 @@
+use crate::dbs::Options;
+use crate::idx::planner::tree::Predicate;
+use crate::key::index;
+use crate::kvs::Key;
+use crate::sql::array::Array;
+use crate::sql::index::Index;
+use crate::sql::number::Number;
+use crate::sql::operator::Operator;
+use crate::sql::statements::DefineIndexStatement;
+use crate::sql::thing::Thing;
+use crate::sql::value::Value;
+use std::ops::Bound;
+
+/// A Plan describes the index key ranges which
+/// need to be scanned, in order to find all of the
+/// records which could match a WHERE clause
+pub(crate) struct Plan {
+	/// The name of the index which is used
+	ix: String,
+	/// The key ranges to scan within the index
+	rng: Vec<(Key, Key)>,
+	/// The records matched by a full-text or vector search
+	ids: Vec<Thing>,
+}
+
+impl Plan {
+	/// Selects the best index to use for the given predicates
+	pub(super) fn new(
+		opt: &Options,
+		tb: &str,
+		ixs: &[DefineIndexStatement],
+		preds: &[Predicate],
+	) -> Option<Plan> {
+		let mut best: Option<(u8, Plan)> = None;
+		for ix in ixs.iter() {
+			if let Some((score, rng)) = Self::option(opt, tb, ix, preds) {
+				if best.as_ref().map_or(true, |(s, _)| score > *s) {
+					best = Some((
+						score,
+						Plan {
+							ix: ix.name.to_string(),
+							rng,
+							ids: vec![],
+						},
+					));
+				}
+			}
+		}
+		best.map(|(_, p)| p)
+	}
+
+	/// Creates a plan for the records matched by a full-text or vector search
+	pub(super) fn search(ix: &str, ids: Vec<Thing>) -> Plan {
+		Plan {
+			ix: ix.to_owned(),
+			rng: vec![],
+			ids,
+		}
+	}
+
+	/// Returns the key ranges to scan within the index
+	pub(crate) fn ranges(&self) -> &[(Key, Key)] {
+		&self.rng
+	}
+
+	/// Returns the records matched by a full-text or vector search
+	pub(crate) fn ids(&self) -> &[Thing] {
+		&self.ids
+	}
+
+	/// Describes the index scan, for the output of an EXPLAIN clause
+	pub(crate) fn explain(&self) -> Value {
+		Value::from(match self.rng.is_empty() {
+			true => map! {
+				String::from("index") => Value::from(self.ix.as_str()),
+				String::from("matches") => Value::from(self.ids.len()),
+			},
+			false => map! {
+				String::from("index") => Value::from(self.ix.as_str()),
+				String::from("ranges") => Value::from(self.rng.len()),
+			},
+		})
+	}
+
+	/// Checks if the predicates can be served by an index, returning a
+	/// score for the index (higher is more selective) and the key ranges
+	fn option(
+		opt: &Options,
+		tb: &str,
+		ix: &DefineIndexStatement,
+		preds: &[Predicate],
+	) -> Option<(u8, Vec<(Key, Key)>)> {
+		// Search, vector and geospatial indexes only serve their own operators
+		if let Index::Search {
+			..
+		}
+		| Index::Vector {
+			..
+		}
+		| Index::Geo = ix.index
+		{
+			return None;
+		}
+		let scan = Scan {
+			ns: opt.ns(),
+			db: opt.db(),
+			tb,
+			ix: &ix.name,
+		};
+		match ix.cols.len() {
+			// A single column index supports equality and range lookups
+			1 => {
+				let col = &ix.cols[0];
+				let preds = preds.iter().filter(|p| &p.idiom == col && p.radius.is_none());
+				let mut beg = Bound::Unbounded;
+				let mut end = Bound::Unbounded;
+				for p in preds {
+					match p.op {
+						Operator::Equal | Operator::Exact if Self::equatable(&p.value) => {
+							let v = Bound::Included(&p.value);
+							let score = match ix.index {
+								Index::Uniq => 3,
+								_ => 2,
+							};
+							return Some((score, scan.ranges(v, v)));
+						}
+						Operator::MoreThan if Self::orderable(&p.value) => {
+							beg = Bound::Excluded(&p.value);
+						}
+						Operator::MoreThanOrEqual if Self::orderable(&p.value) => {
+							beg = Bound::Included(&p.value);
+						}
+						Operator::LessThan if Self::orderable(&p.value) => {
+							end = Bound::Excluded(&p.value);
+						}
+						Operator::LessThanOrEqual if Self::orderable(&p.value) => {
+							end = Bound::Included(&p.value);
+						}
+						_ => {}
+					}
+				}
+				match (beg, end) {
+					(Bound::Unbounded, Bound::Unbounded) => None,
+					(beg, end) => Some((1, scan.ranges(beg, end))),
+				}
+			}
+			// A compound index requires an exact match on every column
+			_ => {
+				let mut fd = Array::with_capacity(ix.cols.len());
+				for col in ix.cols.iter() {
+					let v = preds.iter().find(|p| {
+						&p.idiom == col
+							&& p.radius.is_none() && matches!(p.op, Operator::Equal | Operator::Exact)
+							&& Self::equatable(&p.value)
+							&& !p.value.is_number()
+					})?;
+					fd.push(v.value.to_owned());
+				}
+				let score = match ix.index {
+					Index::Uniq => 3,
+					_ => 2,
+				};
+				Some((score, vec![scan.exact(&fd)]))
+			}
+		}
+	}
+
+	/// Checks if records equal to this value can be found using the index
+	fn equatable(v: &Value) -> bool {
+		matches!(
+			v,
+			Value::Number(_)
+				| Value::Strand(_)
+				| Value::Duration(_)
+				| Value::Datetime(_)
+				| Value::Uuid(_)
+				| Value::Thing(_)
+		)
+	}
+
+	/// Checks if the index keys are ordered in the same way as this value
+	fn orderable(v: &Value) -> bool {
+		matches!(v, Value::Number(_) | Value::Strand(_))
+	}
+}
+
+/// Builds the key ranges for a single index
+struct Scan<'a> {
+	ns: &'a str,
+	db: &'a str,
+	tb: &'a str,
+	ix: &'a str,
+}
+
+impl<'a> Scan<'a> {
+	/// The key range covering all entries for these exact values
+	fn exact(&self, fd: &Array) -> (Key, Key) {
+		(
+			index::prefix_all_ids(self.ns, self.db, self.tb, self.ix, fd),
+			index::suffix_all_ids(self.ns, self.db, self.tb, self.ix, fd),
+		)
+	}
+
+	/// The first key at or after the given bound
+	fn beg(&self, b: Bound<&Value>) -> Key {
+		match b {
+			Bound::Unbounded => index::prefix(self.ns, self.db, self.tb, self.ix),
+			Bound::Included(v) => self.exact(&Array::from(v.to_owned())).0,
+			Bound::Excluded(v) => self.exact(&Array::from(v.to_owned())).1,
+		}
+	}
+
+	/// The first key after the given bound
+	fn end(&self, b: Bound<&Value>) -> Key {
+		match b {
+			Bound::Unbounded => index::suffix(self.ns, self.db, self.tb, self.ix),
+			Bound::Included(v) => self.exact(&Array::from(v.to_owned())).1,
+			Bound::Excluded(v) => self.exact(&Array::from(v.to_owned())).0,
+		}
+	}
+
+	/// Computes the key ranges for a single column index, between two bounds.
+	///
+	/// Index keys are sorted in the same order as values, apart from numbers,
+	/// where integers, floats, and decimals are each stored in their own key
+	/// range. The number key ranges are therefore scanned separately, and the
+	/// decimal key range is scanned completely, as decimals are not stored in
+	/// a sortable format. All records found are checked by the WHERE clause.
+	fn ranges(&self, beg: Bound<&Value>, end: Bound<&Value>) -> Vec<(Key, Key)> {
+		// The lowest possible float key
+		let fmin = Value::from(f64::from_bits(u64::MAX));
+		// The highest possible float key
+		let fmax = Value::from(f64::from_bits(i64::MAX as u64));
+		// The beginning of the number key range
+		let nbeg = self.beg(Bound::Included(&Value::from(i64::MIN)));
+		// The end of the number key range
+		let nend = self.beg(Bound::Included(&Value::from("")));
+		// The key range outside of the number key range
+		let qbeg = match beg {
+			Bound::Included(v) | Bound::Excluded(v) if v.is_number() => nend.clone(),
+			b => self.beg(b),
+		};
+		let qend = match end {
+			Bound::Included(v) | Bound::Excluded(v) if v.is_number() => nbeg.clone(),
+			b => self.end(b),
+		};
+		// Check if the range overlaps the number key range
+		let nlow = match beg {
+			Bound::Included(Value::Number(n)) | Bound::Excluded(Value::Number(n)) => Some(Some(n)),
+			Bound::Unbounded => Some(None),
+			_ if qbeg < nbeg => Some(None),
+			_ => None,
+		};
+		let nhigh = match end {
+			Bound::Included(Value::Number(n)) | Bound::Excluded(Value::Number(n)) => Some(Some(n)),
+			Bound::Unbounded => Some(None),
+			_ if qend > nend => Some(None),
+			_ => None,
+		};
+		// Collect the key ranges
+		let mut res = Vec::new();
+		// Scan the keys below the number key range
+		res.push((qbeg.clone(), qend.clone().min(nbeg.clone())));
+		// Scan the number key ranges
+		if let (Some(lo), Some(hi)) = (nlow, nhigh) {
+			// Scan the integer key range
+			let ilo = lo.map_or(i64::MIN, Self::int_floor);
+			let ihi = hi.map_or(i64::MAX, Self::int_ceil);
+			res.push((
+				self.beg(Bound::Included(&Value::from(ilo))),
+				self.end(Bound::Included(&Value::from(ihi))),
+			));
+			// Scan the float key range
+			let flo = lo.map_or(fmin.clone(), |n| Value::from(Self::float_floor(n)));
+			let fhi = hi.map_or(fmax.clone(), |n| Value::from(Self::float_ceil(n)));
+			res.push((self.beg(Bound::Included(&flo)), self.end(Bound::Included(&fhi))));
+			// Scan the decimal key range
+			res.push((self.end(Bound::Included(&fmax)), nend.clone()));
+		}
+		// Scan the keys above the number key range
+		res.push((qbeg.max(nend), qend));
+		// Remove any empty key ranges
+		res.retain(|(beg, end)| beg < end);
+		res
+	}
+
+	fn int_floor(n: &Number) -> i64 {
+		match n {
+			Number::Int(v) => *v,
+			Number::Float(v) => v.floor() as i64,
+			Number::Decimal(_) => (n.to_float().floor() as i64).saturating_sub(1),
+		}
+	}
+
+	fn int_ceil(n: &Number) -> i64 {
+		match n {
+			Number::Int(v) => *v,
+			Number::Float(v) => v.ceil() as i64,
+			Number::Decimal(_) => (n.to_float().ceil() as i64).saturating_add(1),
+		}
+	}
+
+	fn float_floor(n: &Number) -> f64 {
+		match n {
+			Number::Float(v) => *v,
+			_ => {
+				let v = n.to_float();
+				v - v.abs() * f64::EPSILON
+			}
+		}
+	}
+
+	fn float_ceil(n: &Number) -> f64 {
+		match n {
+			Number::Float(v) => *v,
+			_ => {
+				let v = n.to_float();
+				v + v.abs() * f64::EPSILON
+			}
+		}
+	}
+}
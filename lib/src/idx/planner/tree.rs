@@ -0,0 +1,143 @@
+use crate::ctx::Context;
+use crate::dbs::Options;
+use crate::dbs::Transaction;
+use crate::err::Error;
+use crate::sql::function::Function;
+use crate::sql::idiom::Idiom;
+use crate::sql::operator::Operator;
+use crate::sql::subquery::Subquery;
+use crate::sql::value::Value;
+
+/// A comparison between a field and a value which
+/// does not depend on the document being processed
+pub(super) struct Predicate {
+	pub(super) idiom: Idiom,
+	pub(super) op: Operator,
+	pub(super) value: Value,
+	/// The distance from the value, for a `geo::distance` predicate
+	pub(super) radius: Option<Value>,
+}
+
+pub(super) struct Tree;
+
+impl Tree {
+	/// Collects the predicates of a WHERE clause which could be served by an index
+	pub(super) async fn build(
+		ctx: &Context<'_>,
+		opt: &Options,
+		txn: &Transaction,
+		cond: &Value,
+	) -> Result<Vec<Predicate>, Error> {
+		// Find the candidate comparisons
+		let mut found = Vec::new();
+		Self::walk(cond, &mut found);
+		// Compute the compared values
+		let mut res = Vec::with_capacity(found.len());
+		for (idiom, op, v, r) in found {
+			let value = v.compute(ctx, opt, txn, None).await?;
+			let radius = match r {
+				Some(r) => Some(r.compute(ctx, opt, txn, None).await?),
+				None => None,
+			};
+			res.push(Predicate {
+				idiom: idiom.clone(),
+				op,
+				value,
+				radius,
+			});
+		}
+		Ok(res)
+	}
+
+	#[allow(clippy::type_complexity)]
+	fn walk<'a>(v: &'a Value, res: &mut Vec<(&'a Idiom, Operator, &'a Value, Option<&'a Value>)>) {
+		match v {
+			Value::Subquery(s) => {
+				if let Subquery::Value(v) = s.as_ref() {
+					Self::walk(v, res);
+				}
+			}
+			Value::Expression(e) => match e.o {
+				// Either side of an AND can restrict the records
+				Operator::And => {
+					Self::walk(&e.l, res);
+					Self::walk(&e.r, res);
+				}
+				// Comparisons can be matched against an index
+				Operator::Equal
+				| Operator::Exact
+				| Operator::LessThan
+				| Operator::LessThanOrEqual
+				| Operator::MoreThan
+				| Operator::MoreThanOrEqual => match (&e.l, &e.r) {
+					(Value::Idiom(i), v) if Self::is_constant(v) => {
+						res.push((i, e.o.to_owned(), v, None));
+					}
+					(v, Value::Idiom(i)) if Self::is_constant(v) => {
+						res.push((i, Self::reverse(&e.o), v, None));
+					}
+					(Value::Function(f), r) if Self::is_constant(r) => {
+						if let Some((i, v)) = Self::distance(f) {
+							res.push((i, e.o.to_owned(), v, Some(r)));
+						}
+					}
+					(r, Value::Function(f)) if Self::is_constant(r) => {
+						if let Some((i, v)) = Self::distance(f) {
+							res.push((i, Self::reverse(&e.o), v, Some(r)));
+						}
+					}
+					_ => {}
+				},
+				// Full-text, vector and geospatial searches can be matched against their indexes
+				Operator::Matches(_)
+				| Operator::Knn(_)
+				| Operator::Inside
+				| Operator::Intersects => {
+					if let (Value::Idiom(i), v) = (&e.l, &e.r) {
+						if Self::is_constant(v) {
+							res.push((i, e.o.to_owned(), v, None));
+						}
+					}
+				}
+				// Other expressions can not be used
+				_ => {}
+			},
+			_ => {}
+		}
+	}
+
+	/// Checks if a function computes the distance between a field and a constant point
+	fn distance(f: &Function) -> Option<(&Idiom, &Value)> {
+		match f {
+			Function::Normal(name, args) if name == "geo::distance" && args.len() == 2 => {
+				match (&args[0], &args[1]) {
+					(Value::Idiom(i), v) | (v, Value::Idiom(i)) if Self::is_constant(v) => {
+						Some((i, v))
+					}
+					_ => None,
+				}
+			}
+			_ => None,
+		}
+	}
+
+	/// Checks if a value can be computed without a document
+	fn is_constant(v: &Value) -> bool {
+		match v {
+			Value::Param(p) => !matches!(p.as_str(), "this" | "self"),
+			Value::Thing(_) => true,
+			v => v.is_static(),
+		}
+	}
+
+	/// Returns the operator to use when the operands are swapped
+	fn reverse(o: &Operator) -> Operator {
+		match o {
+			Operator::LessThan => Operator::MoreThan,
+			Operator::LessThanOrEqual => Operator::MoreThanOrEqual,
+			Operator::MoreThan => Operator::LessThan,
+			Operator::MoreThanOrEqual => Operator::LessThanOrEqual,
+			o => o.to_owned(),
+		}
+	}
+}
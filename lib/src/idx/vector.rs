@@ -0,0 +1,194 @@
+use crate::err::Error;
+use crate::fnc::util::math::vector::Vector;
+use crate::key::thing;
+use crate::key::vd;
+use crate::kvs;
+use crate::sql::id::Id;
+use crate::sql::idiom::Idiom;
+use crate::sql::index::{Distance, Index};
+use crate::sql::statements::DefineIndexStatement;
+use crate::sql::value::Value;
+
+/// A flat vector index, storing the vector of every indexed record
+/// so that a k-nearest-neighbour search only needs to read and
+/// compare the indexed vectors, instead of every record in the table
+pub(crate) struct VectorIndex<'a> {
+	ns: &'a str,
+	db: &'a str,
+	ix: &'a DefineIndexStatement,
+	dimension: usize,
+	distance: &'a Distance,
+}
+
+impl<'a> VectorIndex<'a> {
+	pub(crate) fn new(ns: &'a str, db: &'a str, ix: &'a DefineIndexStatement) -> VectorIndex<'a> {
+		match &ix.index {
+			Index::Vector {
+				dimension,
+				distance,
+			} => VectorIndex {
+				ns,
+				db,
+				ix,
+				dimension: *dimension as usize,
+				distance,
+			},
+			_ => unreachable!(),
+		}
+	}
+
+	/// Converts a field value into a vector of the index dimension
+	pub(crate) fn vector(&self, v: &Value) -> Result<Option<Vec<f64>>, Error> {
+		match v {
+			Value::None | Value::Null => Ok(None),
+			Value::Array(a) => {
+				let mut res = Vec::with_capacity(a.len());
+				for v in a.iter() {
+					match v {
+						Value::Number(v) => res.push(v.to_float()),
+						_ => {
+							return Err(Error::InvalidVectorValue {
+								value: a.to_string(),
+							})
+						}
+					}
+				}
+				if res.len() != self.dimension {
+					return Err(Error::InvalidVectorDimension {
+						current: res.len(),
+						expected: self.dimension,
+					});
+				}
+				Ok(Some(res))
+			}
+			v => Err(Error::InvalidVectorValue {
+				value: v.to_string(),
+			}),
+		}
+	}
+
+	/// Indexes the vector of a record, replacing any previously indexed vector
+	pub(crate) async fn index(
+		&self,
+		run: &mut kvs::Transaction,
+		id: &Id,
+		v: Option<Vec<f64>>,
+	) -> Result<(), Error> {
+		let key = vd::new(self.ns, self.db, &self.ix.what, &self.ix.name, id);
+		match v {
+			Some(v) => {
+				run.set(key, Value::from(v.into_iter().map(Value::from).collect::<Vec<_>>())).await
+			}
+			None => run.del(key).await,
+		}
+	}
+
+	/// Finds the k records nearest to the query vector, ordered by distance
+	pub(crate) async fn knn(
+		&self,
+		run: &mut kvs::Transaction,
+		query: &Value,
+		k: u32,
+	) -> Result<Vec<(Id, f64)>, Error> {
+		// Check the query vector
+		let query = match self.vector(query)? {
+			Some(v) => v,
+			None => {
+				return Err(Error::InvalidVectorValue {
+					value: query.to_string(),
+				})
+			}
+		};
+		// Compare every indexed vector
+		let beg = vd::prefix(self.ns, self.db, &self.ix.what, &self.ix.name);
+		let end = vd::suffix(self.ns, self.db, &self.ix.what, &self.ix.name);
+		let mut res = Vec::new();
+		for (key, val) in run.getr(beg..end, u32::MAX).await? {
+			let key: vd::Vd = (&key).into();
+			if let Some(v) = self.vector(&Value::from(val))? {
+				res.push((key.id, self.distance(&query, &v)));
+			}
+		}
+		// Keep the nearest records
+		res.sort_by(|a, b| a.1.total_cmp(&b.1));
+		res.truncate(k as usize);
+		Ok(res)
+	}
+
+	fn distance(&self, a: &[f64], b: &[f64]) -> f64 {
+		match self.distance {
+			Distance::Euclidean => a.euclidean_distance(b),
+			Distance::Manhattan => a.manhattan_distance(b),
+			Distance::Cosine => 1.0 - a.cosine_similarity(b),
+		}
+	}
+}
+
+/// Finds the k records of a table nearest to the query, by comparing the
+/// field of every record, for searches on fields which have no index
+pub(crate) async fn scan(
+	run: &mut kvs::Transaction,
+	(ns, db, tb): (&str, &str, &str),
+	idiom: &Idiom,
+	query: &Value,
+	k: u32,
+) -> Result<Vec<(Id, f64)>, Error> {
+	let k = k as usize;
+	let beg = thing::prefix(ns, db, tb);
+	let end = thing::suffix(ns, db, tb);
+	let mut nxt: Option<Vec<u8>> = None;
+	let mut res = Vec::new();
+	loop {
+		// Get the next 1000 records
+		let batch = match nxt {
+			None => run.scan(beg.clone()..end.clone(), 1000).await?,
+			Some(ref mut beg) => {
+				beg.push(0x00);
+				run.scan(beg.clone()..end.clone(), 1000).await?
+			}
+		};
+		// Exit when settled
+		if batch.is_empty() {
+			break;
+		}
+		nxt = batch.last().map(|(k, _)| k.clone());
+		// Compare the field of each record
+		for (key, val) in batch {
+			let key: thing::Thing = (&key).into();
+			let val: Value = (&val).into();
+			if let Some(d) = distance(query, &val.pick(idiom)) {
+				res.push((key.id, d));
+			}
+		}
+		// Only keep the nearest records found so far
+		if res.len() > k * 2 {
+			res.sort_by(|a, b| a.1.total_cmp(&b.1));
+			res.truncate(k);
+		}
+	}
+	// Keep the nearest records
+	res.sort_by(|a, b| a.1.total_cmp(&b.1));
+	res.truncate(k);
+	Ok(res)
+}
+
+/// Computes the distance between a query and a field, if they can be compared
+fn distance(query: &Value, v: &Value) -> Option<f64> {
+	match (query, v) {
+		(Value::Geometry(a), Value::Geometry(b)) => {
+			Some(crate::fnc::util::geo::distance(&a.clone().into(), &b.clone().into()))
+		}
+		(Value::Array(a), Value::Array(b)) if a.len() == b.len() => {
+			let numbers = |a: &crate::sql::Array| -> Option<Vec<f64>> {
+				a.iter()
+					.map(|v| match v {
+						Value::Number(v) => Some(v.to_float()),
+						_ => None,
+					})
+					.collect()
+			};
+			Some(numbers(a)?.euclidean_distance(&numbers(b)?))
+		}
+		_ => None,
+	}
+}
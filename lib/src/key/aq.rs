@@ -0,0 +1,49 @@
+use derive::Key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+pub struct Aq<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	_d: u8,
+	_e: u8,
+}
+
+pub fn new<'a>(ns: &'a str, db: &'a str) -> Aq<'a> {
+	Aq::new(ns, db)
+}
+
+impl<'a> Aq<'a> {
+	pub fn new(ns: &'a str, db: &'a str) -> Self {
+		Self {
+			__: 0x2f, // /
+			_a: 0x2a, // *
+			ns,
+			_b: 0x2a, // *
+			db,
+			_c: 0x21, // !
+			_d: 0x61, // a
+			_e: 0x71, // q
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = Aq::new(
+			"test",
+			"test",
+		);
+		let enc = Aq::encode(&val).unwrap();
+		let dec = Aq::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+}
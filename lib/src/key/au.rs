@@ -0,0 +1,72 @@
+use derive::Key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+pub struct Au<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	_d: u8,
+	_e: u8,
+	pub tb: &'a str,
+	pub sq: u64,
+}
+
+pub fn new<'a>(ns: &'a str, db: &'a str, tb: &'a str, sq: u64) -> Au<'a> {
+	Au::new(ns, db, tb, sq)
+}
+
+pub fn prefix(ns: &str, db: &str, tb: &str) -> Vec<u8> {
+	Au::new(ns, db, tb, 0).encode().unwrap()
+}
+
+pub fn prefix_sq(ns: &str, db: &str, tb: &str, sq: u64) -> Vec<u8> {
+	Au::new(ns, db, tb, sq).encode().unwrap()
+}
+
+pub fn suffix(ns: &str, db: &str, tb: &str) -> Vec<u8> {
+	let mut k = Au::new(ns, db, tb, u64::MAX).encode().unwrap();
+	k.push(0x00);
+	k
+}
+
+impl<'a> Au<'a> {
+	pub fn new(ns: &'a str, db: &'a str, tb: &'a str, sq: u64) -> Self {
+		Self {
+			__: 0x2f, // /
+			_a: 0x2a, // *
+			ns,
+			_b: 0x2a, // *
+			db,
+			_c: 0x21, // !
+			_d: 0x61, // a
+			_e: 0x75, // u
+			tb,
+			sq,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = Au::new(
+			"test",
+			"test",
+			"test",
+			12345,
+		);
+		let enc = Au::encode(&val).unwrap();
+		let dec = Au::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+		assert!(enc >= prefix("test", "test", "test"));
+		assert!(enc < suffix("test", "test", "test"));
+		assert!(enc < prefix("test", "test", "testing"));
+	}
+}
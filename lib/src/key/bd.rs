@@ -0,0 +1,63 @@
+use crate::sql::id::Id;
+use derive::Key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+pub struct Bd<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	pub tb: &'a str,
+	_d: u8,
+	pub ix: &'a str,
+	_e: u8,
+	_f: u8,
+	_g: u8,
+	pub id: Id,
+}
+
+pub fn new<'a>(ns: &'a str, db: &'a str, tb: &'a str, ix: &'a str, id: &Id) -> Bd<'a> {
+	Bd::new(ns, db, tb, ix, id.to_owned())
+}
+
+impl<'a> Bd<'a> {
+	pub fn new(ns: &'a str, db: &'a str, tb: &'a str, ix: &'a str, id: Id) -> Self {
+		Self {
+			__: 0x2f, // /
+			_a: 0x2a, // *
+			ns,
+			_b: 0x2a, // *
+			db,
+			_c: 0x2a, // *
+			tb,
+			_d: 0xa4, // ¤
+			ix,
+			_e: 0x21, // !
+			_f: 0x62, // b
+			_g: 0x64, // d
+			id,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = Bd::new(
+			"test",
+			"test",
+			"test",
+			"test",
+			"test".into(),
+		);
+		let enc = Bd::encode(&val).unwrap();
+		let dec = Bd::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+}
@@ -0,0 +1,127 @@
+use crate::sql::id::Id;
+use derive::Key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+struct Prefix<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	pub tb: &'a str,
+	_d: u8,
+	pub ix: &'a str,
+	_e: u8,
+	_f: u8,
+	_g: u8,
+	pub tm: &'a str,
+}
+
+impl<'a> Prefix<'a> {
+	fn new(ns: &'a str, db: &'a str, tb: &'a str, ix: &'a str, tm: &'a str) -> Self {
+		Self {
+			__: 0x2f, // /
+			_a: 0x2a, // *
+			ns,
+			_b: 0x2a, // *
+			db,
+			_c: 0x2a, // *
+			tb,
+			_d: 0xa4, // ¤
+			ix,
+			_e: 0x21, // !
+			_f: 0x62, // b
+			_g: 0x66, // f
+			tm,
+		}
+	}
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+pub struct Bf<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	pub tb: &'a str,
+	_d: u8,
+	pub ix: &'a str,
+	_e: u8,
+	_f: u8,
+	_g: u8,
+	pub tm: &'a str,
+	pub id: Id,
+}
+
+pub fn new<'a>(ns: &'a str, db: &'a str, tb: &'a str, ix: &'a str, tm: &'a str, id: &Id) -> Bf<'a> {
+	Bf::new(ns, db, tb, ix, tm, id.to_owned())
+}
+
+pub fn prefix(ns: &str, db: &str, tb: &str, ix: &str, tm: &str) -> Vec<u8> {
+	let mut k = Prefix::new(ns, db, tb, ix, tm).encode().unwrap();
+	k.extend_from_slice(&[0x00]);
+	k
+}
+
+pub fn suffix(ns: &str, db: &str, tb: &str, ix: &str, tm: &str) -> Vec<u8> {
+	let mut k = Prefix::new(ns, db, tb, ix, tm).encode().unwrap();
+	k.extend_from_slice(&[0xff]);
+	k
+}
+
+impl<'a> Bf<'a> {
+	pub fn new(ns: &'a str, db: &'a str, tb: &'a str, ix: &'a str, tm: &'a str, id: Id) -> Self {
+		Self {
+			__: 0x2f, // /
+			_a: 0x2a, // *
+			ns,
+			_b: 0x2a, // *
+			db,
+			_c: 0x2a, // *
+			tb,
+			_d: 0xa4, // ¤
+			ix,
+			_e: 0x21, // !
+			_f: 0x62, // b
+			_g: 0x66, // f
+			tm,
+			id,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = Bf::new(
+			"test",
+			"test",
+			"test",
+			"test",
+			"term",
+			"test".into(),
+		);
+		let enc = Bf::encode(&val).unwrap();
+		let dec = Bf::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+
+	#[test]
+	fn range() {
+		use super::*;
+		let beg = prefix("test", "test", "test", "test", "term");
+		let end = suffix("test", "test", "test", "test", "term");
+		let key = Bf::new("test", "test", "test", "test", "term", "test".into()).encode().unwrap();
+		let other =
+			Bf::new("test", "test", "test", "test", "terms", "test".into()).encode().unwrap();
+		assert!(beg <= key && key < end);
+		assert!(other < beg || other >= end);
+	}
+}
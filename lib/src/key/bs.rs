@@ -0,0 +1,59 @@
+use derive::Key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+pub struct Bs<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	pub tb: &'a str,
+	_d: u8,
+	pub ix: &'a str,
+	_e: u8,
+	_f: u8,
+	_g: u8,
+}
+
+pub fn new<'a>(ns: &'a str, db: &'a str, tb: &'a str, ix: &'a str) -> Bs<'a> {
+	Bs::new(ns, db, tb, ix)
+}
+
+impl<'a> Bs<'a> {
+	pub fn new(ns: &'a str, db: &'a str, tb: &'a str, ix: &'a str) -> Self {
+		Self {
+			__: 0x2f, // /
+			_a: 0x2a, // *
+			ns,
+			_b: 0x2a, // *
+			db,
+			_c: 0x2a, // *
+			tb,
+			_d: 0xa4, // ¤
+			ix,
+			_e: 0x21, // !
+			_f: 0x62, // b
+			_g: 0x73, // s
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = Bs::new(
+			"test",
+			"test",
+			"test",
+			"test",
+		);
+		let enc = Bs::encode(&val).unwrap();
+		let dec = Bs::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+}
@@ -0,0 +1,65 @@
+use derive::Key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+pub struct Cf<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	pub tb: &'a str,
+	_d: u8,
+	pub vs: u64,
+}
+
+pub fn new<'a>(ns: &'a str, db: &'a str, tb: &'a str, vs: u64) -> Cf<'a> {
+	Cf::new(ns, db, tb, vs)
+}
+
+pub fn prefix(ns: &str, db: &str, tb: &str) -> Vec<u8> {
+	let mut k = super::table::new(ns, db, tb).encode().unwrap();
+	k.extend_from_slice(&[0x25, 0x00]);
+	k
+}
+
+pub fn suffix(ns: &str, db: &str, tb: &str) -> Vec<u8> {
+	let mut k = super::table::new(ns, db, tb).encode().unwrap();
+	k.extend_from_slice(&[0x25, 0xff]);
+	k
+}
+
+impl<'a> Cf<'a> {
+	pub fn new(ns: &'a str, db: &'a str, tb: &'a str, vs: u64) -> Self {
+		Self {
+			__: 0x2f, // /
+			_a: 0x2a, // *
+			ns,
+			_b: 0x2a, // *
+			db,
+			_c: 0x2a, // *
+			tb,
+			_d: 0x25, // %
+			vs,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = Cf::new(
+			"test",
+			"test",
+			"test",
+			12345,
+		);
+		let enc = Cf::encode(&val).unwrap();
+		let dec = Cf::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+}
@@ -0,0 +1,43 @@
+use derive::Key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+pub struct Dv {
+	__: u8,
+	_a: u8,
+	_b: u8,
+	_c: u8,
+}
+
+pub fn new() -> Dv {
+	Dv::new()
+}
+
+impl Default for Dv {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Dv {
+	pub fn new() -> Dv {
+		Dv {
+			__: 0x2f, // /
+			_a: 0x21, // !
+			_b: 0x64, // d
+			_c: 0x76, // v
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn key() {
+		use super::*;
+		let val = Dv::new();
+		let enc = Dv::encode(&val).unwrap();
+		let dec = Dv::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+}
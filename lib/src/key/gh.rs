@@ -0,0 +1,156 @@
+use crate::sql::id::Id;
+use derive::Key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+struct Prefix<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	pub tb: &'a str,
+	_d: u8,
+	pub ix: &'a str,
+	_e: u8,
+	_f: u8,
+	_g: u8,
+}
+
+impl<'a> Prefix<'a> {
+	fn new(ns: &'a str, db: &'a str, tb: &'a str, ix: &'a str) -> Self {
+		Self {
+			__: 0x2f, // /
+			_a: 0x2a, // *
+			ns,
+			_b: 0x2a, // *
+			db,
+			_c: 0x2a, // *
+			tb,
+			_d: 0xa4, // ¤
+			ix,
+			_e: 0x21, // !
+			_f: 0x67, // g
+			_g: 0x68, // h
+		}
+	}
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+pub struct Gh<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	pub tb: &'a str,
+	_d: u8,
+	pub ix: &'a str,
+	_e: u8,
+	_f: u8,
+	_g: u8,
+	pub cell: &'a str,
+	pub id: Id,
+}
+
+pub fn new<'a>(
+	ns: &'a str,
+	db: &'a str,
+	tb: &'a str,
+	ix: &'a str,
+	cell: &'a str,
+	id: &Id,
+) -> Gh<'a> {
+	Gh::new(ns, db, tb, ix, cell, id.to_owned())
+}
+
+/// The first key of the cell, or of any cell within it
+pub fn prefix_cell(ns: &str, db: &str, tb: &str, ix: &str, cell: &str) -> Vec<u8> {
+	let mut k = Prefix::new(ns, db, tb, ix).encode().unwrap();
+	k.extend_from_slice(cell.as_bytes());
+	k
+}
+
+/// The key after the cell, and after any cell within it
+pub fn suffix_cell(ns: &str, db: &str, tb: &str, ix: &str, cell: &str) -> Vec<u8> {
+	let mut k = Prefix::new(ns, db, tb, ix).encode().unwrap();
+	k.extend_from_slice(cell.as_bytes());
+	k.extend_from_slice(&[0xff]);
+	k
+}
+
+/// The first key of the cell itself
+pub fn prefix_exact(ns: &str, db: &str, tb: &str, ix: &str, cell: &str) -> Vec<u8> {
+	let mut k = prefix_cell(ns, db, tb, ix, cell);
+	k.extend_from_slice(&[0x00]);
+	k
+}
+
+/// The key after the cell itself
+pub fn suffix_exact(ns: &str, db: &str, tb: &str, ix: &str, cell: &str) -> Vec<u8> {
+	let mut k = prefix_cell(ns, db, tb, ix, cell);
+	k.extend_from_slice(&[0x01]);
+	k
+}
+
+impl<'a> Gh<'a> {
+	pub fn new(ns: &'a str, db: &'a str, tb: &'a str, ix: &'a str, cell: &'a str, id: Id) -> Self {
+		Self {
+			__: 0x2f, // /
+			_a: 0x2a, // *
+			ns,
+			_b: 0x2a, // *
+			db,
+			_c: 0x2a, // *
+			tb,
+			_d: 0xa4, // ¤
+			ix,
+			_e: 0x21, // !
+			_f: 0x67, // g
+			_g: 0x68, // h
+			cell,
+			id,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = Gh::new(
+			"test",
+			"test",
+			"test",
+			"test",
+			"u4pruyd",
+			"test".into(),
+		);
+		let enc = Gh::encode(&val).unwrap();
+		let dec = Gh::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+
+	#[test]
+	fn range() {
+		use super::*;
+		let beg = prefix_cell("test", "test", "test", "test", "u4p");
+		let end = suffix_cell("test", "test", "test", "test", "u4p");
+		let key = Gh::new("test", "test", "test", "test", "u4p", 1.into()).encode().unwrap();
+		assert!(beg <= key && key < end);
+		let key = Gh::new("test", "test", "test", "test", "u4pru", 1.into()).encode().unwrap();
+		assert!(beg <= key && key < end);
+		let key = Gh::new("test", "test", "test", "test", "u4q", 1.into()).encode().unwrap();
+		assert!(!(beg <= key && key < end));
+		let beg = prefix_exact("test", "test", "test", "test", "u4p");
+		let end = suffix_exact("test", "test", "test", "test", "u4p");
+		let key = Gh::new("test", "test", "test", "test", "u4p", "test".into()).encode().unwrap();
+		assert!(beg <= key && key < end);
+		let key = Gh::new("test", "test", "test", "test", "u4pru", 1.into()).encode().unwrap();
+		assert!(!(beg <= key && key < end));
+	}
+}
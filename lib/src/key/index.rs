@@ -32,6 +32,37 @@ impl<'a> Prefix<'a> {
 	}
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+struct PrefixIds<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	pub tb: &'a str,
+	_d: u8,
+	pub ix: &'a str,
+	pub fd: Array,
+}
+
+impl<'a> PrefixIds<'a> {
+	fn new(ns: &'a str, db: &'a str, tb: &'a str, ix: &'a str, fd: &Array) -> Self {
+		Self {
+			__: 0x2f, // /
+			_a: 0x2a, // *
+			ns,
+			_b: 0x2a, // *
+			db,
+			_c: 0x2a, // *
+			tb,
+			_d: 0xa4, // ¤
+			ix,
+			fd: fd.to_owned(),
+		}
+	}
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
 pub struct Index<'a> {
 	__: u8,
@@ -70,6 +101,18 @@ pub fn suffix(ns: &str, db: &str, tb: &str, ix: &str) -> Vec<u8> {
 	k
 }
 
+pub fn prefix_all_ids(ns: &str, db: &str, tb: &str, ix: &str, fd: &Array) -> Vec<u8> {
+	let mut k = PrefixIds::new(ns, db, tb, ix, fd).encode().unwrap();
+	k.extend_from_slice(&[0x00]);
+	k
+}
+
+pub fn suffix_all_ids(ns: &str, db: &str, tb: &str, ix: &str, fd: &Array) -> Vec<u8> {
+	let mut k = PrefixIds::new(ns, db, tb, ix, fd).encode().unwrap();
+	k.extend_from_slice(&[0xff]);
+	k
+}
+
 impl<'a> Index<'a> {
 	pub fn new(
 		ns: &'a str,
@@ -113,4 +156,17 @@ mod tests {
 		let dec = Index::decode(&enc).unwrap();
 		assert_eq!(val, dec);
 	}
+
+	#[test]
+	fn ids() {
+		use super::*;
+		let fd: Array = vec!["test"].into();
+		let beg = prefix_all_ids("test", "test", "test", "test", &fd);
+		let end = suffix_all_ids("test", "test", "test", "test", &fd);
+		let uniq = Index::new("test", "test", "test", "test", fd.clone(), None).encode().unwrap();
+		let nonu =
+			Index::new("test", "test", "test", "test", fd, Some("test".into())).encode().unwrap();
+		assert!(beg <= uniq && uniq < end);
+		assert!(beg <= nonu && nonu < end);
+	}
 }
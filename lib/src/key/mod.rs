@@ -1,6 +1,10 @@
 ///
 /// KV              /
+/// DV              /!dv
 /// NS              /!ns{ns}
+/// SQ              /!sq
+/// SY              /!sy{sq}
+/// US              /!us{us}
 ///
 /// Namespace       /*{ns}
 /// NL              /*{ns}!nl{us}
@@ -8,12 +12,16 @@
 /// DB              /*{ns}!db{db}
 ///
 /// Database        /*{ns}*{db}
+/// AQ              /*{ns}*{db}!aq
+/// AU              /*{ns}*{db}!au{tb}{sq}
+/// AZ              /*{ns}*{db}!az{az}
 /// DL              /*{ns}*{db}!dl{us}
 /// DT              /*{ns}*{db}!dt{tk}
 /// PA              /*{ns}*{db}!pa{pa}
 /// SC              /*{ns}*{db}!sc{sc}
 /// TB              /*{ns}*{db}!tb{tb}
 /// LQ              /*{ns}*{db}!lq{lq}
+/// VS              /*{ns}*{db}!vs
 ///
 /// Scope           /*{ns}*{db}±{sc}
 /// ST              /*{ns}*{db}±{sc}!st{tk}
@@ -27,18 +35,36 @@
 ///
 /// Thing           /*{ns}*{db}*{tb}*{id}
 ///
+/// Version         /*{ns}*{db}*{tb}#{id}{ts}
+///
+/// Change          /*{ns}*{db}*{tb}%{vs}
+///
 /// Graph           /*{ns}*{db}*{tb}~{id}{eg}{fk}
 ///
 /// Index           /*{ns}*{db}*{tb}¤{ix}{fd}{id}
+/// BD              /*{ns}*{db}*{tb}¤{ix}!bd{id}
+/// BF              /*{ns}*{db}*{tb}¤{ix}!bf{tm}{id}
+/// BS              /*{ns}*{db}*{tb}¤{ix}!bs
+/// GH              /*{ns}*{db}*{tb}¤{ix}!gh{cell}{id}
+/// VD              /*{ns}*{db}*{tb}¤{ix}!vd{id}
 ///
+pub mod aq; // Stores the position of the latest audit record in a database
+pub mod au; // Stores an audit record of a change to a record
+pub mod az; // Stores a DEFINE ANALYZER config definition
+pub mod bd; // Stores the terms of a document in a search index
+pub mod bf; // Stores the term frequency and offsets of a document in a search index
+pub mod bs; // Stores the statistics of a search index
+pub mod cf; // Stores the data changes for a table at a versionstamp
 pub mod database; // Stores the key prefix for all keys under a database
 pub mod db; // Stores a DEFINE DATABASE config definition
-pub mod dl; // Stores a DEFINE LOGIN ON DATABASE config definition
+pub mod dl; // Stores a DEFINE USER ON DATABASE config definition
 pub mod dt; // Stores a DEFINE LOGIN ON DATABASE config definition
+pub mod dv; // Stores the version of the cached definitions
 pub mod ev; // Stores a DEFINE EVENT config definition
 pub mod fc; // Stores a DEFINE FUNCTION config definition
 pub mod fd; // Stores a DEFINE FIELD config definition
 pub mod ft; // Stores a DEFINE TABLE AS config definition
+pub mod gh; // Stores a geohash cell of a document in a geospatial index
 pub mod graph; // Stores a graph edge pointer
 pub mod index; // Stores an index entry
 pub mod ix; // Stores a DEFINE INDEX config definition
@@ -46,13 +72,19 @@ pub mod kv; // Stores the key prefix for all keys
 pub mod lq; // Stores a LIVE SELECT query definition on the database
 pub mod lv; // Stores a LIVE SELECT query definition on the table
 pub mod namespace; // Stores the key prefix for all keys under a namespace
-pub mod nl; // Stores a DEFINE LOGIN ON NAMESPACE config definition
+pub mod nl; // Stores a DEFINE USER ON NAMESPACE config definition
 pub mod ns; // Stores a DEFINE NAMESPACE config definition
 pub mod nt; // Stores a DEFINE TOKEN ON NAMESPACE config definition
 pub mod pa; // Stores a DEFINE PARAM config definition
 pub mod sc; // Stores a DEFINE SCOPE config definition
 pub mod scope; // Stores the key prefix for all keys under a scope
+pub mod sq; // Stores the latest sequence number of the sync log
 pub mod st; // Stores a DEFINE TOKEN ON SCOPE config definition
+pub mod sy; // Stores the writes of a committed transaction in the sync log
 pub mod table; // Stores the key prefix for all keys under a table
 pub mod tb; // Stores a DEFINE TABLE config definition
 pub mod thing; // Stores a record id
+pub mod us; // Stores a DEFINE USER ON ROOT config definition
+pub mod vd; // Stores the vector of a document in a vector index
+pub mod version; // Stores a timestamped version of a record
+pub mod vs; // Stores the latest versionstamp for a database
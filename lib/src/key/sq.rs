@@ -0,0 +1,43 @@
+use derive::Key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+pub struct Sq {
+	__: u8,
+	_a: u8,
+	_b: u8,
+	_c: u8,
+}
+
+pub fn new() -> Sq {
+	Sq::new()
+}
+
+impl Default for Sq {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Sq {
+	pub fn new() -> Sq {
+		Sq {
+			__: 0x2f, // /
+			_a: 0x21, // !
+			_b: 0x73, // s
+			_c: 0x71, // q
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn key() {
+		use super::*;
+		let val = Sq::new();
+		let enc = Sq::encode(&val).unwrap();
+		let dec = Sq::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+}
@@ -0,0 +1,65 @@
+use derive::Key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+pub struct Sy {
+	__: u8,
+	_a: u8,
+	_b: u8,
+	_c: u8,
+	pub sq: u64,
+}
+
+pub fn new(sq: u64) -> Sy {
+	Sy::new(sq)
+}
+
+pub fn prefix() -> Vec<u8> {
+	let mut k = super::kv::new().encode().unwrap();
+	k.extend_from_slice(&[0x21, 0x73, 0x79, 0x00]);
+	k
+}
+
+pub fn suffix() -> Vec<u8> {
+	let mut k = Sy::new(u64::MAX).encode().unwrap();
+	k.extend_from_slice(&[0x00]);
+	k
+}
+
+impl Sy {
+	pub fn new(sq: u64) -> Sy {
+		Sy {
+			__: 0x2f, // /
+			_a: 0x21, // !
+			_b: 0x73, // s
+			_c: 0x79, // y
+			sq,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = Sy::new(
+			12345,
+		);
+		let enc = Sy::encode(&val).unwrap();
+		let dec = Sy::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+
+	#[test]
+	fn range() {
+		use super::*;
+		let beg = prefix();
+		let end = suffix();
+		let key = Sy::new(1).encode().unwrap();
+		assert!(beg <= key && key < end);
+		let key = Sy::new(u64::MAX).encode().unwrap();
+		assert!(beg <= key && key < end);
+	}
+}
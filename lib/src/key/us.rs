@@ -0,0 +1,54 @@
+use derive::Key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+pub struct Us<'a> {
+	__: u8,
+	_a: u8,
+	_b: u8,
+	_c: u8,
+	pub us: &'a str,
+}
+
+pub fn new(us: &str) -> Us<'_> {
+	Us::new(us)
+}
+
+pub fn prefix() -> Vec<u8> {
+	let mut k = super::kv::new().encode().unwrap();
+	k.extend_from_slice(&[0x21, 0x75, 0x73, 0x00]);
+	k
+}
+
+pub fn suffix() -> Vec<u8> {
+	let mut k = super::kv::new().encode().unwrap();
+	k.extend_from_slice(&[0x21, 0x75, 0x73, 0xff]);
+	k
+}
+
+impl<'a> Us<'a> {
+	pub fn new(us: &'a str) -> Self {
+		Self {
+			__: 0x2f, // /
+			_a: 0x21, // !
+			_b: 0x75, // u
+			_c: 0x73, // s
+			us,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = Us::new(
+			"test",
+		);
+		let enc = Us::encode(&val).unwrap();
+		let dec = Us::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+}
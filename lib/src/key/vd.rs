@@ -0,0 +1,121 @@
+use crate::sql::id::Id;
+use derive::Key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+struct Prefix<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	pub tb: &'a str,
+	_d: u8,
+	pub ix: &'a str,
+	_e: u8,
+	_f: u8,
+	_g: u8,
+}
+
+impl<'a> Prefix<'a> {
+	fn new(ns: &'a str, db: &'a str, tb: &'a str, ix: &'a str) -> Self {
+		Self {
+			__: 0x2f, // /
+			_a: 0x2a, // *
+			ns,
+			_b: 0x2a, // *
+			db,
+			_c: 0x2a, // *
+			tb,
+			_d: 0xa4, // ¤
+			ix,
+			_e: 0x21, // !
+			_f: 0x76, // v
+			_g: 0x64, // d
+		}
+	}
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+pub struct Vd<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	pub tb: &'a str,
+	_d: u8,
+	pub ix: &'a str,
+	_e: u8,
+	_f: u8,
+	_g: u8,
+	pub id: Id,
+}
+
+pub fn new<'a>(ns: &'a str, db: &'a str, tb: &'a str, ix: &'a str, id: &Id) -> Vd<'a> {
+	Vd::new(ns, db, tb, ix, id.to_owned())
+}
+
+pub fn prefix(ns: &str, db: &str, tb: &str, ix: &str) -> Vec<u8> {
+	let mut k = Prefix::new(ns, db, tb, ix).encode().unwrap();
+	k.extend_from_slice(&[0x00]);
+	k
+}
+
+pub fn suffix(ns: &str, db: &str, tb: &str, ix: &str) -> Vec<u8> {
+	let mut k = Prefix::new(ns, db, tb, ix).encode().unwrap();
+	k.extend_from_slice(&[0xff]);
+	k
+}
+
+impl<'a> Vd<'a> {
+	pub fn new(ns: &'a str, db: &'a str, tb: &'a str, ix: &'a str, id: Id) -> Self {
+		Self {
+			__: 0x2f, // /
+			_a: 0x2a, // *
+			ns,
+			_b: 0x2a, // *
+			db,
+			_c: 0x2a, // *
+			tb,
+			_d: 0xa4, // ¤
+			ix,
+			_e: 0x21, // !
+			_f: 0x76, // v
+			_g: 0x64, // d
+			id,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = Vd::new(
+			"test",
+			"test",
+			"test",
+			"test",
+			"test".into(),
+		);
+		let enc = Vd::encode(&val).unwrap();
+		let dec = Vd::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+
+	#[test]
+	fn range() {
+		use super::*;
+		let beg = prefix("test", "test", "test", "test");
+		let end = suffix("test", "test", "test", "test");
+		let key = Vd::new("test", "test", "test", "test", 1.into()).encode().unwrap();
+		assert!(beg <= key && key < end);
+		let key = Vd::new("test", "test", "test", "test", "test".into()).encode().unwrap();
+		assert!(beg <= key && key < end);
+	}
+}
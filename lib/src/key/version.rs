@@ -0,0 +1,99 @@
+use crate::sql::id::Id;
+use derive::Key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+pub struct Version<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	pub tb: &'a str,
+	_d: u8,
+	pub id: Id,
+	pub ts: u64,
+}
+
+pub fn new<'a>(ns: &'a str, db: &'a str, tb: &'a str, id: &Id, ts: u64) -> Version<'a> {
+	Version::new(ns, db, tb, id.to_owned(), ts)
+}
+
+pub fn prefix(ns: &str, db: &str, tb: &str) -> Vec<u8> {
+	let mut k = super::table::new(ns, db, tb).encode().unwrap();
+	k.extend_from_slice(&[0x23, 0x00]);
+	k
+}
+
+pub fn suffix(ns: &str, db: &str, tb: &str) -> Vec<u8> {
+	let mut k = super::table::new(ns, db, tb).encode().unwrap();
+	k.extend_from_slice(&[0x23, 0xff]);
+	k
+}
+
+/// The first version of a record
+pub fn prefix_id(ns: &str, db: &str, tb: &str, id: &Id) -> Vec<u8> {
+	new(ns, db, tb, id, 0).encode().unwrap()
+}
+
+/// The key after the last version of a record
+pub fn suffix_id(ns: &str, db: &str, tb: &str, id: &Id) -> Vec<u8> {
+	let mut k = new(ns, db, tb, id, u64::MAX).encode().unwrap();
+	k.push(0x00);
+	k
+}
+
+impl<'a> Version<'a> {
+	pub fn new(ns: &'a str, db: &'a str, tb: &'a str, id: Id, ts: u64) -> Self {
+		Self {
+			__: 0x2f, // /
+			_a: 0x2a, // *
+			ns,
+			_b: 0x2a, // *
+			db,
+			_c: 0x2a, // *
+			tb,
+			_d: 0x23, // #
+			id,
+			ts,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = Version::new(
+			"test",
+			"test",
+			"test",
+			"test".into(),
+			1_672_531_200_000_000_000,
+		);
+		let enc = Version::encode(&val).unwrap();
+		let dec = Version::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+	#[test]
+	fn range() {
+		use super::*;
+		let beg = prefix_id("test", "test", "test", &"test".into());
+		let end = suffix_id("test", "test", "test", &"test".into());
+		let key = Version::new("test", "test", "test", "test".into(), 1).encode().unwrap();
+		assert!(beg <= key && key < end);
+		let key = Version::new("test", "test", "test", "tests".into(), 1).encode().unwrap();
+		assert!(!(beg <= key && key < end));
+	}
+
+	#[test]
+	fn key_order() {
+		use super::*;
+		let old = Version::new("test", "test", "test", "test".into(), 1).encode().unwrap();
+		let new = Version::new("test", "test", "test", "test".into(), 256).encode().unwrap();
+		assert!(old < new);
+	}
+}
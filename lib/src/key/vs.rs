@@ -0,0 +1,49 @@
+use derive::Key;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+pub struct Vs<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	_d: u8,
+	_e: u8,
+}
+
+pub fn new<'a>(ns: &'a str, db: &'a str) -> Vs<'a> {
+	Vs::new(ns, db)
+}
+
+impl<'a> Vs<'a> {
+	pub fn new(ns: &'a str, db: &'a str) -> Self {
+		Self {
+			__: 0x2f, // /
+			_a: 0x2a, // *
+			ns,
+			_b: 0x2a, // *
+			db,
+			_c: 0x21, // !
+			_d: 0x76, // v
+			_e: 0x73, // s
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = Vs::new(
+			"test",
+			"test",
+		);
+		let enc = Vs::encode(&val).unwrap();
+		let dec = Vs::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+}
@@ -1,18 +1,20 @@
 use crate::kvs::kv::Key;
+use crate::sql::statements::DefineAnalyzerStatement;
 use crate::sql::statements::DefineDatabaseStatement;
 use crate::sql::statements::DefineEventStatement;
 use crate::sql::statements::DefineFieldStatement;
 use crate::sql::statements::DefineFunctionStatement;
 use crate::sql::statements::DefineIndexStatement;
-use crate::sql::statements::DefineLoginStatement;
 use crate::sql::statements::DefineNamespaceStatement;
 use crate::sql::statements::DefineParamStatement;
 use crate::sql::statements::DefineScopeStatement;
 use crate::sql::statements::DefineTableStatement;
 use crate::sql::statements::DefineTokenStatement;
+use crate::sql::statements::DefineUserStatement;
 use crate::sql::statements::LiveStatement;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::RwLock;
 
 #[derive(Clone)]
 pub enum Entry {
@@ -21,8 +23,9 @@ pub enum Entry {
 	Ns(Arc<DefineNamespaceStatement>),
 	Tb(Arc<DefineTableStatement>),
 	// Multi definitions
+	Azs(Arc<[DefineAnalyzerStatement]>),
 	Dbs(Arc<[DefineDatabaseStatement]>),
-	Dls(Arc<[DefineLoginStatement]>),
+	Dls(Arc<[DefineUserStatement]>),
 	Dts(Arc<[DefineTokenStatement]>),
 	Evs(Arc<[DefineEventStatement]>),
 	Fcs(Arc<[DefineFunctionStatement]>),
@@ -30,29 +33,217 @@ pub enum Entry {
 	Fts(Arc<[DefineTableStatement]>),
 	Ixs(Arc<[DefineIndexStatement]>),
 	Lvs(Arc<[LiveStatement]>),
-	Nls(Arc<[DefineLoginStatement]>),
+	Nls(Arc<[DefineUserStatement]>),
 	Nss(Arc<[DefineNamespaceStatement]>),
 	Nts(Arc<[DefineTokenStatement]>),
 	Pas(Arc<[DefineParamStatement]>),
 	Scs(Arc<[DefineScopeStatement]>),
 	Sts(Arc<[DefineTokenStatement]>),
 	Tbs(Arc<[DefineTableStatement]>),
+	Uss(Arc<[DefineUserStatement]>),
 }
 
+/// The definitions which are cached across transactions
 #[derive(Default)]
-pub struct Cache(pub HashMap<Key, Entry>);
+struct Shared {
+	/// The version of the cached definitions
+	version: u64,
+	/// The cached definitions at this version
+	entries: HashMap<Key, Entry>,
+}
+
+/// A datastore-wide cache of definitions, shared across transactions
+///
+/// The version of the definitions is stored in the datastore, and is
+/// incremented by every transaction which changes any definitions. Each
+/// transaction reads this version from its own snapshot of the datastore,
+/// and only uses the definitions which were cached at the same version,
+/// so that the cache is invalidated as soon as changed definitions are
+/// committed, including by other processes using the same datastore.
+#[derive(Default)]
+pub struct Definitions {
+	inner: RwLock<Shared>,
+}
+
+impl Definitions {
+	/// Get a cached definition, if it was cached at this version
+	pub fn get(&self, version: u64, key: &Key) -> Option<Entry> {
+		match self.inner.read() {
+			Ok(v) if v.version == version => v.entries.get(key).cloned(),
+			_ => None,
+		}
+	}
+	/// Cache a definition, discarding any definitions at other versions
+	pub fn set(&self, version: u64, key: Key, val: Entry) {
+		if let Ok(mut v) = self.inner.write() {
+			if v.version != version {
+				v.version = version;
+				v.entries.clear();
+			}
+			v.entries.insert(key, val);
+		}
+	}
+}
+
+/// The definition cache of a single transaction
+///
+/// Definitions which are not found in this transaction are looked up in
+/// the datastore-wide definition cache, until this transaction changes
+/// any definitions itself, after which only this cache is used.
+pub struct Cache {
+	/// The definitions cached in this transaction
+	local: HashMap<Key, Entry>,
+	/// The definitions cached across transactions
+	global: Arc<Definitions>,
+	/// The version of the definitions in this transaction
+	version: Option<u64>,
+	/// Whether this transaction has changed any definitions
+	changed: bool,
+}
 
 impl Cache {
+	/// Create a transaction cache on top of the datastore-wide cache
+	pub fn new(global: Arc<Definitions>) -> Self {
+		Cache {
+			local: HashMap::new(),
+			global,
+			version: None,
+			changed: false,
+		}
+	}
 	/// Set a key in the cache
 	pub fn set(&mut self, key: Key, val: Entry) {
-		self.0.insert(key, val);
+		if let (false, Some(version)) = (self.changed, self.version) {
+			self.global.set(version, key.clone(), val.clone());
+		}
+		self.local.insert(key, val);
 	}
 	/// Get a key from the cache
 	pub fn get(&mut self, key: &Key) -> Option<Entry> {
-		self.0.get(key).cloned()
+		match self.local.get(key) {
+			Some(v) => Some(v.clone()),
+			None => match (self.changed, self.version) {
+				(false, Some(version)) => {
+					let val = self.global.get(version, key)?;
+					self.local.insert(key.clone(), val.clone());
+					Some(val)
+				}
+				_ => None,
+			},
+		}
 	}
 	/// Delete a key from the cache
 	pub fn del(&mut self, key: &Key) -> Option<Entry> {
-		self.0.remove(key)
+		self.local.remove(key)
+	}
+	/// Check if the version of the definitions is needed to use the shared cache
+	pub fn needs_version(&self) -> bool {
+		!self.changed && self.version.is_none()
+	}
+	/// Set the version of the definitions in this transaction
+	pub fn set_version(&mut self, version: u64) {
+		self.version = Some(version);
+	}
+	/// Record a write, and stop using the shared cache if it changes a definition
+	pub fn write(&mut self, key: &[u8]) {
+		if !self.changed && is_definition(key) {
+			self.changed = true;
+		}
+	}
+	/// Check if this transaction has changed any definitions
+	pub fn changed(&self) -> bool {
+		self.changed
+	}
+}
+
+/// Checks whether a key stores a definition which may be cached
+fn is_definition(key: &[u8]) -> bool {
+	// All keys start with the root prefix
+	let mut key = match key.strip_prefix(b"/") {
+		Some(v) => v,
+		None => return false,
+	};
+	// Skip the namespace, database, and table or scope
+	for _ in 0..3 {
+		match key.first() {
+			Some(0x2a) | Some(0xb1) => match key.iter().position(|v| *v == 0x00) {
+				Some(i) => key = &key[i + 1..],
+				None => return false,
+			},
+			_ => break,
+		}
+	}
+	// Definitions are marked with an exclamation mark
+	if key.first() != Some(&0x21) {
+		return false;
+	}
+	// Check the type of the definition
+	matches!(
+		key.get(1..3),
+		Some(
+			b"ns"
+				| b"us" | b"nl"
+				| b"nt" | b"db"
+				| b"az" | b"dl"
+				| b"dt" | b"pa"
+				| b"sc" | b"tb"
+				| b"lq" | b"st"
+				| b"ev" | b"fd"
+				| b"fn" | b"ft"
+				| b"ix" | b"lv"
+		)
+	)
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn definition_keys() {
+		assert!(is_definition(&crate::key::ns::new("test").encode().unwrap()));
+		assert!(is_definition(&crate::key::tb::new("test", "test", "test").encode().unwrap()));
+		assert!(is_definition(
+			&crate::key::fd::new("test", "test", "test", "name").encode().unwrap()
+		));
+		assert!(is_definition(&crate::key::fc::new("test", "test", "test").encode().unwrap()));
+		assert!(is_definition(
+			&crate::key::st::new("test", "test", "test", "test").encode().unwrap()
+		));
+		assert!(!is_definition(&crate::key::sq::new().encode().unwrap()));
+		assert!(!is_definition(&crate::key::vs::new("test", "test").encode().unwrap()));
+		assert!(!is_definition(
+			&crate::key::thing::new("test", "test", "test", &"test".into()).encode().unwrap()
+		));
+	}
+
+	#[test]
+	fn definitions_versioned() {
+		let global = Arc::new(Definitions::default());
+		let key = crate::key::tb::prefix("test", "test");
+		let val = Entry::Tbs(Arc::new([]));
+		// A transaction populates the cache for the same version
+		let mut txn = Cache::new(global.clone());
+		txn.set_version(1);
+		txn.set(key.clone(), val.clone());
+		let mut txn = Cache::new(global.clone());
+		txn.set_version(1);
+		assert!(txn.get(&key).is_some());
+		// The cache is not used before the version is known
+		let mut txn = Cache::new(global.clone());
+		assert!(txn.needs_version());
+		assert!(txn.get(&key).is_none());
+		// The cache is not used at a different version
+		let mut txn = Cache::new(global.clone());
+		txn.set_version(2);
+		assert!(txn.get(&key).is_none());
+		// The cache is not used once definitions are changed
+		let mut txn = Cache::new(global);
+		txn.set_version(1);
+		txn.write(&crate::key::fd::new("test", "test", "test", "name").encode().unwrap());
+		assert!(txn.changed());
+		assert!(!txn.needs_version());
+		assert!(txn.get(&key).is_none());
 	}
 }
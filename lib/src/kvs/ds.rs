@@ -1,9 +1,14 @@
+use super::cache::Definitions;
 use super::tx::Transaction;
 use crate::ctx::Context;
 use crate::dbs::Attach;
+use crate::dbs::Chunk;
 use crate::dbs::Executor;
+use crate::dbs::Notification;
 use crate::dbs::Options;
+use crate::dbs::QueryLog;
 use crate::dbs::Response;
+use crate::dbs::ScriptOptions;
 use crate::dbs::Session;
 use crate::dbs::Variables;
 use crate::err::Error;
@@ -11,8 +16,10 @@ use crate::kvs::LOG;
 use crate::sql;
 use crate::sql::Query;
 use crate::sql::Value;
+use channel::Receiver;
 use channel::Sender;
 use futures::lock::Mutex;
+use std::collections::BTreeMap;
 use std::fmt;
 use std::sync::Arc;
 use tracing::instrument;
@@ -21,6 +28,12 @@ use tracing::instrument;
 #[allow(dead_code)]
 pub struct Datastore {
 	pub(super) inner: Inner,
+	pub(super) notification_channel: Option<(Sender<Notification>, Receiver<Notification>)>,
+	pub(super) sync_log: bool,
+	pub(super) query_log: Option<QueryLog>,
+	pub(super) script: Arc<ScriptOptions>,
+	pub(super) jwks_sources: Vec<String>,
+	pub(super) definitions: Arc<Definitions>,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -103,6 +116,12 @@ impl Datastore {
 					info!(target: LOG, "Starting kvs store in {}", path);
 					let v = super::mem::Datastore::new().await.map(|v| Datastore {
 						inner: Inner::Mem(v),
+						notification_channel: None,
+						sync_log: false,
+						query_log: None,
+						script: Arc::default(),
+						jwks_sources: Vec::new(),
+						definitions: Arc::default(),
 					});
 					info!(target: LOG, "Started kvs store in {}", path);
 					v
@@ -120,6 +139,12 @@ impl Datastore {
 					let s = s.trim_start_matches("file:");
 					let v = super::rocksdb::Datastore::new(s).await.map(|v| Datastore {
 						inner: Inner::RocksDB(v),
+						notification_channel: None,
+						sync_log: false,
+						query_log: None,
+						script: Arc::default(),
+						jwks_sources: Vec::new(),
+						definitions: Arc::default(),
 					});
 					info!(target: LOG, "Started kvs store at {}", path);
 					v
@@ -137,6 +162,12 @@ impl Datastore {
 					let s = s.trim_start_matches("rocksdb:");
 					let v = super::rocksdb::Datastore::new(s).await.map(|v| Datastore {
 						inner: Inner::RocksDB(v),
+						notification_channel: None,
+						sync_log: false,
+						query_log: None,
+						script: Arc::default(),
+						jwks_sources: Vec::new(),
+						definitions: Arc::default(),
 					});
 					info!(target: LOG, "Started kvs store at {}", path);
 					v
@@ -154,6 +185,12 @@ impl Datastore {
 					let s = s.trim_start_matches("indxdb:");
 					let v = super::indxdb::Datastore::new(s).await.map(|v| Datastore {
 						inner: Inner::IndxDB(v),
+						notification_channel: None,
+						sync_log: false,
+						query_log: None,
+						script: Arc::default(),
+						jwks_sources: Vec::new(),
+						definitions: Arc::default(),
 					});
 					info!(target: LOG, "Started kvs store at {}", path);
 					v
@@ -171,6 +208,12 @@ impl Datastore {
 					let s = s.trim_start_matches("tikv:");
 					let v = super::tikv::Datastore::new(s).await.map(|v| Datastore {
 						inner: Inner::TiKV(v),
+						notification_channel: None,
+						sync_log: false,
+						query_log: None,
+						script: Arc::default(),
+						jwks_sources: Vec::new(),
+						definitions: Arc::default(),
 					});
 					info!(target: LOG, "Connected to kvs store at {}", path);
 					v
@@ -188,6 +231,12 @@ impl Datastore {
 					let s = s.trim_start_matches("fdb:");
 					let v = super::fdb::Datastore::new(s).await.map(|v| Datastore {
 						inner: Inner::FDB(v),
+						notification_channel: None,
+						sync_log: false,
+						query_log: None,
+						script: Arc::default(),
+						jwks_sources: Vec::new(),
+						definitions: Arc::default(),
 					});
 					info!(target: LOG, "Connected to kvs store at {}", path);
 					v
@@ -204,6 +253,94 @@ impl Datastore {
 		}
 	}
 
+	/// Enable the delivery of live query notifications
+	///
+	/// ```rust,no_run
+	/// # use surrealdb::kvs::Datastore;
+	/// # use surrealdb::err::Error;
+	/// # #[tokio::main]
+	/// # async fn main() -> Result<(), Error> {
+	/// let ds = Datastore::new("memory").await?.with_notifications();
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn with_notifications(mut self) -> Self {
+		self.notification_channel = Some(channel::unbounded());
+		self
+	}
+
+	/// Enable the sync log, which records the writes of every committed transaction
+	///
+	/// The sync log allows another datastore to follow this one incrementally,
+	/// by requesting the writes made since a position in the log, using
+	/// [`Datastore::sync`], after it has been loaded from a full snapshot.
+	pub fn with_sync_log(mut self) -> Self {
+		self.sync_log = true;
+		self
+	}
+
+	/// Enable the query log, which records executed statements
+	///
+	/// Depending on the configuration of the [`QueryLog`], this records
+	/// either slow statements, or every statement, as JSON lines.
+	pub fn with_query_log(mut self, log: QueryLog) -> Self {
+		self.query_log = Some(log);
+		self
+	}
+
+	/// Configure the limits and capabilities of embedded script functions
+	///
+	/// ```rust,no_run
+	/// # use surrealdb::dbs::ScriptOptions;
+	/// # use surrealdb::kvs::Datastore;
+	/// # use surrealdb::err::Error;
+	/// # use std::time::Duration;
+	/// # #[tokio::main]
+	/// # async fn main() -> Result<(), Error> {
+	/// let opt = ScriptOptions::default()
+	///     .with_timeout(Duration::from_secs(5))
+	///     .with_fetch_hosts(vec!["api.example.com".to_owned()])
+	///     .with_os(false);
+	/// let ds = Datastore::new("memory").await?.with_script_options(opt);
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn with_script_options(mut self, opt: ScriptOptions) -> Self {
+		self.script = Arc::new(opt);
+		self
+	}
+
+	/// Allow JWKS token definitions to load key sets from these sources
+	///
+	/// By default key sets can only be specified inline, as loading them
+	/// from a URL or a local file would let any user able to define a
+	/// token make requests from, or read files on, the server. Each
+	/// source is either a URL or a local file path, and a source ending
+	/// with a `/` allows every URL or file path inside it.
+	pub fn with_jwks_sources(mut self, sources: Vec<String>) -> Self {
+		self.jwks_sources = sources;
+		self
+	}
+
+	/// Get the sources which key sets can be loaded from
+	pub(crate) fn jwks_sources(&self) -> &[String] {
+		&self.jwks_sources
+	}
+
+	/// Get the query log, if it has been enabled
+	pub(crate) fn query_log(&self) -> Option<&QueryLog> {
+		self.query_log.as_ref()
+	}
+
+	/// Get a receiver for live query notifications
+	///
+	/// Notifications are sent once the transaction which produced them has
+	/// been committed. This returns [`None`] unless live query notifications
+	/// have been enabled using [`Datastore::with_notifications`].
+	pub fn notifications(&self) -> Option<Receiver<Notification>> {
+		self.notification_channel.as_ref().map(|(_, rx)| rx.clone())
+	}
+
 	/// Create a new transaction on this datastore
 	///
 	/// ```rust,no_run
@@ -253,7 +390,12 @@ impl Datastore {
 		#[allow(unreachable_code)]
 		Ok(Transaction {
 			inner,
-			cache: super::cache::Cache::default(),
+			cache: super::cache::Cache::new(self.definitions.clone()),
+			sender: self.notification_channel.as_ref().map(|(tx, _)| tx.clone()),
+			notifications: Vec::new(),
+			changes: BTreeMap::new(),
+			writes: self.sync_log.then(Vec::new),
+			write,
 		})
 	}
 
@@ -284,7 +426,7 @@ impl Datastore {
 		// Create a new query options
 		let mut opt = Options::default();
 		// Create a new query executor
-		let mut exe = Executor::new(self);
+		let mut exe = Executor::new(self, sess);
 		// Create a default context
 		let ctx = Context::default();
 		// Start an execution context
@@ -295,6 +437,7 @@ impl Datastore {
 		let ast = sql::parse(txt)?;
 		// Setup the auth options
 		opt.auth = sess.au.clone();
+		opt.role = sess.rl;
 		// Setup the live options
 		opt.live = sess.rt;
 		// Set current NS and DB
@@ -302,6 +445,8 @@ impl Datastore {
 		opt.db = sess.db();
 		// Set strict config
 		opt.strict = strict;
+		// Set scripting config
+		opt.script = self.script.clone();
 		// Process all statements
 		exe.execute(ctx, opt, ast).await
 	}
@@ -334,7 +479,7 @@ impl Datastore {
 		// Create a new query options
 		let mut opt = Options::default();
 		// Create a new query executor
-		let mut exe = Executor::new(self);
+		let mut exe = Executor::new(self, sess);
 		// Create a default context
 		let ctx = Context::default();
 		// Start an execution context
@@ -343,6 +488,7 @@ impl Datastore {
 		let ctx = vars.attach(ctx)?;
 		// Setup the auth options
 		opt.auth = sess.au.clone();
+		opt.role = sess.rl;
 		// Setup the live options
 		opt.live = sess.rt;
 		// Set current NS and DB
@@ -350,10 +496,90 @@ impl Datastore {
 		opt.db = sess.db();
 		// Set strict config
 		opt.strict = strict;
+		// Set scripting config
+		opt.script = self.script.clone();
 		// Process all statements
 		exe.execute(ctx, opt, ast).await
 	}
 
+	/// Parse and execute an SQL query, streaming the output to a channel
+	///
+	/// The records output by each `SELECT` statement which can be streamed
+	/// are sent as they are produced, followed by the response of every
+	/// statement in the query set, in order. See [`Chunk`] for details.
+	///
+	/// ```rust,no_run
+	/// use surrealdb::kvs::Datastore;
+	/// use surrealdb::err::Error;
+	/// use surrealdb::dbs::Session;
+	///
+	/// #[tokio::main]
+	/// async fn main() -> Result<(), Error> {
+	///     let ds = Datastore::new("memory").await?;
+	///     let ses = Session::for_kv();
+	///     let ast = "USE NS test DB test; SELECT * FROM person;";
+	///     let (chn, rcv) = surrealdb::channel::new(100);
+	///     let (res, _) = futures::join!(
+	///         ds.execute_stream(ast, &ses, None, false, chn),
+	///         async { while let Ok(_chunk) = rcv.recv().await {} },
+	///     );
+	///     res?;
+	///     Ok(())
+	/// }
+	/// ```
+	#[instrument(skip_all)]
+	pub async fn execute_stream(
+		&self,
+		txt: &str,
+		sess: &Session,
+		vars: Variables,
+		strict: bool,
+		chn: Sender<Chunk>,
+	) -> Result<(), Error> {
+		// Parse the SQL query text
+		let ast = sql::parse(txt)?;
+		// Process all statements
+		self.process_stream(ast, sess, vars, strict, chn).await
+	}
+
+	/// Execute a pre-parsed SQL query, streaming the output to a channel
+	#[instrument(skip_all)]
+	pub async fn process_stream(
+		&self,
+		ast: Query,
+		sess: &Session,
+		vars: Variables,
+		strict: bool,
+		chn: Sender<Chunk>,
+	) -> Result<(), Error> {
+		// Create a new query options
+		let mut opt = Options::default();
+		// Create a new query executor
+		let mut exe = Executor::new(self, sess).with_stream(chn);
+		// Create a default context
+		let ctx = Context::default();
+		// Start an execution context
+		let ctx = sess.context(ctx);
+		// Store the query variables
+		let ctx = vars.attach(ctx)?;
+		// Setup the auth options
+		opt.auth = sess.au.clone();
+		opt.role = sess.rl;
+		// Setup the live options
+		opt.live = sess.rt;
+		// Set current NS and DB
+		opt.ns = sess.ns();
+		opt.db = sess.db();
+		// Set strict config
+		opt.strict = strict;
+		// Set scripting config
+		opt.script = self.script.clone();
+		// Process all statements
+		exe.execute(ctx, opt, ast).await?;
+		// Everything ok
+		Ok(())
+	}
+
 	/// Ensure a SQL [`Value`] is fully computed
 	///
 	/// ```rust,no_run
@@ -394,11 +620,14 @@ impl Datastore {
 		let ctx = vars.attach(ctx)?;
 		// Setup the auth options
 		opt.auth = sess.au.clone();
+		opt.role = sess.rl;
 		// Set current NS and DB
 		opt.ns = sess.ns();
 		opt.db = sess.db();
 		// Set strict config
 		opt.strict = strict;
+		// Set scripting config
+		opt.script = self.script.clone();
 		// Compute the value
 		let res = val.compute(&ctx, &opt, &txn, None).await?;
 		// Store any data
@@ -420,4 +649,47 @@ impl Datastore {
 		// Everything ok
 		Ok(())
 	}
+
+	/// Streams the entire datastore as binary sync data
+	///
+	/// Without a position, this sends a consistent snapshot of every key in the
+	/// datastore. With a position in the sync log, this sends only the writes
+	/// which have been committed since then. In both cases the stream starts
+	/// with the latest position in the sync log, which can be used to request
+	/// the next set of writes.
+	#[instrument(skip(self, chn))]
+	pub async fn sync(&self, since: Option<u64>, chn: Sender<Vec<u8>>) -> Result<(), Error> {
+		// Start a new transaction
+		let mut txn = self.transaction(false, false).await?;
+		// Process the sync
+		match since {
+			Some(since) => txn.sync_since(since, chn).await?,
+			None => txn.sync(chn).await?,
+		}
+		// Everything ok
+		Ok(())
+	}
+
+	/// Loads binary sync data into the datastore
+	///
+	/// The data is loaded within a single transaction, and the position in
+	/// the sync log of the source datastore is returned, if it was present.
+	#[instrument(skip(self, data))]
+	pub async fn load(&self, data: &[u8]) -> Result<Option<u64>, Error> {
+		// Parse the sync data
+		let frames = super::sync::decode(data)?;
+		// Start a new transaction
+		let mut txn = self.transaction(true, false).await?;
+		// Process the frames
+		match txn.load(frames).await {
+			Ok(seq) => {
+				txn.commit().await?;
+				Ok(seq)
+			}
+			Err(e) => {
+				txn.cancel().await?;
+				Err(e)
+			}
+		}
+	}
 }
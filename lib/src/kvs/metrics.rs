@@ -0,0 +1,284 @@
+use once_cell::sync::Lazy;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::time::Duration;
+
+/// The upper bounds, in seconds, of the statement latency histogram buckets
+const BUCKETS: [f64; 12] =
+	[0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 5.0];
+
+/// The statement types which are recorded, in label order
+const STATEMENTS: [&str; 25] = [
+	"begin", "break", "cancel", "commit", "continue", "create", "define", "delete", "for", "if",
+	"info", "insert", "kill", "let", "live", "option", "relate", "remove", "return", "select",
+	"show", "sleep", "throw", "update", "use",
+];
+
+/// The key-value operations which are recorded, in label order
+const OPERATIONS: [&str; 8] = ["del", "delc", "exi", "get", "put", "putc", "scan", "set"];
+
+/// A key-value operation, indexing the operation labels
+#[derive(Clone, Copy)]
+pub(crate) enum Operation {
+	Del,
+	Delc,
+	Exi,
+	Get,
+	Put,
+	Putc,
+	Scan,
+	Set,
+}
+
+/// The metrics recorded by all datastores in this process
+pub static METRICS: Lazy<Metrics> = Lazy::new(Metrics::default);
+
+/// Counters and histograms recording the activity of the datastore
+#[derive(Default)]
+pub struct Metrics {
+	/// The number of statements executed, by statement type
+	statements: Family<Counter, { STATEMENTS.len() }>,
+	/// The time taken to execute statements, by statement type
+	durations: Family<Histogram, { STATEMENTS.len() }>,
+	/// The number of transactions which were committed
+	committed: Counter,
+	/// The number of write transactions which were cancelled or failed to commit
+	cancelled: Counter,
+	/// The number of key-value operations, by operation type
+	operations: Family<Counter, { OPERATIONS.len() }>,
+}
+
+impl Metrics {
+	/// Records a statement which has been executed
+	pub(crate) fn statement(&self, name: &'static str, time: Duration) {
+		if let Some(i) = STATEMENTS.iter().position(|v| *v == name) {
+			self.statements.0[i].inc();
+			self.durations.0[i].observe(time);
+		}
+	}
+	/// Records a transaction which has been committed
+	pub(crate) fn committed(&self) {
+		self.committed.inc();
+	}
+	/// Records a write transaction which has been cancelled, or which failed to commit
+	pub(crate) fn cancelled(&self) {
+		self.cancelled.inc();
+	}
+	/// Records a key-value operation
+	pub(crate) fn operation(&self, op: Operation) {
+		self.operations.0[op as usize].inc();
+	}
+	/// Renders the metrics in the Prometheus text exposition format
+	pub fn render(&self) -> String {
+		let mut out = String::new();
+		// Output the statement metrics
+		header(&mut out, "surrealdb_statements_total", "counter", "Statements executed");
+		self.statements.each(&STATEMENTS, |k, v| {
+			v.render(&mut out, "surrealdb_statements_total", "statement", k)
+		});
+		header(&mut out, "surrealdb_statement_duration_seconds", "histogram", "Statement latency");
+		self.durations.each(&STATEMENTS, |k, v| {
+			v.render(&mut out, "surrealdb_statement_duration_seconds", "statement", k)
+		});
+		// Output the transaction metrics
+		header(
+			&mut out,
+			"surrealdb_transactions_committed_total",
+			"counter",
+			"Transactions committed",
+		);
+		self.committed.render(&mut out, "surrealdb_transactions_committed_total", "", "");
+		header(
+			&mut out,
+			"surrealdb_transactions_cancelled_total",
+			"counter",
+			"Write transactions cancelled or failed to commit",
+		);
+		self.cancelled.render(&mut out, "surrealdb_transactions_cancelled_total", "", "");
+		// Output the key-value metrics
+		header(&mut out, "surrealdb_kv_operations_total", "counter", "Key-value operations");
+		self.operations.each(&OPERATIONS, |k, v| {
+			v.render(&mut out, "surrealdb_kv_operations_total", "operation", k)
+		});
+		out
+	}
+}
+
+/// Writes the description and type of a metric
+pub fn header(out: &mut String, name: &str, kind: &str, help: &str) {
+	let _ = writeln!(out, "# HELP {name} {help}");
+	let _ = writeln!(out, "# TYPE {name} {kind}");
+}
+
+/// Formats a single label for a metric sample
+fn label(key: &str, val: &str) -> String {
+	match key.is_empty() {
+		true => String::new(),
+		false => format!("{key}=\"{val}\""),
+	}
+}
+
+/// A metric which can only increase
+#[derive(Default)]
+pub struct Counter(AtomicU64);
+
+impl Counter {
+	/// Increments the counter
+	pub fn inc(&self) {
+		self.0.fetch_add(1, Ordering::Relaxed);
+	}
+	/// Returns the current value of the counter
+	pub fn get(&self) -> u64 {
+		self.0.load(Ordering::Relaxed)
+	}
+	/// Writes the counter sample, with an optional label
+	pub fn render(&self, out: &mut String, name: &str, key: &str, val: &str) {
+		let _ = match key.is_empty() {
+			true => writeln!(out, "{name} {}", self.get()),
+			false => writeln!(out, "{name}{{{}}} {}", label(key, val), self.get()),
+		};
+	}
+}
+
+/// A metric which can increase and decrease
+#[derive(Default)]
+pub struct Gauge(AtomicI64);
+
+impl Gauge {
+	/// Increments the gauge
+	pub fn inc(&self) {
+		self.0.fetch_add(1, Ordering::Relaxed);
+	}
+	/// Decrements the gauge
+	pub fn dec(&self) {
+		self.0.fetch_sub(1, Ordering::Relaxed);
+	}
+	/// Returns the current value of the gauge
+	pub fn get(&self) -> i64 {
+		self.0.load(Ordering::Relaxed)
+	}
+	/// Writes the gauge sample
+	pub fn render(&self, out: &mut String, name: &str) {
+		let _ = writeln!(out, "{name} {}", self.get());
+	}
+}
+
+/// A metric which counts observed durations in buckets
+#[derive(Default)]
+pub struct Histogram {
+	/// The number of observations in each bucket
+	buckets: [AtomicU64; BUCKETS.len()],
+	/// The total of all observations, in nanoseconds
+	sum: AtomicU64,
+	/// The number of observations
+	count: AtomicU64,
+}
+
+impl Histogram {
+	/// Records an observed duration
+	pub fn observe(&self, time: Duration) {
+		let secs = time.as_secs_f64();
+		if let Some(i) = BUCKETS.iter().position(|b| secs <= *b) {
+			self.buckets[i].fetch_add(1, Ordering::Relaxed);
+		}
+		self.sum.fetch_add(time.as_nanos() as u64, Ordering::Relaxed);
+		self.count.fetch_add(1, Ordering::Relaxed);
+	}
+	/// Writes the cumulative bucket, sum, and count samples, with a label
+	pub fn render(&self, out: &mut String, name: &str, key: &str, val: &str) {
+		let lbl = label(key, val);
+		let sep = if lbl.is_empty() {
+			""
+		} else {
+			","
+		};
+		let mut total = 0;
+		for (i, b) in BUCKETS.iter().enumerate() {
+			total += self.buckets[i].load(Ordering::Relaxed);
+			let _ = writeln!(out, "{name}_bucket{{{lbl}{sep}le=\"{b}\"}} {total}");
+		}
+		let count = self.count.load(Ordering::Relaxed);
+		let sum = self.sum.load(Ordering::Relaxed) as f64 / 1e9;
+		let _ = writeln!(out, "{name}_bucket{{{lbl}{sep}le=\"+Inf\"}} {count}");
+		let _ = writeln!(out, "{name}_sum{{{lbl}}} {sum}");
+		let _ = writeln!(out, "{name}_count{{{lbl}}} {count}");
+	}
+}
+
+/// A metric which records how many times it was used
+trait Used {
+	/// Checks if the metric has recorded any value
+	fn used(&self) -> bool;
+}
+
+impl Used for Counter {
+	fn used(&self) -> bool {
+		self.get() > 0
+	}
+}
+
+impl Used for Histogram {
+	fn used(&self) -> bool {
+		self.count.load(Ordering::Relaxed) > 0
+	}
+}
+
+/// A set of metrics, each identified by the value of a single label,
+/// and stored at the position of that label in a fixed list of labels
+struct Family<T, const N: usize>([T; N]);
+
+impl<T: Default, const N: usize> Default for Family<T, N> {
+	fn default() -> Self {
+		Self(std::array::from_fn(|_| T::default()))
+	}
+}
+
+impl<T: Used, const N: usize> Family<T, N> {
+	/// Runs a function on every metric which has been used, in label order
+	fn each(&self, keys: &[&str; N], mut f: impl FnMut(&str, &T)) {
+		keys.iter().zip(self.0.iter()).filter(|(_, v)| v.used()).for_each(|(k, v)| f(k, v));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn render_metrics() {
+		let met = Metrics::default();
+		met.statement("select", Duration::from_millis(2));
+		met.statement("select", Duration::from_secs(10));
+		met.committed();
+		met.operation(Operation::Get);
+		met.operation(Operation::Get);
+		let out = met.render();
+		assert!(out.contains("surrealdb_statements_total{statement=\"select\"} 2\n"));
+		assert!(out.contains(
+			"surrealdb_statement_duration_seconds_bucket{statement=\"select\",le=\"0.0025\"} 1\n"
+		));
+		assert!(out.contains(
+			"surrealdb_statement_duration_seconds_bucket{statement=\"select\",le=\"+Inf\"} 2\n"
+		));
+		assert!(
+			out.contains("surrealdb_statement_duration_seconds_sum{statement=\"select\"} 10.002\n")
+		);
+		assert!(out.contains("surrealdb_transactions_committed_total 1\n"));
+		assert!(out.contains("surrealdb_transactions_cancelled_total 0\n"));
+		assert!(out.contains("surrealdb_kv_operations_total{operation=\"get\"} 2\n"));
+		assert!(!out.contains("operation=\"set\""));
+	}
+
+	#[test]
+	fn labels_match_operations() {
+		assert_eq!(OPERATIONS[Operation::Del as usize], "del");
+		assert_eq!(OPERATIONS[Operation::Delc as usize], "delc");
+		assert_eq!(OPERATIONS[Operation::Exi as usize], "exi");
+		assert_eq!(OPERATIONS[Operation::Get as usize], "get");
+		assert_eq!(OPERATIONS[Operation::Put as usize], "put");
+		assert_eq!(OPERATIONS[Operation::Putc as usize], "putc");
+		assert_eq!(OPERATIONS[Operation::Scan as usize], "scan");
+		assert_eq!(OPERATIONS[Operation::Set as usize], "set");
+	}
+}
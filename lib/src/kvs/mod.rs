@@ -4,7 +4,9 @@ mod fdb;
 mod indxdb;
 mod kv;
 mod mem;
+pub mod metrics;
 mod rocksdb;
+mod sync;
 mod tikv;
 mod tx;
 
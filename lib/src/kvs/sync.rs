@@ -0,0 +1,154 @@
+use crate::err::Error;
+use crate::kvs::Key;
+use crate::kvs::Val;
+
+/// The bytes at the start of every sync stream
+const HEADER: &[u8] = b"SURREALDB-SYNC\x01";
+
+const END: u8 = 0x00;
+const SEQ: u8 = 0x01;
+const CLEAR: u8 = 0x02;
+const SET: u8 = 0x03;
+const DEL: u8 = 0x04;
+
+/// A single entry in a sync stream
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) enum Frame {
+	/// Marks the end of a complete stream
+	End,
+	/// The position in the sync log which the stream is up to date with
+	Seq(u64),
+	/// Removes all existing keys, before a snapshot is loaded
+	Clear,
+	/// Inserts or updates a key
+	Set(Key, Val),
+	/// Deletes a key
+	Del(Key),
+}
+
+impl Frame {
+	/// Appends the binary representation of this frame to a buffer
+	pub(super) fn encode(&self, buf: &mut Vec<u8>) {
+		match self {
+			Frame::End => buf.push(END),
+			Frame::Seq(v) => {
+				buf.push(SEQ);
+				buf.extend_from_slice(&v.to_be_bytes());
+			}
+			Frame::Clear => buf.push(CLEAR),
+			Frame::Set(k, v) => {
+				buf.push(SET);
+				write(buf, k);
+				write(buf, v);
+			}
+			Frame::Del(k) => {
+				buf.push(DEL);
+				write(buf, k);
+			}
+		}
+	}
+}
+
+/// Returns the bytes which begin a sync stream
+pub(super) fn header() -> Vec<u8> {
+	HEADER.to_vec()
+}
+
+/// Checks whether a key belongs to the sync log, which is never synced
+pub(super) fn is_log_key(key: &[u8]) -> bool {
+	let sq = crate::key::sq::new().encode().unwrap_or_default();
+	key == sq || key.starts_with(&crate::key::sy::prefix()[..sq.len()])
+}
+
+/// Parses the frames of a complete sync stream
+///
+/// The stream is rejected if it was truncated, so that an
+/// incomplete snapshot is never loaded into a datastore.
+pub(super) fn decode(mut buf: &[u8]) -> Result<Vec<Frame>, Error> {
+	// Check the stream header
+	buf = buf.strip_prefix(HEADER).ok_or(Error::InvalidSync)?;
+	// Parse each of the frames
+	let mut out = Vec::new();
+	while let Some((tag, rest)) = buf.split_first() {
+		buf = rest;
+		let frame = match *tag {
+			END if rest.is_empty() => return Ok(out),
+			SEQ => {
+				let (v, rest) = split(buf, 8)?;
+				buf = rest;
+				Frame::Seq(u64::from_be_bytes(v.try_into().map_err(|_| Error::InvalidSync)?))
+			}
+			CLEAR => Frame::Clear,
+			SET => {
+				let k = read(&mut buf)?;
+				let v = read(&mut buf)?;
+				Frame::Set(k, v)
+			}
+			DEL => Frame::Del(read(&mut buf)?),
+			_ => return Err(Error::InvalidSync),
+		};
+		out.push(frame);
+	}
+	Err(Error::InvalidSync)
+}
+
+fn write(buf: &mut Vec<u8>, v: &[u8]) {
+	buf.extend_from_slice(&(v.len() as u32).to_be_bytes());
+	buf.extend_from_slice(v);
+}
+
+fn read(buf: &mut &[u8]) -> Result<Vec<u8>, Error> {
+	let (len, rest) = split(buf, 4)?;
+	let len = u32::from_be_bytes(len.try_into().map_err(|_| Error::InvalidSync)?);
+	let (v, rest) = split(rest, len as usize)?;
+	*buf = rest;
+	Ok(v.to_vec())
+}
+
+fn split(buf: &[u8], len: usize) -> Result<(&[u8], &[u8]), Error> {
+	match buf.len() >= len {
+		true => Ok(buf.split_at(len)),
+		false => Err(Error::InvalidSync),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn roundtrip() {
+		let frames = vec![
+			Frame::Seq(42),
+			Frame::Clear,
+			Frame::Set(b"key".to_vec(), b"val".to_vec()),
+			Frame::Set(b"empty".to_vec(), vec![]),
+			Frame::Del(b"key".to_vec()),
+		];
+		let mut buf = header();
+		for f in frames.iter() {
+			f.encode(&mut buf);
+		}
+		Frame::End.encode(&mut buf);
+		assert_eq!(decode(&buf).unwrap(), frames);
+	}
+
+	#[test]
+	fn invalid() {
+		assert!(decode(b"invalid").is_err());
+		let mut buf = header();
+		Frame::Set(b"key".to_vec(), b"val".to_vec()).encode(&mut buf);
+		assert!(decode(&buf).is_err());
+		buf.pop();
+		Frame::End.encode(&mut buf);
+		assert!(decode(&buf).is_err());
+	}
+
+	#[test]
+	fn log_keys() {
+		assert!(is_log_key(&crate::key::sq::new().encode().unwrap()));
+		assert!(is_log_key(&crate::key::sy::new(1).encode().unwrap()));
+		assert!(!is_log_key(&crate::key::ns::new("test").encode().unwrap()));
+	}
+}
@@ -1,35 +1,45 @@
 use super::kv::Add;
 use super::kv::Convert;
+use super::metrics::Operation;
+use super::metrics::METRICS;
+use super::sync::Frame;
 use super::Key;
 use super::Val;
+use crate::cnf::DEFINITIONS_VERSION_RETRIES;
+use crate::dbs::Notification;
 use crate::err::Error;
 use crate::key::thing;
 use crate::kvs::cache::Cache;
 use crate::kvs::cache::Entry;
 use crate::sql;
+use crate::sql::id::Id;
 use crate::sql::paths::EDGE;
 use crate::sql::paths::IN;
 use crate::sql::paths::OUT;
 use crate::sql::thing::Thing;
+use crate::sql::Datetime;
 use crate::sql::Value;
 use channel::Sender;
 use sql::permission::Permissions;
+use sql::statements::DefineAnalyzerStatement;
 use sql::statements::DefineDatabaseStatement;
 use sql::statements::DefineEventStatement;
 use sql::statements::DefineFieldStatement;
 use sql::statements::DefineFunctionStatement;
 use sql::statements::DefineIndexStatement;
-use sql::statements::DefineLoginStatement;
 use sql::statements::DefineNamespaceStatement;
 use sql::statements::DefineParamStatement;
 use sql::statements::DefineScopeStatement;
 use sql::statements::DefineTableStatement;
 use sql::statements::DefineTokenStatement;
+use sql::statements::DefineUserStatement;
 use sql::statements::LiveStatement;
+use std::collections::BTreeMap;
 use std::fmt;
 use std::fmt::Debug;
 use std::ops::Range;
 use std::sync::Arc;
+use std::time::Duration;
 
 #[cfg(debug_assertions)]
 const LOG: &str = "surrealdb::txn";
@@ -39,6 +49,11 @@ const LOG: &str = "surrealdb::txn";
 pub struct Transaction {
 	pub(super) inner: Inner,
 	pub(super) cache: Cache,
+	pub(super) sender: Option<Sender<Notification>>,
+	pub(super) notifications: Vec<Notification>,
+	pub(super) changes: BTreeMap<(String, String, String), (Duration, Vec<Value>)>,
+	pub(super) writes: Option<Vec<u8>>,
+	pub(super) write: bool,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -126,6 +141,19 @@ impl Transaction {
 	pub async fn cancel(&mut self) -> Result<(), Error> {
 		#[cfg(debug_assertions)]
 		trace!(target: LOG, "Cancel");
+		// Discard any queued notifications
+		self.notifications.clear();
+		// Discard any recorded data changes
+		self.changes.clear();
+		// Discard any recorded writes
+		if let Some(writes) = &mut self.writes {
+			writes.clear();
+		}
+		// Record the cancellation of write transactions
+		if self.write {
+			METRICS.cancelled();
+		}
+		// Cancel the transaction
 		match self {
 			#[cfg(feature = "kv-mem")]
 			Transaction {
@@ -163,7 +191,13 @@ impl Transaction {
 	pub async fn commit(&mut self) -> Result<(), Error> {
 		#[cfg(debug_assertions)]
 		trace!(target: LOG, "Commit");
-		match self {
+		// Write any recorded changes, and cancel the transaction if this fails
+		if let Err(e) = self.write_pending().await {
+			let _ = self.cancel().await;
+			return Err(e);
+		}
+		// Commit the transaction
+		let res = match self {
 			#[cfg(feature = "kv-mem")]
 			Transaction {
 				inner: Inner::Mem(v),
@@ -191,7 +225,173 @@ impl Transaction {
 			} => v.commit().await,
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
+		};
+		// Record the commit, or the failure to commit
+		match res.is_ok() {
+			true => METRICS.committed(),
+			false => METRICS.cancelled(),
+		}
+		// Send any queued notifications
+		if res.is_ok() {
+			if let Some(chn) = &self.sender {
+				for v in self.notifications.drain(..) {
+					let _ = chn.try_send(v);
+				}
+			}
+		}
+		// Return the result
+		res
+	}
+
+	/// Write any data changes, definition versions, and sync log entries
+	/// which have been recorded during the transaction.
+	async fn write_pending(&mut self) -> Result<(), Error> {
+		// Write any recorded data changes
+		self.write_changes().await?;
+		// Write the version of any changed definitions
+		self.write_definitions_version().await?;
+		// Write any recorded writes to the sync log
+		self.write_sync_log().await
+	}
+
+	/// Queue a notification for a live query.
+	///
+	/// Notifications are only sent once the transaction has been
+	/// successfully committed, and are discarded if it is cancelled.
+	pub(crate) fn notify(&mut self, notification: Notification) {
+		if self.sender.is_some() {
+			self.notifications.push(notification);
+		}
+	}
+
+	/// Record a write for the sync log.
+	fn log(&mut self, frame: Frame) {
+		if let Some(writes) = &mut self.writes {
+			frame.encode(writes);
+		}
+	}
+
+	/// Write the recorded writes to the sync log.
+	///
+	/// All of the writes made within this transaction are stored
+	/// together, at the next position in the sync log.
+	async fn write_sync_log(&mut self) -> Result<(), Error> {
+		// Take the recorded writes, so that the log itself is not recorded
+		let writes = match self.writes.take() {
+			Some(v) if !v.is_empty() => v,
+			_ => return Ok(()),
+		};
+		// Allocate the next position in the sync log
+		let sq = self.sync_position().await? + 1;
+		self.set(crate::key::sq::new(), sq.to_be_bytes().to_vec()).await?;
+		// Store the writes at this position
+		self.set(crate::key::sy::new(sq), writes).await
+	}
+
+	/// Allocate the next audit record position in a database.
+	///
+	/// Positions are unique and increasing across all of the audited
+	/// tables in a database, so audit records are never overwritten.
+	pub(crate) async fn next_audit(&mut self, ns: &str, db: &str) -> Result<u64, Error> {
+		let key = crate::key::aq::new(ns, db);
+		let sq = match self.get(key.clone()).await? {
+			Some(v) => u64::from_be_bytes(v.try_into().unwrap_or_default()) + 1,
+			None => 1,
+		};
+		self.set(key, sq.to_be_bytes().to_vec()).await?;
+		Ok(sq)
+	}
+
+	/// Record a data change for a table with a change feed.
+	///
+	/// Changes are written to the change feed once the transaction
+	/// is committed, and are discarded if it is cancelled.
+	pub(crate) fn record_change(
+		&mut self,
+		ns: &str,
+		db: &str,
+		tb: &str,
+		expiry: Duration,
+		change: Value,
+	) {
+		let key = (ns.to_owned(), db.to_owned(), tb.to_owned());
+		self.changes.entry(key).or_insert_with(|| (expiry, Vec::new())).1.push(change);
+	}
+
+	/// Write the recorded data changes to the table change feeds.
+	///
+	/// All of the changes made to a database within this transaction
+	/// are written at the same, newly allocated, versionstamp.
+	async fn write_changes(&mut self) -> Result<(), Error> {
+		// Take the recorded data changes
+		let changes = std::mem::take(&mut self.changes);
+		// Get the current time
+		let now = Datetime::default();
+		// The current versionstamp for each database
+		let mut cur: Option<(String, String, u64)> = None;
+		// Loop over the changes for each table
+		for ((ns, db, tb), (expiry, mutations)) in changes {
+			// Allocate a versionstamp for this database
+			let vs = match cur {
+				Some((ref n, ref d, vs)) if *n == ns && *d == db => vs,
+				_ => {
+					let key = crate::key::vs::new(&ns, &db);
+					let vs = match self.get(key.clone()).await? {
+						Some(v) => u64::from_be_bytes(v.try_into().unwrap_or_default()) + 1,
+						None => 1,
+					};
+					self.set(key, vs.to_be_bytes().to_vec()).await?;
+					cur = Some((ns.clone(), db.clone(), vs));
+					vs
+				}
+			};
+			// Write the changes for this table
+			let key = crate::key::cf::new(&ns, &db, &tb, vs);
+			let val = Value::from(map! {
+				String::from("timestamp") => now.clone().into(),
+				String::from("changes") => mutations.into(),
+			});
+			self.set(key, val).await?;
+			// Delete any expired changes for this table
+			let ts = match chrono::Duration::from_std(expiry) {
+				Ok(v) => Datetime::from(now.0 - v),
+				Err(_) => continue,
+			};
+			self.prune_changes(&ns, &db, &tb, &ts).await?;
 		}
+		Ok(())
+	}
+
+	/// Delete the change feed entries for a table which were written before a point in time.
+	async fn prune_changes(
+		&mut self,
+		ns: &str,
+		db: &str,
+		tb: &str,
+		ts: &Datetime,
+	) -> Result<(), Error> {
+		let beg = crate::key::cf::prefix(ns, db, tb);
+		let end = crate::key::cf::suffix(ns, db, tb);
+		loop {
+			// Get the oldest entries
+			let res = self.scan(beg.clone()..end.clone(), 1000).await?;
+			// Exit when settled
+			if res.is_empty() {
+				break;
+			}
+			// Loop over results
+			for (k, v) in res.into_iter() {
+				// Entries are ordered by versionstamp, and therefore by time
+				match Value::from(v) {
+					Value::Object(v) => match v.get("timestamp") {
+						Some(Value::Datetime(v)) if v < ts => self.del(k).await?,
+						_ => return Ok(()),
+					},
+					_ => return Ok(()),
+				}
+			}
+		}
+		Ok(())
 	}
 
 	/// Delete a key from the datastore.
@@ -202,7 +402,14 @@ impl Transaction {
 	{
 		#[cfg(debug_assertions)]
 		trace!(target: LOG, "Del {:?}", key);
-		match self {
+		// Record the operation
+		METRICS.operation(Operation::Del);
+		let key: Key = key.into();
+		// Invalidate any cached definitions
+		self.cache.write(&key);
+		// Prepare the write for the sync log
+		let log = self.writes.is_some().then(|| Frame::Del(key.clone()));
+		let res = match self {
 			#[cfg(feature = "kv-mem")]
 			Transaction {
 				inner: Inner::Mem(v),
@@ -230,7 +437,12 @@ impl Transaction {
 			} => v.del(key).await,
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
+		};
+		// Record the write in the sync log
+		if let (Ok(_), Some(frame)) = (&res, log) {
+			self.log(frame);
 		}
+		res
 	}
 
 	/// Check if a key exists in the datastore.
@@ -241,6 +453,8 @@ impl Transaction {
 	{
 		#[cfg(debug_assertions)]
 		trace!(target: LOG, "Exi {:?}", key);
+		// Record the operation
+		METRICS.operation(Operation::Exi);
 		match self {
 			#[cfg(feature = "kv-mem")]
 			Transaction {
@@ -280,6 +494,8 @@ impl Transaction {
 	{
 		#[cfg(debug_assertions)]
 		trace!(target: LOG, "Get {:?}", key);
+		// Record the operation
+		METRICS.operation(Operation::Get);
 		match self {
 			#[cfg(feature = "kv-mem")]
 			Transaction {
@@ -320,7 +536,15 @@ impl Transaction {
 	{
 		#[cfg(debug_assertions)]
 		trace!(target: LOG, "Set {:?} => {:?}", key, val);
-		match self {
+		// Record the operation
+		METRICS.operation(Operation::Set);
+		let key: Key = key.into();
+		// Invalidate any cached definitions
+		self.cache.write(&key);
+		let val: Val = val.into();
+		// Prepare the write for the sync log
+		let log = self.writes.is_some().then(|| Frame::Set(key.clone(), val.clone()));
+		let res = match self {
 			#[cfg(feature = "kv-mem")]
 			Transaction {
 				inner: Inner::Mem(v),
@@ -348,7 +572,12 @@ impl Transaction {
 			} => v.set(key, val).await,
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
+		};
+		// Record the write in the sync log
+		if let (Ok(_), Some(frame)) = (&res, log) {
+			self.log(frame);
 		}
+		res
 	}
 
 	/// Insert a key if it doesn't exist in the datastore.
@@ -360,7 +589,15 @@ impl Transaction {
 	{
 		#[cfg(debug_assertions)]
 		trace!(target: LOG, "Put {:?} => {:?}", key, val);
-		match self {
+		// Record the operation
+		METRICS.operation(Operation::Put);
+		let key: Key = key.into();
+		// Invalidate any cached definitions
+		self.cache.write(&key);
+		let val: Val = val.into();
+		// Prepare the write for the sync log
+		let log = self.writes.is_some().then(|| Frame::Set(key.clone(), val.clone()));
+		let res = match self {
 			#[cfg(feature = "kv-mem")]
 			Transaction {
 				inner: Inner::Mem(v),
@@ -388,7 +625,12 @@ impl Transaction {
 			} => v.put(key, val).await,
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
+		};
+		// Record the write in the sync log
+		if let (Ok(_), Some(frame)) = (&res, log) {
+			self.log(frame);
 		}
+		res
 	}
 
 	/// Retrieve a specific range of keys from the datastore.
@@ -401,6 +643,8 @@ impl Transaction {
 	{
 		#[cfg(debug_assertions)]
 		trace!(target: LOG, "Scan {:?} - {:?}", rng.start, rng.end);
+		// Record the operation
+		METRICS.operation(Operation::Scan);
 		match self {
 			#[cfg(feature = "kv-mem")]
 			Transaction {
@@ -441,7 +685,16 @@ impl Transaction {
 	{
 		#[cfg(debug_assertions)]
 		trace!(target: LOG, "Putc {:?} if {:?} => {:?}", key, chk, val);
-		match self {
+		// Record the operation
+		METRICS.operation(Operation::Putc);
+		let key: Key = key.into();
+		// Invalidate any cached definitions
+		self.cache.write(&key);
+		let val: Val = val.into();
+		let chk: Option<Val> = chk.map(Into::into);
+		// Prepare the write for the sync log
+		let log = self.writes.is_some().then(|| Frame::Set(key.clone(), val.clone()));
+		let res = match self {
 			#[cfg(feature = "kv-mem")]
 			Transaction {
 				inner: Inner::Mem(v),
@@ -469,7 +722,12 @@ impl Transaction {
 			} => v.putc(key, val, chk).await,
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
+		};
+		// Record the write in the sync log
+		if let (Ok(_), Some(frame)) = (&res, log) {
+			self.log(frame);
 		}
+		res
 	}
 
 	/// Delete a key from the datastore if the current value matches a condition.
@@ -481,7 +739,15 @@ impl Transaction {
 	{
 		#[cfg(debug_assertions)]
 		trace!(target: LOG, "Delc {:?} if {:?}", key, chk);
-		match self {
+		// Record the operation
+		METRICS.operation(Operation::Delc);
+		let key: Key = key.into();
+		// Invalidate any cached definitions
+		self.cache.write(&key);
+		let chk: Option<Val> = chk.map(Into::into);
+		// Prepare the write for the sync log
+		let log = self.writes.is_some().then(|| Frame::Del(key.clone()));
+		let res = match self {
 			#[cfg(feature = "kv-mem")]
 			Transaction {
 				inner: Inner::Mem(v),
@@ -509,7 +775,12 @@ impl Transaction {
 			} => v.delc(key, chk).await,
 			#[allow(unreachable_patterns)]
 			_ => unreachable!(),
+		};
+		// Record the write in the sync log
+		if let (Ok(_), Some(frame)) = (&res, log) {
+			self.log(frame);
 		}
+		res
 	}
 
 	// --------------------------------------------------
@@ -729,10 +1000,24 @@ impl Transaction {
 		Ok(())
 	}
 
+	/// Get a definition from the cache of this transaction, or from the
+	/// datastore-wide cache, once the version of the definitions is known.
+	async fn cached(&mut self, key: &Key) -> Result<Option<Entry>, Error> {
+		if let Some(v) = self.cache.get(key) {
+			return Ok(Some(v));
+		}
+		if self.cache.needs_version() {
+			let version = self.definitions_version().await?;
+			self.cache.set_version(version);
+			return Ok(self.cache.get(key));
+		}
+		Ok(None)
+	}
+
 	/// Retrieve all namespace definitions in a datastore.
 	pub async fn all_ns(&mut self) -> Result<Arc<[DefineNamespaceStatement]>, Error> {
 		let key = crate::key::ns::prefix();
-		Ok(if let Some(e) = self.cache.get(&key) {
+		Ok(if let Some(e) = self.cached(&key).await? {
 			if let Entry::Nss(v) = e {
 				v
 			} else {
@@ -748,10 +1033,29 @@ impl Transaction {
 		})
 	}
 
-	/// Retrieve all namespace login definitions for a specific namespace.
-	pub async fn all_nl(&mut self, ns: &str) -> Result<Arc<[DefineLoginStatement]>, Error> {
+	/// Retrieve all root user definitions.
+	pub async fn all_us(&mut self) -> Result<Arc<[DefineUserStatement]>, Error> {
+		let key = crate::key::us::prefix();
+		Ok(if let Some(e) = self.cached(&key).await? {
+			if let Entry::Uss(v) = e {
+				v
+			} else {
+				unreachable!();
+			}
+		} else {
+			let beg = crate::key::us::prefix();
+			let end = crate::key::us::suffix();
+			let val = self.getr(beg..end, u32::MAX).await?;
+			let val = val.convert().into();
+			self.cache.set(key, Entry::Uss(Arc::clone(&val)));
+			val
+		})
+	}
+
+	/// Retrieve all namespace user definitions for a specific namespace.
+	pub async fn all_nl(&mut self, ns: &str) -> Result<Arc<[DefineUserStatement]>, Error> {
 		let key = crate::key::nl::prefix(ns);
-		Ok(if let Some(e) = self.cache.get(&key) {
+		Ok(if let Some(e) = self.cached(&key).await? {
 			if let Entry::Nls(v) = e {
 				v
 			} else {
@@ -770,7 +1074,7 @@ impl Transaction {
 	/// Retrieve all namespace token definitions for a specific namespace.
 	pub async fn all_nt(&mut self, ns: &str) -> Result<Arc<[DefineTokenStatement]>, Error> {
 		let key = crate::key::nt::prefix(ns);
-		Ok(if let Some(e) = self.cache.get(&key) {
+		Ok(if let Some(e) = self.cached(&key).await? {
 			if let Entry::Nts(v) = e {
 				v
 			} else {
@@ -789,7 +1093,7 @@ impl Transaction {
 	/// Retrieve all database definitions for a specific namespace.
 	pub async fn all_db(&mut self, ns: &str) -> Result<Arc<[DefineDatabaseStatement]>, Error> {
 		let key = crate::key::db::prefix(ns);
-		Ok(if let Some(e) = self.cache.get(&key) {
+		Ok(if let Some(e) = self.cached(&key).await? {
 			if let Entry::Dbs(v) = e {
 				v
 			} else {
@@ -805,14 +1109,14 @@ impl Transaction {
 		})
 	}
 
-	/// Retrieve all database login definitions for a specific database.
+	/// Retrieve all database user definitions for a specific database.
 	pub async fn all_dl(
 		&mut self,
 		ns: &str,
 		db: &str,
-	) -> Result<Arc<[DefineLoginStatement]>, Error> {
+	) -> Result<Arc<[DefineUserStatement]>, Error> {
 		let key = crate::key::dl::prefix(ns, db);
-		Ok(if let Some(e) = self.cache.get(&key) {
+		Ok(if let Some(e) = self.cached(&key).await? {
 			if let Entry::Dls(v) = e {
 				v
 			} else {
@@ -835,7 +1139,7 @@ impl Transaction {
 		db: &str,
 	) -> Result<Arc<[DefineTokenStatement]>, Error> {
 		let key = crate::key::dt::prefix(ns, db);
-		Ok(if let Some(e) = self.cache.get(&key) {
+		Ok(if let Some(e) = self.cached(&key).await? {
 			if let Entry::Dts(v) = e {
 				v
 			} else {
@@ -858,7 +1162,7 @@ impl Transaction {
 		db: &str,
 	) -> Result<Arc<[DefineFunctionStatement]>, Error> {
 		let key = crate::key::fc::prefix(ns, db);
-		Ok(if let Some(e) = self.cache.get(&key) {
+		Ok(if let Some(e) = self.cached(&key).await? {
 			if let Entry::Fcs(v) = e {
 				v
 			} else {
@@ -881,7 +1185,7 @@ impl Transaction {
 		db: &str,
 	) -> Result<Arc<[DefineScopeStatement]>, Error> {
 		let key = crate::key::sc::prefix(ns, db);
-		Ok(if let Some(e) = self.cache.get(&key) {
+		Ok(if let Some(e) = self.cached(&key).await? {
 			if let Entry::Scs(v) = e {
 				v
 			} else {
@@ -905,7 +1209,7 @@ impl Transaction {
 		sc: &str,
 	) -> Result<Arc<[DefineTokenStatement]>, Error> {
 		let key = crate::key::st::prefix(ns, db, sc);
-		Ok(if let Some(e) = self.cache.get(&key) {
+		Ok(if let Some(e) = self.cached(&key).await? {
 			if let Entry::Sts(v) = e {
 				v
 			} else {
@@ -928,7 +1232,7 @@ impl Transaction {
 		db: &str,
 	) -> Result<Arc<[DefineParamStatement]>, Error> {
 		let key = crate::key::pa::prefix(ns, db);
-		Ok(if let Some(e) = self.cache.get(&key) {
+		Ok(if let Some(e) = self.cached(&key).await? {
 			if let Entry::Pas(v) = e {
 				v
 			} else {
@@ -944,6 +1248,29 @@ impl Transaction {
 		})
 	}
 
+	/// Retrieve all analyzer definitions for a specific database.
+	pub async fn all_az(
+		&mut self,
+		ns: &str,
+		db: &str,
+	) -> Result<Arc<[DefineAnalyzerStatement]>, Error> {
+		let key = crate::key::az::prefix(ns, db);
+		Ok(if let Some(e) = self.cached(&key).await? {
+			if let Entry::Azs(v) = e {
+				v
+			} else {
+				unreachable!();
+			}
+		} else {
+			let beg = crate::key::az::prefix(ns, db);
+			let end = crate::key::az::suffix(ns, db);
+			let val = self.getr(beg..end, u32::MAX).await?;
+			let val = val.convert().into();
+			self.cache.set(key, Entry::Azs(Arc::clone(&val)));
+			val
+		})
+	}
+
 	/// Retrieve all table definitions for a specific database.
 	pub async fn all_tb(
 		&mut self,
@@ -951,7 +1278,7 @@ impl Transaction {
 		db: &str,
 	) -> Result<Arc<[DefineTableStatement]>, Error> {
 		let key = crate::key::tb::prefix(ns, db);
-		Ok(if let Some(e) = self.cache.get(&key) {
+		Ok(if let Some(e) = self.cached(&key).await? {
 			if let Entry::Tbs(v) = e {
 				v
 			} else {
@@ -975,7 +1302,7 @@ impl Transaction {
 		tb: &str,
 	) -> Result<Arc<[DefineEventStatement]>, Error> {
 		let key = crate::key::ev::prefix(ns, db, tb);
-		Ok(if let Some(e) = self.cache.get(&key) {
+		Ok(if let Some(e) = self.cached(&key).await? {
 			if let Entry::Evs(v) = e {
 				v
 			} else {
@@ -999,7 +1326,7 @@ impl Transaction {
 		tb: &str,
 	) -> Result<Arc<[DefineFieldStatement]>, Error> {
 		let key = crate::key::fd::prefix(ns, db, tb);
-		Ok(if let Some(e) = self.cache.get(&key) {
+		Ok(if let Some(e) = self.cached(&key).await? {
 			if let Entry::Fds(v) = e {
 				v
 			} else {
@@ -1023,7 +1350,7 @@ impl Transaction {
 		tb: &str,
 	) -> Result<Arc<[DefineIndexStatement]>, Error> {
 		let key = crate::key::ix::prefix(ns, db, tb);
-		Ok(if let Some(e) = self.cache.get(&key) {
+		Ok(if let Some(e) = self.cached(&key).await? {
 			if let Entry::Ixs(v) = e {
 				v
 			} else {
@@ -1047,7 +1374,7 @@ impl Transaction {
 		tb: &str,
 	) -> Result<Arc<[DefineTableStatement]>, Error> {
 		let key = crate::key::ft::prefix(ns, db, tb);
-		Ok(if let Some(e) = self.cache.get(&key) {
+		Ok(if let Some(e) = self.cached(&key).await? {
 			if let Entry::Fts(v) = e {
 				v
 			} else {
@@ -1071,7 +1398,7 @@ impl Transaction {
 		tb: &str,
 	) -> Result<Arc<[LiveStatement]>, Error> {
 		let key = crate::key::lv::prefix(ns, db, tb);
-		Ok(if let Some(e) = self.cache.get(&key) {
+		Ok(if let Some(e) = self.cached(&key).await? {
 			if let Entry::Lvs(v) = e {
 				v
 			} else {
@@ -1096,8 +1423,17 @@ impl Transaction {
 		Ok(val.into())
 	}
 
-	/// Retrieve a specific namespace login definition.
-	pub async fn get_nl(&mut self, ns: &str, nl: &str) -> Result<DefineLoginStatement, Error> {
+	/// Retrieve a specific root user definition.
+	pub async fn get_us(&mut self, us: &str) -> Result<DefineUserStatement, Error> {
+		let key = crate::key::us::new(us);
+		let val = self.get(key).await?.ok_or(Error::UsNotFound {
+			value: us.to_owned(),
+		})?;
+		Ok(val.into())
+	}
+
+	/// Retrieve a specific namespace user definition.
+	pub async fn get_nl(&mut self, ns: &str, nl: &str) -> Result<DefineUserStatement, Error> {
 		let key = crate::key::nl::new(ns, nl);
 		let val = self.get(key).await?.ok_or(Error::NlNotFound {
 			value: nl.to_owned(),
@@ -1123,13 +1459,13 @@ impl Transaction {
 		Ok(val.into())
 	}
 
-	/// Retrieve a specific database login definition.
+	/// Retrieve a specific database user definition.
 	pub async fn get_dl(
 		&mut self,
 		ns: &str,
 		db: &str,
 		dl: &str,
-	) -> Result<DefineLoginStatement, Error> {
+	) -> Result<DefineUserStatement, Error> {
 		let key = crate::key::dl::new(ns, db, dl);
 		let val = self.get(key).await?.ok_or(Error::DlNotFound {
 			value: dl.to_owned(),
@@ -1208,6 +1544,20 @@ impl Transaction {
 		Ok(val.into())
 	}
 
+	/// Retrieve a specific analyzer definition.
+	pub async fn get_az(
+		&mut self,
+		ns: &str,
+		db: &str,
+		az: &str,
+	) -> Result<DefineAnalyzerStatement, Error> {
+		let key = crate::key::az::new(ns, db, az);
+		let val = self.get(key).await?.ok_or(Error::AzNotFound {
+			value: az.to_owned(),
+		})?;
+		Ok(val.into())
+	}
+
 	/// Retrieve a specific table definition.
 	pub async fn get_tb(
 		&mut self,
@@ -1264,6 +1614,7 @@ impl Transaction {
 					let key = crate::key::db::new(ns, db);
 					let val = DefineDatabaseStatement {
 						name: db.to_owned().into(),
+						..DefineDatabaseStatement::default()
 					};
 					self.put(key, &val).await?;
 					Ok(val)
@@ -1344,7 +1695,7 @@ impl Transaction {
 		ns: &str,
 	) -> Result<Arc<DefineNamespaceStatement>, Error> {
 		let key = crate::key::ns::new(ns).encode()?;
-		Ok(if let Some(e) = self.cache.get(&key) {
+		Ok(if let Some(e) = self.cached(&key).await? {
 			if let Entry::Ns(v) = e {
 				v
 			} else {
@@ -1367,7 +1718,7 @@ impl Transaction {
 		db: &str,
 	) -> Result<Arc<DefineDatabaseStatement>, Error> {
 		let key = crate::key::db::new(ns, db).encode()?;
-		Ok(if let Some(e) = self.cache.get(&key) {
+		Ok(if let Some(e) = self.cached(&key).await? {
 			if let Entry::Db(v) = e {
 				v
 			} else {
@@ -1391,7 +1742,7 @@ impl Transaction {
 		tb: &str,
 	) -> Result<Arc<DefineTableStatement>, Error> {
 		let key = crate::key::tb::new(ns, db, tb).encode()?;
-		Ok(if let Some(e) = self.cache.get(&key) {
+		Ok(if let Some(e) = self.cached(&key).await? {
 			if let Entry::Tb(v) = e {
 				v
 			} else {
@@ -1449,6 +1800,7 @@ impl Transaction {
 					let key = crate::key::db::new(ns, db);
 					let val = DefineDatabaseStatement {
 						name: db.to_owned().into(),
+						..DefineDatabaseStatement::default()
 					};
 					self.put(key, &val).await?;
 					Ok(Arc::new(val))
@@ -1514,6 +1866,108 @@ impl Transaction {
 		}
 	}
 
+	/// Retrieve a record as it was at a specific point in time.
+	///
+	/// This returns the latest version of the record which was written at or before
+	/// the specified timestamp, or [`None`] if the record did not exist at that time.
+	pub async fn get_version(
+		&mut self,
+		ns: &str,
+		db: &str,
+		tb: &str,
+		id: &Id,
+		ts: u64,
+	) -> Result<Option<Val>, Error> {
+		let beg = crate::key::version::new(ns, db, tb, id, 0);
+		let end = crate::key::version::new(ns, db, tb, id, ts.saturating_add(1));
+		let res = self.getr(beg..end, u32::MAX).await?;
+		// Deleted records are stored as an empty value
+		Ok(res.into_iter().last().map(|(_, v)| v).filter(|v| !v.is_empty()))
+	}
+
+	/// Retrieve a batch of records in a range of versions, as they were at a specific point in time.
+	///
+	/// This function fetches up to `limit` versions from the underlying datastore, and returns
+	/// the records found along with the key to continue the scan from, if there may be more.
+	pub async fn scan_version(
+		&mut self,
+		rng: Range<Key>,
+		ts: u64,
+		limit: u32,
+	) -> Result<(Vec<(Thing, Val)>, Option<Key>), Error> {
+		let end = rng.end.clone();
+		let res = self.scan(rng, limit).await?;
+		let mut out: Vec<(Thing, Option<Val>)> = vec![];
+		// Versions are ordered by record id, and then by time
+		for (k, v) in res.iter() {
+			let key: crate::key::version::Version = k.into();
+			let rid = Thing::from((key.tb, key.id));
+			// Ignore versions written after the timestamp
+			let val = match key.ts <= ts {
+				true => Some(v.to_owned()),
+				false => None,
+			};
+			match out.last_mut() {
+				Some((id, cur)) if *id == rid => {
+					if val.is_some() {
+						*cur = val;
+					}
+				}
+				_ => out.push((rid, val)),
+			}
+		}
+		// The versions of the last record may continue past this batch
+		let mut nxt = None;
+		if res.len() == limit as usize {
+			if let Some((k, _)) = res.last() {
+				let key: crate::key::version::Version = k.into();
+				// Fetch all of the versions of the last record
+				let val = self.get_version(key.ns, key.db, key.tb, &key.id, ts).await?;
+				out.pop();
+				out.push((Thing::from((key.tb, key.id.clone())), val));
+				// Continue the scan from the next record
+				let beg = crate::key::version::suffix_id(key.ns, key.db, key.tb, &key.id);
+				if beg < end {
+					nxt = Some(beg);
+				}
+			}
+		}
+		// Deleted records are stored as an empty value
+		let out = out
+			.into_iter()
+			.filter_map(|(rid, v)| v.filter(|v| !v.is_empty()).map(|v| (rid, v)))
+			.collect();
+		Ok((out, nxt))
+	}
+
+	/// Delete the versions of a record which were written before a specific point in time.
+	///
+	/// The latest of these versions is kept, so that the record can still be read as it
+	/// was at any point in time after the specified timestamp.
+	pub async fn prune_versions(
+		&mut self,
+		ns: &str,
+		db: &str,
+		tb: &str,
+		id: &Id,
+		ts: u64,
+	) -> Result<(), Error> {
+		let beg = crate::key::version::new(ns, db, tb, id, 0);
+		let end = crate::key::version::new(ns, db, tb, id, ts);
+		let mut res = self.getr(beg..end, u32::MAX).await?;
+		// Keep the latest version, unless the record was deleted
+		if let Some((_, v)) = res.last() {
+			if !v.is_empty() {
+				res.pop();
+			}
+		}
+		// Delete the older versions
+		for (k, _) in res {
+			self.del(k).await?;
+		}
+		Ok(())
+	}
+
 	// --------------------------------------------------
 	// Additional methods
 	// --------------------------------------------------
@@ -1543,12 +1997,12 @@ impl Transaction {
 				chn.send(bytes!("")).await?;
 			}
 		}
-		// Output LOGINS
+		// Output USERS
 		{
 			let dls = self.all_dl(ns, db).await?;
 			if !dls.is_empty() {
 				chn.send(bytes!("-- ------------------------------")).await?;
-				chn.send(bytes!("-- LOGINS")).await?;
+				chn.send(bytes!("-- USERS")).await?;
 				chn.send(bytes!("-- ------------------------------")).await?;
 				chn.send(bytes!("")).await?;
 				for dl in dls.iter() {
@@ -1571,6 +2025,20 @@ impl Transaction {
 				chn.send(bytes!("")).await?;
 			}
 		}
+		// Output ANALYZERS
+		{
+			let azs = self.all_az(ns, db).await?;
+			if !azs.is_empty() {
+				chn.send(bytes!("-- ------------------------------")).await?;
+				chn.send(bytes!("-- ANALYZERS")).await?;
+				chn.send(bytes!("-- ------------------------------")).await?;
+				chn.send(bytes!("")).await?;
+				for az in azs.iter() {
+					chn.send(bytes!(format!("{az};"))).await?;
+				}
+				chn.send(bytes!("")).await?;
+			}
+		}
 		// Output PARAMS
 		{
 			let pas = self.all_pa(ns, db).await?;
@@ -1730,4 +2198,149 @@ impl Transaction {
 		// Everything exported
 		Ok(())
 	}
+
+	/// Writes the entire datastore contents as binary sync data.
+	pub async fn sync(&mut self, chn: Sender<Vec<u8>>) -> Result<(), Error> {
+		// Output the current sync log position
+		let mut buf = super::sync::header();
+		Frame::Seq(self.sync_position().await?).encode(&mut buf);
+		// Replace any existing data when loaded
+		Frame::Clear.encode(&mut buf);
+		chn.send(buf).await?;
+		// Output all keys in batches
+		let end: Key = vec![0xff];
+		let mut nxt: Key = vec![0x00];
+		loop {
+			let res = self.scan(nxt.clone()..end.clone(), 1000).await?;
+			// Ready the next batch
+			match res.last() {
+				Some((k, _)) => nxt = k.clone().add(0x00),
+				None => break,
+			}
+			// Output the batch, skipping the sync log
+			let mut buf = Vec::new();
+			for (k, v) in res.into_iter() {
+				if !super::sync::is_log_key(&k) {
+					Frame::Set(k, v).encode(&mut buf);
+				}
+			}
+			if !buf.is_empty() {
+				chn.send(buf).await?;
+			}
+		}
+		// Mark the stream as complete
+		let mut buf = Vec::new();
+		Frame::End.encode(&mut buf);
+		chn.send(buf).await?;
+		// Everything synced
+		Ok(())
+	}
+
+	/// Writes the changes committed after a sync log position as binary sync data.
+	pub async fn sync_since(&mut self, since: u64, chn: Sender<Vec<u8>>) -> Result<(), Error> {
+		// Output the current sync log position
+		let mut buf = super::sync::header();
+		Frame::Seq(self.sync_position().await?).encode(&mut buf);
+		chn.send(buf).await?;
+		// Output the sync log entries in batches
+		let end = crate::key::sy::suffix();
+		let mut nxt = crate::key::sy::new(since.saturating_add(1)).encode()?;
+		loop {
+			let res = self.scan(nxt.clone()..end.clone(), 1000).await?;
+			// Ready the next batch
+			match res.last() {
+				Some((k, _)) => nxt = k.clone().add(0x00),
+				None => break,
+			}
+			// Each entry is already encoded
+			for (_, v) in res.into_iter() {
+				chn.send(v).await?;
+			}
+		}
+		// Mark the stream as complete
+		let mut buf = Vec::new();
+		Frame::End.encode(&mut buf);
+		chn.send(buf).await?;
+		// Everything synced
+		Ok(())
+	}
+
+	/// Applies parsed sync data, returning the sync log position of the source.
+	pub(super) async fn load(&mut self, frames: Vec<Frame>) -> Result<Option<u64>, Error> {
+		let mut seq = None;
+		for frame in frames {
+			match frame {
+				Frame::End => break,
+				Frame::Seq(v) => seq = Some(v),
+				Frame::Clear => self.clear().await?,
+				Frame::Set(k, v) => self.set(k, v).await?,
+				Frame::Del(k) => self.del(k).await?,
+			}
+		}
+		Ok(seq)
+	}
+
+	/// Retrieves the version of the definitions in this transaction.
+	async fn definitions_version(&mut self) -> Result<u64, Error> {
+		Ok(match self.get(crate::key::dv::new()).await? {
+			Some(v) => u64::from_be_bytes(v.try_into().unwrap_or_default()),
+			None => 0,
+		})
+	}
+
+	/// Increment the version of the definitions, if any were changed.
+	///
+	/// This invalidates the datastore-wide definition cache for every
+	/// transaction which starts after this transaction is committed.
+	async fn write_definitions_version(&mut self) -> Result<(), Error> {
+		if self.cache.changed() {
+			let key = crate::key::dv::new();
+			let mut retries = 0;
+			loop {
+				// Fetch the current version of the definitions
+				let cur = self.get(key.clone()).await?;
+				let dv = match cur.clone() {
+					Some(v) => u64::from_be_bytes(v.try_into().unwrap_or_default()) + 1,
+					None => 1,
+				};
+				// Only increment the version if it was not changed concurrently
+				match self.putc(key.clone(), dv.to_be_bytes().to_vec(), cur).await {
+					Err(Error::TxConditionNotMet) if retries < DEFINITIONS_VERSION_RETRIES => {
+						retries += 1
+					}
+					res => return res,
+				}
+			}
+		}
+		Ok(())
+	}
+
+	/// Retrieves the latest position in the sync log.
+	async fn sync_position(&mut self) -> Result<u64, Error> {
+		Ok(match self.get(crate::key::sq::new()).await? {
+			Some(v) => u64::from_be_bytes(v.try_into().unwrap_or_default()),
+			None => 0,
+		})
+	}
+
+	/// Deletes every key in the datastore, except for the sync log.
+	async fn clear(&mut self) -> Result<(), Error> {
+		let end: Key = vec![0xff];
+		let mut nxt: Key = vec![0x00];
+		loop {
+			let res = self.scan(nxt.clone()..end.clone(), 1000).await?;
+			// Ready the next batch
+			match res.last() {
+				Some((k, _)) => nxt = k.clone().add(0x00),
+				None => break,
+			}
+			// Delete the batch, skipping the sync log
+			for (k, _) in res.into_iter() {
+				if !super::sync::is_log_key(&k) {
+					self.del(k).await?;
+				}
+			}
+		}
+		Ok(())
+	}
 }
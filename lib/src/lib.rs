@@ -112,6 +112,7 @@ mod ctx;
 mod doc;
 mod exe;
 mod fnc;
+mod idx;
 mod key;
 
 pub mod sql;
@@ -123,6 +124,8 @@ pub mod env;
 #[doc(hidden)]
 pub mod err;
 #[doc(hidden)]
+pub mod iam;
+#[doc(hidden)]
 pub mod kvs;
 
 #[doc(inline)]
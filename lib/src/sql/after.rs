@@ -0,0 +1,74 @@
+use crate::ctx::Context;
+use crate::dbs::Options;
+use crate::dbs::Transaction;
+use crate::err::Error;
+use crate::sql::comment::shouldbespace;
+use crate::sql::error::IResult;
+use crate::sql::thing::Thing;
+use crate::sql::value::{value, Value};
+use nom::bytes::complete::tag_no_case;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A cursor which resumes a table or range scan after the specified record
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+pub struct After(pub Value);
+
+impl After {
+	pub(crate) async fn process(
+		&self,
+		ctx: &Context<'_>,
+		opt: &Options,
+		txn: &Transaction,
+		doc: Option<&Value>,
+	) -> Result<Thing, Error> {
+		match self.0.compute(ctx, opt, txn, doc).await {
+			// This is a valid record cursor
+			Ok(Value::Thing(v)) => Ok(v),
+			// An invalid value was specified
+			Ok(v) => Err(Error::InvalidAfter {
+				value: v.as_string(),
+			}),
+			// A different error occured
+			Err(e) => Err(e),
+		}
+	}
+}
+
+impl fmt::Display for After {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "AFTER {}", self.0)
+	}
+}
+
+pub fn after(i: &str) -> IResult<&str, After> {
+	let (i, _) = tag_no_case("AFTER")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, v) = value(i)?;
+	Ok((i, After(v)))
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn after_statement() {
+		let sql = "AFTER person:tobie";
+		let res = after(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(out, After(Value::from(Thing::from(("person", "tobie")))));
+		assert_eq!("AFTER person:tobie", format!("{}", out));
+	}
+
+	#[test]
+	fn after_statement_param() {
+		let sql = "AFTER $cursor";
+		let res = after(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!("AFTER $cursor", format!("{}", out));
+	}
+}
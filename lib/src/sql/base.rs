@@ -27,7 +27,7 @@ impl fmt::Display for Base {
 			Self::Ns => f.write_str("NAMESPACE"),
 			Self::Db => f.write_str("DATABASE"),
 			Self::Sc(sc) => write!(f, "SCOPE {sc}"),
-			Self::Kv => f.write_str("KV"),
+			Self::Kv => f.write_str("ROOT"),
 		}
 	}
 }
@@ -41,6 +41,10 @@ pub fn base(i: &str) -> IResult<&str, Base> {
 	))(i)
 }
 
+pub fn base_or_root(i: &str) -> IResult<&str, Base> {
+	alt((map(tag_no_case("ROOT"), |_| Base::Kv), base))(i)
+}
+
 pub fn base_or_scope(i: &str) -> IResult<&str, Base> {
 	alt((
 		map(tag_no_case("NAMESPACE"), |_| Base::Ns),
@@ -9,12 +9,16 @@ use crate::sql::error::IResult;
 use crate::sql::fmt::{is_pretty, pretty_indent, Fmt, Pretty};
 use crate::sql::statements::create::{create, CreateStatement};
 use crate::sql::statements::delete::{delete, DeleteStatement};
+use crate::sql::statements::foreach::{foreach, ForeachStatement};
 use crate::sql::statements::ifelse::{ifelse, IfelseStatement};
 use crate::sql::statements::insert::{insert, InsertStatement};
 use crate::sql::statements::output::{output, OutputStatement};
+use crate::sql::statements::r#break::{r#break, BreakStatement};
+use crate::sql::statements::r#continue::{r#continue, ContinueStatement};
 use crate::sql::statements::relate::{relate, RelateStatement};
 use crate::sql::statements::select::{select, SelectStatement};
 use crate::sql::statements::set::{set, SetStatement};
+use crate::sql::statements::throw::{throw, ThrowStatement};
 use crate::sql::statements::update::{update, UpdateStatement};
 use crate::sql::value::{value, Value};
 use nom::branch::alt;
@@ -100,6 +104,18 @@ impl Block {
 				Entry::Insert(v) => {
 					v.compute(&ctx, opt, txn, doc).await?;
 				}
+				Entry::Foreach(v) => {
+					v.compute(&ctx, opt, txn, doc).await?;
+				}
+				Entry::Break(v) => {
+					v.compute(&ctx, opt, txn, doc).await?;
+				}
+				Entry::Continue(v) => {
+					v.compute(&ctx, opt, txn, doc).await?;
+				}
+				Entry::Throw(v) => {
+					v.compute(&ctx, opt, txn, doc).await?;
+				}
 				Entry::Output(v) => {
 					return v.compute(&ctx, opt, txn, doc).await;
 				}
@@ -179,6 +195,10 @@ pub enum Entry {
 	Relate(RelateStatement),
 	Insert(InsertStatement),
 	Output(OutputStatement),
+	Foreach(ForeachStatement),
+	Break(BreakStatement),
+	Continue(ContinueStatement),
+	Throw(ThrowStatement),
 }
 
 impl PartialOrd for Entry {
@@ -201,6 +221,10 @@ impl Entry {
 			Self::Relate(v) => v.writeable(),
 			Self::Insert(v) => v.writeable(),
 			Self::Output(v) => v.writeable(),
+			Self::Foreach(v) => v.writeable(),
+			Self::Break(_) => false,
+			Self::Continue(_) => false,
+			Self::Throw(v) => v.writeable(),
 		}
 	}
 }
@@ -218,6 +242,10 @@ impl Display for Entry {
 			Self::Relate(v) => write!(f, "{v}"),
 			Self::Insert(v) => write!(f, "{v}"),
 			Self::Output(v) => write!(f, "{v}"),
+			Self::Foreach(v) => write!(f, "{v}"),
+			Self::Break(v) => write!(f, "{v}"),
+			Self::Continue(v) => write!(f, "{v}"),
+			Self::Throw(v) => write!(f, "{v}"),
 		}
 	}
 }
@@ -235,6 +263,10 @@ pub fn entry(i: &str) -> IResult<&str, Entry> {
 			map(relate, Entry::Relate),
 			map(delete, Entry::Delete),
 			map(insert, Entry::Insert),
+			map(foreach, Entry::Foreach),
+			map(r#break, Entry::Break),
+			map(r#continue, Entry::Continue),
+			map(throw, Entry::Throw),
 			map(value, Entry::Value),
 		)),
 		mightbespace,
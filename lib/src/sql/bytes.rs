@@ -12,3 +12,15 @@ impl Serialize for Bytes {
 		serializer.serialize_bytes(&self.0)
 	}
 }
+
+impl From<Vec<u8>> for Bytes {
+	fn from(v: Vec<u8>) -> Self {
+		Self(v)
+	}
+}
+
+impl Bytes {
+	pub fn into_inner(self) -> Vec<u8> {
+		self.0
+	}
+}
@@ -0,0 +1,45 @@
+use crate::sql::comment::shouldbespace;
+use crate::sql::duration::{duration, Duration};
+use crate::sql::error::IResult;
+use nom::bytes::complete::tag_no_case;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+pub struct ChangeFeed {
+	pub expiry: Duration,
+}
+
+impl fmt::Display for ChangeFeed {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "CHANGEFEED {}", self.expiry)
+	}
+}
+
+pub fn changefeed(i: &str) -> IResult<&str, ChangeFeed> {
+	let (i, _) = tag_no_case("CHANGEFEED")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, v) = duration(i)?;
+	Ok((
+		i,
+		ChangeFeed {
+			expiry: v,
+		},
+	))
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn changefeed_clause() {
+		let sql = "CHANGEFEED 1h";
+		let res = changefeed(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!("CHANGEFEED 1h", format!("{}", out));
+		assert_eq!(out.expiry, Duration::from_hours(1));
+	}
+}
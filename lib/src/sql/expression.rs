@@ -3,10 +3,15 @@ use crate::dbs::Options;
 use crate::dbs::Transaction;
 use crate::err::Error;
 use crate::fnc;
+use crate::idx::ft::analyzer::Analyzer;
+use crate::idx::ft::FtIndex;
 use crate::sql::error::IResult;
+use crate::sql::index::Index;
 use crate::sql::operator::{operator, Operator};
+use crate::sql::paths::ID;
 use crate::sql::value::{single, value, Value};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::fmt;
 use std::str;
 
@@ -127,9 +132,78 @@ impl Expression {
 			Operator::NoneInside => fnc::operate::inside_none(&l, &r),
 			Operator::Outside => fnc::operate::outside(&l, &r),
 			Operator::Intersects => fnc::operate::intersects(&l, &r),
+			Operator::Matches(mr) => {
+				self.matches(ctx, opt, txn, doc, mr.unwrap_or(0), &l, &r).await
+			}
+			Operator::Knn(_) => self.knn(ctx, doc),
 			_ => unreachable!(),
 		}
 	}
+
+	/// Checks if the record is one of the nearest neighbours found by the query planner
+	fn knn(&self, ctx: &Context<'_>, doc: Option<&Value>) -> Result<Value, Error> {
+		if let (Some(exe), Some(Value::Thing(rid)), Value::Idiom(idiom)) =
+			(ctx.get_query_executor(), doc.map(|v| v.pick(&*ID)), &self.l)
+		{
+			if let Some(v) = exe.knn(&rid, idiom) {
+				return Ok(v.into());
+			}
+		}
+		Err(Error::KnnUnsupported {
+			value: self.l.to_string(),
+		})
+	}
+
+	/// Checks if the text on the left matches the full-text search on the right
+	#[allow(clippy::too_many_arguments)]
+	async fn matches(
+		&self,
+		ctx: &Context<'_>,
+		opt: &Options,
+		txn: &Transaction,
+		doc: Option<&Value>,
+		mr: u8,
+		l: &Value,
+		r: &Value,
+	) -> Result<Value, Error> {
+		// Get the id of the current record
+		let rid = match doc.map(|v| v.pick(&*ID)) {
+			Some(Value::Thing(v)) => Some(v),
+			_ => None,
+		};
+		// Use the results of the index search
+		if let (Some(exe), Some(rid)) = (ctx.get_query_executor(), &rid) {
+			if let Some(v) = exe.matches(rid, mr) {
+				return Ok(v.into());
+			}
+		}
+		// Otherwise use the analyzer of the search index on this field
+		let mut az = Analyzer::default();
+		if let (Some(rid), Value::Idiom(idiom)) = (&rid, &self.l) {
+			let mut run = txn.lock().await;
+			let ixs = run.all_ix(opt.ns(), opt.db(), &rid.tb).await?;
+			for ix in ixs.iter() {
+				if let Index::Search {
+					az: name,
+					..
+				} = &ix.index
+				{
+					if ix.cols.len() == 1 && &ix.cols[0] == idiom {
+						az = Analyzer::from(&run.get_az(opt.ns(), opt.db(), name).await?);
+						break;
+					}
+				}
+			}
+		}
+		// Check that the text contains every term of the query
+		let text = match FtIndex::text(l) {
+			Some(v) => v,
+			None => return Ok(Value::Bool(false)),
+		};
+		let terms: HashSet<String> = az.analyze(&text).into_iter().map(|t| t.text).collect();
+		let query = az.analyze(&r.to_raw_string());
+		Ok((!query.is_empty() && query.iter().all(|t| terms.contains(&t.text))).into())
+	}
 }
 
 impl fmt::Display for Expression {
@@ -181,6 +255,24 @@ mod tests {
 		assert_eq!("(3 * 3 * 3) = 27", format!("{}", out));
 	}
 
+	#[test]
+	fn expression_matches() {
+		let sql = "title @@ 'hello' AND body @1@ 'world'";
+		let res = expression(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!("title @@ 'hello' AND body @1@ 'world'", format!("{}", out));
+	}
+
+	#[test]
+	fn expression_knn() {
+		let sql = "point <|10|> [1, 2, 3] AND age < 30";
+		let res = expression(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!("point <|10|> [1, 2, 3] AND age < 30", format!("{}", out));
+	}
+
 	#[test]
 	fn expression_right_opened() {
 		let sql = "27 = 3 * 3 * 3";
@@ -0,0 +1,113 @@
+use crate::sql::comment::mightbespace;
+use crate::sql::common::commas;
+use crate::sql::error::IResult;
+use nom::branch::alt;
+use nom::bytes::complete::tag_no_case;
+use nom::character::complete::char;
+use nom::character::complete::u16;
+use nom::combinator::map;
+use nom::multi::separated_list1;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash)]
+pub enum Language {
+	English,
+}
+
+impl fmt::Display for Language {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(match self {
+			Self::English => "ENGLISH",
+		})
+	}
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash)]
+pub enum Filter {
+	Ascii,
+	EdgeNgram(u16, u16),
+	Lowercase,
+	Snowball(Language),
+	Uppercase,
+}
+
+impl fmt::Display for Filter {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Ascii => f.write_str("ASCII"),
+			Self::EdgeNgram(min, max) => write!(f, "EDGENGRAM({min},{max})"),
+			Self::Lowercase => f.write_str("LOWERCASE"),
+			Self::Snowball(lang) => write!(f, "SNOWBALL({lang})"),
+			Self::Uppercase => f.write_str("UPPERCASE"),
+		}
+	}
+}
+
+fn language(i: &str) -> IResult<&str, Language> {
+	alt((
+		map(tag_no_case("ENGLISH"), |_| Language::English),
+		map(tag_no_case("ENG"), |_| Language::English),
+	))(i)
+}
+
+fn edgengram(i: &str) -> IResult<&str, Filter> {
+	let (i, _) = tag_no_case("EDGENGRAM")(i)?;
+	let (i, _) = char('(')(i)?;
+	let (i, _) = mightbespace(i)?;
+	let (i, min) = u16(i)?;
+	let (i, _) = commas(i)?;
+	let (i, max) = u16(i)?;
+	let (i, _) = mightbespace(i)?;
+	let (i, _) = char(')')(i)?;
+	Ok((i, Filter::EdgeNgram(min, max)))
+}
+
+fn snowball(i: &str) -> IResult<&str, Filter> {
+	let (i, _) = tag_no_case("SNOWBALL")(i)?;
+	let (i, _) = char('(')(i)?;
+	let (i, _) = mightbespace(i)?;
+	let (i, lang) = language(i)?;
+	let (i, _) = mightbespace(i)?;
+	let (i, _) = char(')')(i)?;
+	Ok((i, Filter::Snowball(lang)))
+}
+
+fn filter(i: &str) -> IResult<&str, Filter> {
+	alt((
+		map(tag_no_case("ASCII"), |_| Filter::Ascii),
+		edgengram,
+		map(tag_no_case("LOWERCASE"), |_| Filter::Lowercase),
+		snowball,
+		map(tag_no_case("UPPERCASE"), |_| Filter::Uppercase),
+	))(i)
+}
+
+pub fn filters(i: &str) -> IResult<&str, Vec<Filter>> {
+	separated_list1(commas, filter)(i)
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn filters_list() {
+		let sql = "lowercase,ascii, EDGENGRAM(2,10), snowball(english)";
+		let res = filters(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(
+			out,
+			vec![
+				Filter::Lowercase,
+				Filter::Ascii,
+				Filter::EdgeNgram(2, 10),
+				Filter::Snowball(Language::English)
+			]
+		);
+		assert_eq!("EDGENGRAM(2,10)", format!("{}", out[2]));
+		assert_eq!("SNOWBALL(ENGLISH)", format!("{}", out[3]));
+	}
+}
@@ -93,14 +93,15 @@ impl Function {
 	pub fn is_custom(&self) -> bool {
 		matches!(self, Self::Custom(_, _))
 	}
-	/// Check if this function is a rolling function
+	/// Check if this function is a rolling function,
+	/// which can be maintained using running totals
 	pub fn is_rolling(&self) -> bool {
 		match self {
 			Self::Normal(f, _) if f == "count" => true,
-			Self::Normal(f, _) if f == "math::max" => true,
 			Self::Normal(f, _) if f == "math::mean" => true,
-			Self::Normal(f, _) if f == "math::min" => true,
+			Self::Normal(f, _) if f == "math::stddev" => true,
 			Self::Normal(f, _) if f == "math::sum" => true,
+			Self::Normal(f, _) if f == "math::variance" => true,
 			_ => false,
 		}
 	}
@@ -127,6 +128,8 @@ impl Function {
 			Self::Normal(f, _) if f == "math::top" => true,
 			Self::Normal(f, _) if f == "math::trimean" => true,
 			Self::Normal(f, _) if f == "math::variance" => true,
+			Self::Normal(f, _) if f == "time::max" => true,
+			Self::Normal(f, _) if f == "time::min" => true,
 			_ => false,
 		}
 	}
@@ -158,7 +161,7 @@ impl Function {
 				// Compute the function arguments
 				let a = try_join_all(x.iter().map(|v| v.compute(ctx, opt, txn, doc))).await?;
 				// Run the normal function
-				fnc::run(ctx, s, a).await
+				fnc::run(ctx, doc, s, a).await
 			}
 			Self::Custom(s, x) => {
 				// Get the function definition
@@ -280,10 +283,12 @@ fn function_names(i: &str) -> IResult<&str, &str> {
 		preceded(tag("meta::"), function_meta),
 		preceded(tag("parse::"), function_parse),
 		preceded(tag("rand::"), function_rand),
+		preceded(tag("search::"), function_search),
 		preceded(tag("session::"), function_session),
 		preceded(tag("string::"), function_string),
 		preceded(tag("time::"), function_time),
 		preceded(tag("type::"), function_type),
+		preceded(tag("vector::"), function_vector),
 		tag("count"),
 		tag("not"),
 		tag("rand"),
@@ -370,9 +375,20 @@ fn function_geo(i: &str) -> IResult<&str, &str> {
 	alt((
 		tag("area"),
 		tag("bearing"),
+		tag("bounding_box"),
+		tag("buffer"),
 		tag("centroid"),
+		tag("contains"),
+		tag("convex_hull"),
 		tag("distance"),
+		tag("intersection"),
+		tag("length"),
+		tag("simplify"),
+		tag("union"),
+		tag("within_distance"),
 		preceded(tag("hash::"), alt((tag("decode"), tag("encode")))),
+		preceded(tag("wkb::"), alt((tag("decode"), tag("encode")))),
+		preceded(tag("wkt::"), alt((tag("decode"), tag("encode")))),
 	))(i)
 }
 
@@ -469,6 +485,10 @@ fn function_rand(i: &str) -> IResult<&str, &str> {
 	))(i)
 }
 
+fn function_search(i: &str) -> IResult<&str, &str> {
+	alt((tag("highlight"), tag("score")))(i)
+}
+
 fn function_session(i: &str) -> IResult<&str, &str> {
 	alt((
 		tag("db"),
@@ -509,7 +529,9 @@ fn function_time(i: &str) -> IResult<&str, &str> {
 		tag("format"),
 		tag("group"),
 		tag("hour"),
+		tag("max"),
 		tag("minute"),
+		tag("min"),
 		tag("month"),
 		tag("nano"),
 		tag("now"),
@@ -541,6 +563,17 @@ fn function_type(i: &str) -> IResult<&str, &str> {
 	))(i)
 }
 
+fn function_vector(i: &str) -> IResult<&str, &str> {
+	alt((
+		tag("distance::euclidean"),
+		tag("distance::manhattan"),
+		tag("dot"),
+		tag("magnitude"),
+		tag("normalize"),
+		tag("similarity::cosine"),
+	))(i)
+}
+
 #[cfg(test)]
 mod tests {
 
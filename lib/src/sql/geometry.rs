@@ -22,6 +22,9 @@ use std::cmp::Ordering;
 use std::iter::{once, FromIterator};
 use std::{fmt, hash};
 
+mod wkb;
+mod wkt;
+
 pub(crate) const TOKEN: &str = "$surrealdb::private::sql::Geometry";
 
 const SINGLE: char = '\'';
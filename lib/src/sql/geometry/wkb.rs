@@ -0,0 +1,250 @@
+use crate::sql::geometry::Geometry;
+use geo::{Coord, LineString, Point, Polygon};
+use geo::{MultiLineString, MultiPoint, MultiPolygon};
+
+/// The maximum nesting depth of geometry collections
+const MAX_DEPTH: usize = 32;
+
+impl Geometry {
+	/// Converts the geometry to little-endian Well-Known Binary
+	pub fn to_wkb(&self) -> Vec<u8> {
+		let mut out = Vec::new();
+		write_geometry(&mut out, self);
+		out
+	}
+
+	/// Parses a geometry from Well-Known Binary, in either byte order
+	pub fn from_wkb(v: &[u8]) -> Option<Geometry> {
+		let mut r = Reader {
+			buf: v,
+			le: true,
+		};
+		let v = r.geometry(0)?;
+		match r.buf.is_empty() {
+			true => Some(v),
+			false => None,
+		}
+	}
+}
+
+fn write_header(out: &mut Vec<u8>, kind: u32) {
+	out.push(1);
+	out.extend_from_slice(&kind.to_le_bytes());
+}
+
+fn write_len(out: &mut Vec<u8>, len: usize) {
+	out.extend_from_slice(&(len as u32).to_le_bytes());
+}
+
+fn write_coord(out: &mut Vec<u8>, v: &Coord<f64>) {
+	out.extend_from_slice(&v.x.to_le_bytes());
+	out.extend_from_slice(&v.y.to_le_bytes());
+}
+
+fn write_line(out: &mut Vec<u8>, v: &LineString<f64>) {
+	write_len(out, v.0.len());
+	v.coords().for_each(|v| write_coord(out, v));
+}
+
+fn write_polygon(out: &mut Vec<u8>, v: &Polygon<f64>) {
+	match v.exterior().0.is_empty() {
+		true => write_len(out, 0),
+		false => {
+			write_len(out, v.interiors().len() + 1);
+			write_line(out, v.exterior());
+			v.interiors().iter().for_each(|v| write_line(out, v));
+		}
+	}
+}
+
+fn write_geometry(out: &mut Vec<u8>, v: &Geometry) {
+	match v {
+		Geometry::Point(v) => {
+			write_header(out, 1);
+			write_coord(out, &v.0);
+		}
+		Geometry::Line(v) => {
+			write_header(out, 2);
+			write_line(out, v);
+		}
+		Geometry::Polygon(v) => {
+			write_header(out, 3);
+			write_polygon(out, v);
+		}
+		Geometry::MultiPoint(v) => {
+			write_header(out, 4);
+			write_len(out, v.0.len());
+			v.iter().for_each(|v| write_geometry(out, &Geometry::Point(*v)));
+		}
+		Geometry::MultiLine(v) => {
+			write_header(out, 5);
+			write_len(out, v.0.len());
+			v.iter().for_each(|v| {
+				write_header(out, 2);
+				write_line(out, v);
+			});
+		}
+		Geometry::MultiPolygon(v) => {
+			write_header(out, 6);
+			write_len(out, v.0.len());
+			v.iter().for_each(|v| {
+				write_header(out, 3);
+				write_polygon(out, v);
+			});
+		}
+		Geometry::Collection(v) => {
+			write_header(out, 7);
+			write_len(out, v.len());
+			v.iter().for_each(|v| write_geometry(out, v));
+		}
+	}
+}
+
+struct Reader<'a> {
+	buf: &'a [u8],
+	le: bool,
+}
+
+impl<'a> Reader<'a> {
+	fn bytes<const N: usize>(&mut self) -> Option<[u8; N]> {
+		let v = self.buf.get(..N)?.try_into().ok()?;
+		self.buf = &self.buf[N..];
+		Some(v)
+	}
+
+	fn u32(&mut self) -> Option<u32> {
+		let v = self.bytes::<4>()?;
+		Some(match self.le {
+			true => u32::from_le_bytes(v),
+			false => u32::from_be_bytes(v),
+		})
+	}
+
+	fn f64(&mut self) -> Option<f64> {
+		let v = self.bytes::<8>()?;
+		Some(match self.le {
+			true => f64::from_le_bytes(v),
+			false => f64::from_be_bytes(v),
+		})
+	}
+
+	/// Reads a count of items, each of which takes at least `size` bytes
+	fn len(&mut self, size: usize) -> Option<usize> {
+		let v = self.u32()? as usize;
+		match v.checked_mul(size)? <= self.buf.len() {
+			true => Some(v),
+			false => None,
+		}
+	}
+
+	/// Reads the byte order and type of a geometry
+	fn header(&mut self) -> Option<u32> {
+		self.le = match self.bytes::<1>()? {
+			[0] => false,
+			[1] => true,
+			_ => return None,
+		};
+		self.u32()
+	}
+
+	fn coord(&mut self) -> Option<Coord<f64>> {
+		Some(Coord {
+			x: self.f64()?,
+			y: self.f64()?,
+		})
+	}
+
+	fn line(&mut self) -> Option<LineString<f64>> {
+		let len = self.len(16)?;
+		(0..len).map(|_| self.coord()).collect::<Option<Vec<_>>>().map(LineString)
+	}
+
+	fn polygon(&mut self) -> Option<Polygon<f64>> {
+		let len = self.len(4)?;
+		let mut v = (0..len).map(|_| self.line()).collect::<Option<Vec<_>>>()?;
+		Some(match v.is_empty() {
+			true => Polygon::new(LineString(vec![]), vec![]),
+			false => {
+				let e = v.remove(0);
+				Polygon::new(e, v)
+			}
+		})
+	}
+
+	/// Reads a geometry of a specific type, within a multi geometry
+	fn child<T>(&mut self, kind: u32, f: impl Fn(&mut Self) -> Option<T>) -> Option<T> {
+		match self.header()? == kind {
+			true => f(self),
+			false => None,
+		}
+	}
+
+	fn geometry(&mut self, depth: usize) -> Option<Geometry> {
+		if depth > MAX_DEPTH {
+			return None;
+		}
+		Some(match self.header()? {
+			1 => Geometry::Point(Point(self.coord()?)),
+			2 => Geometry::Line(self.line()?),
+			3 => Geometry::Polygon(self.polygon()?),
+			4 => {
+				let len = self.len(21)?;
+				let v = (0..len).map(|_| self.child(1, |r| r.coord().map(Point)));
+				Geometry::MultiPoint(MultiPoint(v.collect::<Option<Vec<_>>>()?))
+			}
+			5 => {
+				let len = self.len(9)?;
+				let v = (0..len).map(|_| self.child(2, Self::line));
+				Geometry::MultiLine(MultiLineString(v.collect::<Option<Vec<_>>>()?))
+			}
+			6 => {
+				let len = self.len(9)?;
+				let v = (0..len).map(|_| self.child(3, Self::polygon));
+				Geometry::MultiPolygon(MultiPolygon(v.collect::<Option<Vec<_>>>()?))
+			}
+			7 => {
+				let len = self.len(5)?;
+				let v = (0..len).map(|_| self.geometry(depth + 1));
+				Geometry::Collection(v.collect::<Option<Vec<_>>>()?)
+			}
+			_ => return None,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn wkb_point() {
+		let val = Geometry::Point((1.0, 2.0).into());
+		let enc = val.to_wkb();
+		assert_eq!(enc, vec![1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 63, 0, 0, 0, 0, 0, 0, 0, 64]);
+		assert_eq!(Geometry::from_wkb(&enc), Some(val));
+	}
+
+	#[test]
+	fn wkb_big_endian() {
+		let enc = vec![0, 0, 0, 0, 1, 63, 240, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0];
+		assert_eq!(Geometry::from_wkb(&enc), Some(Geometry::Point((1.0, 2.0).into())));
+	}
+
+	#[test]
+	fn wkb_collection() {
+		let val = Geometry::from_wkt(
+			"GEOMETRYCOLLECTION(MULTIPOINT((1 2), (3 4)), POLYGON((0 0, 4 0, 4 4, 0 0), (1 1, 2 1, 2 2, 1 1)), MULTILINESTRING((1 2, 3 4)))",
+		)
+		.unwrap();
+		assert_eq!(Geometry::from_wkb(&val.to_wkb()), Some(val));
+	}
+
+	#[test]
+	fn wkb_invalid() {
+		assert!(Geometry::from_wkb(&[]).is_none());
+		assert!(Geometry::from_wkb(&[1, 1, 0, 0, 0, 0]).is_none());
+		assert!(Geometry::from_wkb(&[1, 2, 0, 0, 0, 255, 255, 255, 255]).is_none());
+		assert!(Geometry::from_wkb(&[1, 233, 3, 0, 0]).is_none());
+	}
+}
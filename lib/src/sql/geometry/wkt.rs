@@ -0,0 +1,194 @@
+use crate::sql::comment::{mightbespace, shouldbespace};
+use crate::sql::common::commas;
+use crate::sql::error::IResult;
+use crate::sql::geometry::Geometry;
+use geo::{Coord, LineString, Point, Polygon};
+use geo::{MultiLineString, MultiPoint, MultiPolygon};
+use nom::branch::alt;
+use nom::bytes::complete::tag_no_case;
+use nom::character::complete::char;
+use nom::combinator::{all_consuming, map};
+use nom::multi::separated_list1;
+use nom::number::complete::double;
+use nom::sequence::{delimited, pair, preceded, terminated};
+use std::iter::once;
+
+impl Geometry {
+	/// Converts the geometry to Well-Known Text
+	pub fn to_wkt(&self) -> String {
+		match self {
+			Self::Point(v) => format!("POINT({})", fmt_coord(&v.0)),
+			Self::Line(v) => format!("LINESTRING{}", fmt_line(v)),
+			Self::Polygon(v) => format!("POLYGON{}", fmt_polygon(v)),
+			Self::MultiPoint(v) => format!(
+				"MULTIPOINT{}",
+				fmt_list(v.iter().map(|v| format!("({})", fmt_coord(&v.0))))
+			),
+			Self::MultiLine(v) => format!("MULTILINESTRING{}", fmt_list(v.iter().map(fmt_line))),
+			Self::MultiPolygon(v) => format!("MULTIPOLYGON{}", fmt_list(v.iter().map(fmt_polygon))),
+			Self::Collection(v) => {
+				format!("GEOMETRYCOLLECTION{}", fmt_list(v.iter().map(Geometry::to_wkt)))
+			}
+		}
+	}
+
+	/// Parses a geometry from Well-Known Text
+	pub fn from_wkt(v: &str) -> Option<Geometry> {
+		all_consuming(delimited(mightbespace, wkt, mightbespace))(v).ok().map(|(_, v)| v)
+	}
+}
+
+fn fmt_coord(v: &Coord<f64>) -> String {
+	format!("{} {}", v.x, v.y)
+}
+
+fn fmt_line(v: &LineString<f64>) -> String {
+	fmt_list(v.coords().map(fmt_coord))
+}
+
+fn fmt_polygon(v: &Polygon<f64>) -> String {
+	match v.exterior().0.is_empty() {
+		true => fmt_list(std::iter::empty()),
+		false => fmt_list(once(v.exterior()).chain(v.interiors()).map(fmt_line)),
+	}
+}
+
+fn fmt_list(v: impl Iterator<Item = String>) -> String {
+	let v: Vec<String> = v.collect();
+	match v.is_empty() {
+		true => String::from(" EMPTY"),
+		false => format!("({})", v.join(", ")),
+	}
+}
+
+pub fn wkt(i: &str) -> IResult<&str, Geometry> {
+	alt((point, line, polygon, multipoint, multiline, multipolygon, collection))(i)
+}
+
+fn point(i: &str) -> IResult<&str, Geometry> {
+	let (i, _) = tag_no_case("POINT")(i)?;
+	let (i, _) = mightbespace(i)?;
+	let (i, v) = parens(coord)(i)?;
+	Ok((i, Geometry::Point(v)))
+}
+
+fn line(i: &str) -> IResult<&str, Geometry> {
+	let (i, _) = tag_no_case("LINESTRING")(i)?;
+	let (i, v) = alt((map(empty, LineString), preceded(mightbespace, line_vals)))(i)?;
+	Ok((i, Geometry::Line(v)))
+}
+
+fn polygon(i: &str) -> IResult<&str, Geometry> {
+	let (i, _) = tag_no_case("POLYGON")(i)?;
+	let (i, v) = alt((
+		map(empty::<Vec<LineString<f64>>>, |_| Polygon::new(LineString(vec![]), vec![])),
+		preceded(mightbespace, polygon_vals),
+	))(i)?;
+	Ok((i, Geometry::Polygon(v)))
+}
+
+fn multipoint(i: &str) -> IResult<&str, Geometry> {
+	let (i, _) = tag_no_case("MULTIPOINT")(i)?;
+	let (i, v) = alt((
+		empty,
+		preceded(mightbespace, parens(separated_list1(commas, alt((parens(coord), coord))))),
+	))(i)?;
+	Ok((i, Geometry::MultiPoint(MultiPoint(v))))
+}
+
+fn multiline(i: &str) -> IResult<&str, Geometry> {
+	let (i, _) = tag_no_case("MULTILINESTRING")(i)?;
+	let (i, v) =
+		alt((empty, preceded(mightbespace, parens(separated_list1(commas, line_vals)))))(i)?;
+	Ok((i, Geometry::MultiLine(MultiLineString(v))))
+}
+
+fn multipolygon(i: &str) -> IResult<&str, Geometry> {
+	let (i, _) = tag_no_case("MULTIPOLYGON")(i)?;
+	let (i, v) =
+		alt((empty, preceded(mightbespace, parens(separated_list1(commas, polygon_vals)))))(i)?;
+	Ok((i, Geometry::MultiPolygon(MultiPolygon(v))))
+}
+
+fn collection(i: &str) -> IResult<&str, Geometry> {
+	let (i, _) = tag_no_case("GEOMETRYCOLLECTION")(i)?;
+	let (i, v) = alt((empty, preceded(mightbespace, parens(separated_list1(commas, wkt)))))(i)?;
+	Ok((i, Geometry::Collection(v)))
+}
+
+//
+//
+//
+
+fn line_vals(i: &str) -> IResult<&str, LineString<f64>> {
+	let (i, v) = parens(separated_list1(commas, coord))(i)?;
+	Ok((i, LineString::from(v)))
+}
+
+fn polygon_vals(i: &str) -> IResult<&str, Polygon<f64>> {
+	let (i, mut v) = parens(separated_list1(commas, line_vals))(i)?;
+	let e = v.remove(0);
+	Ok((i, Polygon::new(e, v)))
+}
+
+fn coord(i: &str) -> IResult<&str, Point<f64>> {
+	let (i, x) = double(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, y) = double(i)?;
+	Ok((i, Point::new(x, y)))
+}
+
+fn empty<T: Default>(i: &str) -> IResult<&str, T> {
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("EMPTY")(i)?;
+	Ok((i, T::default()))
+}
+
+fn parens<'a, O>(
+	f: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+	delimited(pair(char('('), mightbespace), f, terminated(mightbespace, char(')')))
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn wkt_point() {
+		let out = Geometry::from_wkt("POINT (-0.118092 51.509865)").unwrap();
+		assert_eq!(out, Geometry::Point((-0.118092, 51.509865).into()));
+		assert_eq!("POINT(-0.118092 51.509865)", out.to_wkt());
+	}
+
+	#[test]
+	fn wkt_polygon() {
+		let sql = "POLYGON((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 2 1, 2 2, 1 1))";
+		let out = Geometry::from_wkt(sql).unwrap();
+		assert!(matches!(&out, Geometry::Polygon(v) if v.interiors().len() == 1));
+		assert_eq!(sql, out.to_wkt());
+	}
+
+	#[test]
+	fn wkt_multipoint() {
+		let out = Geometry::from_wkt("multipoint(1 2, 3 4)").unwrap();
+		assert_eq!("MULTIPOINT((1 2), (3 4))", out.to_wkt());
+		assert_eq!(Geometry::from_wkt(&out.to_wkt()), Some(out));
+	}
+
+	#[test]
+	fn wkt_collection() {
+		let sql = "GEOMETRYCOLLECTION(POINT(1 2), LINESTRING(1 2, 3 4), MULTIPOLYGON(((0 0, 1 0, 1 1, 0 0))), MULTILINESTRING EMPTY)";
+		let out = Geometry::from_wkt(sql).unwrap();
+		assert_eq!(sql, out.to_wkt());
+	}
+
+	#[test]
+	fn wkt_invalid() {
+		assert!(Geometry::from_wkt("POINT(1)").is_none());
+		assert!(Geometry::from_wkt("POINT EMPTY").is_none());
+		assert!(Geometry::from_wkt("LINESTRING(1 2, 3 4) extra").is_none());
+		assert!(Geometry::from_wkt("POINT Z(1 2 3)").is_none());
+	}
+}
@@ -0,0 +1,297 @@
+use crate::sql::comment::{mightbespace, shouldbespace};
+use crate::sql::common::commas;
+use crate::sql::error::IResult;
+use crate::sql::ident::{ident, Ident};
+use crate::sql::number::{number, Number};
+use nom::branch::alt;
+use nom::bytes::complete::tag_no_case;
+use nom::character::complete::char;
+use nom::character::complete::u16;
+use nom::combinator::{map, opt};
+use nom::sequence::{preceded, tuple};
+use serde::de::value::{MapAccessDeserializer, StrDeserializer};
+use serde::de::{self, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Hash)]
+pub enum Index {
+	/// (Basic) non unique
+	#[default]
+	Idx,
+	/// Unique index
+	Uniq,
+	/// Index with Full-Text search capabilities
+	Search {
+		az: Ident,
+		sc: Scoring,
+		hl: bool,
+	},
+	/// Index for k-nearest-neighbour searches on vectors
+	Vector {
+		dimension: u16,
+		distance: Distance,
+	},
+	/// Index for geospatial searches on geometries
+	Geo,
+}
+
+/// Deserializes an index type, including the `uniq` flag of index
+/// definitions which were stored before index types were introduced
+pub(crate) fn legacy<'de, D>(deserializer: D) -> Result<Index, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	struct IndexVisitor;
+
+	impl<'de> Visitor<'de> for IndexVisitor {
+		type Value = Index;
+
+		fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+			f.write_str("an index type, or a unique flag")
+		}
+
+		fn visit_bool<E: de::Error>(self, v: bool) -> Result<Index, E> {
+			Ok(match v {
+				true => Index::Uniq,
+				false => Index::Idx,
+			})
+		}
+
+		fn visit_str<E: de::Error>(self, v: &str) -> Result<Index, E> {
+			Index::deserialize(StrDeserializer::new(v))
+		}
+
+		fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Index, A::Error> {
+			Index::deserialize(MapAccessDeserializer::new(map))
+		}
+	}
+
+	deserializer.deserialize_any(IndexVisitor)
+}
+
+impl fmt::Display for Index {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Idx => Ok(()),
+			Self::Uniq => f.write_str("UNIQUE"),
+			Self::Search {
+				az,
+				sc,
+				hl,
+			} => {
+				write!(f, "SEARCH ANALYZER {az} {sc}")?;
+				if *hl {
+					f.write_str(" HIGHLIGHTS")?
+				}
+				Ok(())
+			}
+			Self::Vector {
+				dimension,
+				distance,
+			} => write!(f, "VECTOR DIMENSION {dimension} DIST {distance}"),
+			Self::Geo => f.write_str("GEO"),
+		}
+	}
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash)]
+pub enum Scoring {
+	/// Okapi BM25 ranking
+	Bm {
+		k1: Number,
+		b: Number,
+	},
+}
+
+impl Default for Scoring {
+	fn default() -> Self {
+		Self::Bm {
+			k1: Number::Float(1.2),
+			b: Number::Float(0.75),
+		}
+	}
+}
+
+impl fmt::Display for Scoring {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Bm {
+				k1,
+				b,
+			} => write!(f, "BM25({k1},{b})"),
+		}
+	}
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Hash)]
+pub enum Distance {
+	#[default]
+	Euclidean,
+	Manhattan,
+	Cosine,
+}
+
+impl fmt::Display for Distance {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Euclidean => f.write_str("EUCLIDEAN"),
+			Self::Manhattan => f.write_str("MANHATTAN"),
+			Self::Cosine => f.write_str("COSINE"),
+		}
+	}
+}
+
+pub fn index(i: &str) -> IResult<&str, Index> {
+	alt((unique, search, vector, geo))(i)
+}
+
+fn unique(i: &str) -> IResult<&str, Index> {
+	let (i, _) = tag_no_case("UNIQUE")(i)?;
+	Ok((i, Index::Uniq))
+}
+
+fn search(i: &str) -> IResult<&str, Index> {
+	let (i, _) = tag_no_case("SEARCH")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("ANALYZER")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, az) = ident(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, sc) = scoring(i)?;
+	let (i, hl) = opt(preceded(shouldbespace, tag_no_case("HIGHLIGHTS")))(i)?;
+	Ok((
+		i,
+		Index::Search {
+			az,
+			sc,
+			hl: hl.is_some(),
+		},
+	))
+}
+
+fn vector(i: &str) -> IResult<&str, Index> {
+	let (i, _) = tag_no_case("VECTOR")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("DIMENSION")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, dimension) = u16(i)?;
+	let (i, distance) =
+		opt(preceded(tuple((shouldbespace, tag_no_case("DIST"), shouldbespace)), distance))(i)?;
+	Ok((
+		i,
+		Index::Vector {
+			dimension,
+			distance: distance.unwrap_or_default(),
+		},
+	))
+}
+
+fn geo(i: &str) -> IResult<&str, Index> {
+	let (i, _) = tag_no_case("GEO")(i)?;
+	Ok((i, Index::Geo))
+}
+
+fn distance(i: &str) -> IResult<&str, Distance> {
+	alt((
+		map(tag_no_case("EUCLIDEAN"), |_| Distance::Euclidean),
+		map(tag_no_case("MANHATTAN"), |_| Distance::Manhattan),
+		map(tag_no_case("COSINE"), |_| Distance::Cosine),
+	))(i)
+}
+
+fn scoring(i: &str) -> IResult<&str, Scoring> {
+	let (i, _) = tag_no_case("BM25")(i)?;
+	let (i, v) =
+		opt(tuple((char('('), mightbespace, number, commas, number, mightbespace, char(')'))))(i)?;
+	Ok((
+		i,
+		match v {
+			Some((_, _, k1, _, b, _, _)) => Scoring::Bm {
+				k1,
+				b,
+			},
+			None => Scoring::default(),
+		},
+	))
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn index_unique() {
+		let sql = "UNIQUE";
+		let res = index(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(out, Index::Uniq);
+		assert_eq!("UNIQUE", format!("{}", out));
+	}
+
+	#[test]
+	fn index_search() {
+		let sql = "SEARCH ANALYZER simple BM25";
+		let res = index(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!("SEARCH ANALYZER simple BM25(1.2,0.75)", format!("{}", out));
+	}
+
+	#[test]
+	fn index_search_params() {
+		let sql = "SEARCH ANALYZER simple BM25(1.5, 0.5) HIGHLIGHTS";
+		let res = index(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(
+			out,
+			Index::Search {
+				az: Ident::from("simple"),
+				sc: Scoring::Bm {
+					k1: Number::Float(1.5),
+					b: Number::Float(0.5),
+				},
+				hl: true,
+			}
+		);
+		assert_eq!("SEARCH ANALYZER simple BM25(1.5,0.5) HIGHLIGHTS", format!("{}", out));
+	}
+
+	#[test]
+	fn index_vector() {
+		let sql = "VECTOR DIMENSION 3 DIST COSINE";
+		let res = index(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(
+			out,
+			Index::Vector {
+				dimension: 3,
+				distance: Distance::Cosine,
+			}
+		);
+		assert_eq!("VECTOR DIMENSION 3 DIST COSINE", format!("{}", out));
+	}
+
+	#[test]
+	fn index_vector_default() {
+		let sql = "VECTOR DIMENSION 128";
+		let res = index(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!("VECTOR DIMENSION 128 DIST EUCLIDEAN", format!("{}", out));
+	}
+
+	#[test]
+	fn index_geo() {
+		let sql = "GEO";
+		let res = index(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(out, Index::Geo);
+		assert_eq!("GEO", format!("{}", out));
+	}
+}
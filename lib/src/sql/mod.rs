@@ -1,10 +1,12 @@
 //! The full type definitions for the SurrealQL query language
 
+pub(crate) mod after;
 pub(crate) mod algorithm;
 pub(crate) mod array;
 pub(crate) mod base;
 pub(crate) mod block;
 pub(crate) mod bytes;
+pub(crate) mod changefeed;
 pub(crate) mod comment;
 pub(crate) mod common;
 pub(crate) mod cond;
@@ -17,9 +19,11 @@ pub(crate) mod edges;
 pub(crate) mod ending;
 pub(crate) mod error;
 pub(crate) mod escape;
+pub(crate) mod explain;
 pub(crate) mod expression;
 pub(crate) mod fetch;
 pub(crate) mod field;
+pub(crate) mod filter;
 pub(crate) mod fmt;
 pub(crate) mod function;
 pub(crate) mod future;
@@ -29,6 +33,7 @@ pub(crate) mod group;
 pub(crate) mod id;
 pub(crate) mod ident;
 pub(crate) mod idiom;
+pub(crate) mod index;
 pub(crate) mod kind;
 pub(crate) mod limit;
 pub(crate) mod model;
@@ -46,6 +51,7 @@ pub(crate) mod permission;
 pub(crate) mod query;
 pub(crate) mod range;
 pub(crate) mod regex;
+pub(crate) mod role;
 pub(crate) mod script;
 pub(crate) mod special;
 pub(crate) mod split;
@@ -54,8 +60,10 @@ pub(crate) mod statement;
 pub(crate) mod strand;
 pub(crate) mod subquery;
 pub(crate) mod table;
+pub(crate) mod tabletype;
 pub(crate) mod thing;
 pub(crate) mod timeout;
+pub(crate) mod tokenizer;
 pub(crate) mod uuid;
 pub(crate) mod value;
 pub(crate) mod version;
@@ -68,11 +76,13 @@ pub mod statements;
 
 pub use self::parser::*;
 
+pub use self::after::After;
 pub use self::algorithm::Algorithm;
 pub use self::array::Array;
 pub use self::base::Base;
 pub use self::block::Block;
 pub use self::bytes::Bytes;
+pub use self::changefeed::ChangeFeed;
 pub use self::cond::Cond;
 pub use self::data::Data;
 pub use self::datetime::Datetime;
@@ -80,11 +90,13 @@ pub use self::dir::Dir;
 pub use self::duration::Duration;
 pub use self::edges::Edges;
 pub use self::error::Error;
+pub use self::explain::Explain;
 pub use self::expression::Expression;
 pub use self::fetch::Fetch;
 pub use self::fetch::Fetchs;
 pub use self::field::Field;
 pub use self::field::Fields;
+pub use self::filter::Filter;
 pub use self::function::Function;
 pub use self::future::Future;
 pub use self::geometry::Geometry;
@@ -95,6 +107,7 @@ pub use self::id::Id;
 pub use self::ident::Ident;
 pub use self::idiom::Idiom;
 pub use self::idiom::Idioms;
+pub use self::index::Index;
 pub use self::kind::Kind;
 pub use self::limit::Limit;
 pub use self::model::Model;
@@ -113,6 +126,7 @@ pub use self::permission::Permissions;
 pub use self::query::Query;
 pub use self::range::Range;
 pub use self::regex::Regex;
+pub use self::role::Role;
 pub use self::script::Script;
 pub use self::split::Split;
 pub use self::split::Splits;
@@ -123,8 +137,11 @@ pub use self::strand::Strand;
 pub use self::subquery::Subquery;
 pub use self::table::Table;
 pub use self::table::Tables;
+pub use self::tabletype::Relation;
+pub use self::tabletype::TableType;
 pub use self::thing::Thing;
 pub use self::timeout::Timeout;
+pub use self::tokenizer::Tokenizer;
 pub use self::uuid::Uuid;
 pub use self::value::Value;
 pub use self::value::Values;
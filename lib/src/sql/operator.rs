@@ -5,7 +5,9 @@ use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::bytes::complete::tag_no_case;
 use nom::character::complete::char;
-use nom::combinator::map;
+use nom::character::complete::u32;
+use nom::character::complete::u8;
+use nom::combinator::{map, opt};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -55,6 +57,9 @@ pub enum Operator {
 	//
 	Outside,
 	Intersects,
+	//
+	Matches(Option<u8>), // @{ref}@
+	Knn(u32),            // <|{k}|>
 }
 
 impl Default for Operator {
@@ -82,45 +87,48 @@ impl Operator {
 
 impl fmt::Display for Operator {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		f.write_str(match self {
-			Self::Or => "OR",
-			Self::And => "AND",
-			Self::Tco => "?:",
-			Self::Nco => "??",
-			Self::Add => "+",
-			Self::Sub => "-",
-			Self::Mul => "*",
-			Self::Div => "/",
-			Self::Pow => "**",
-			Self::Inc => "+=",
-			Self::Dec => "-=",
-			Self::Ext => "+?=",
-			Self::Equal => "=",
-			Self::Exact => "==",
-			Self::NotEqual => "!=",
-			Self::AllEqual => "*=",
-			Self::AnyEqual => "?=",
-			Self::Like => "~",
-			Self::NotLike => "!~",
-			Self::AllLike => "*~",
-			Self::AnyLike => "?~",
-			Self::LessThan => "<",
-			Self::LessThanOrEqual => "<=",
-			Self::MoreThan => ">",
-			Self::MoreThanOrEqual => ">=",
-			Self::Contain => "CONTAINS",
-			Self::NotContain => "CONTAINSNOT",
-			Self::ContainAll => "CONTAINSALL",
-			Self::ContainAny => "CONTAINSANY",
-			Self::ContainNone => "CONTAINSNONE",
-			Self::Inside => "INSIDE",
-			Self::NotInside => "NOTINSIDE",
-			Self::AllInside => "ALLINSIDE",
-			Self::AnyInside => "ANYINSIDE",
-			Self::NoneInside => "NONEINSIDE",
-			Self::Outside => "OUTSIDE",
-			Self::Intersects => "INTERSECTS",
-		})
+		match self {
+			Self::Or => f.write_str("OR"),
+			Self::And => f.write_str("AND"),
+			Self::Tco => f.write_str("?:"),
+			Self::Nco => f.write_str("??"),
+			Self::Add => f.write_str("+"),
+			Self::Sub => f.write_str("-"),
+			Self::Mul => f.write_str("*"),
+			Self::Div => f.write_str("/"),
+			Self::Pow => f.write_str("**"),
+			Self::Inc => f.write_str("+="),
+			Self::Dec => f.write_str("-="),
+			Self::Ext => f.write_str("+?="),
+			Self::Equal => f.write_str("="),
+			Self::Exact => f.write_str("=="),
+			Self::NotEqual => f.write_str("!="),
+			Self::AllEqual => f.write_str("*="),
+			Self::AnyEqual => f.write_str("?="),
+			Self::Like => f.write_str("~"),
+			Self::NotLike => f.write_str("!~"),
+			Self::AllLike => f.write_str("*~"),
+			Self::AnyLike => f.write_str("?~"),
+			Self::LessThan => f.write_str("<"),
+			Self::LessThanOrEqual => f.write_str("<="),
+			Self::MoreThan => f.write_str(">"),
+			Self::MoreThanOrEqual => f.write_str(">="),
+			Self::Contain => f.write_str("CONTAINS"),
+			Self::NotContain => f.write_str("CONTAINSNOT"),
+			Self::ContainAll => f.write_str("CONTAINSALL"),
+			Self::ContainAny => f.write_str("CONTAINSANY"),
+			Self::ContainNone => f.write_str("CONTAINSNONE"),
+			Self::Inside => f.write_str("INSIDE"),
+			Self::NotInside => f.write_str("NOTINSIDE"),
+			Self::AllInside => f.write_str("ALLINSIDE"),
+			Self::AnyInside => f.write_str("ANYINSIDE"),
+			Self::NoneInside => f.write_str("NONEINSIDE"),
+			Self::Outside => f.write_str("OUTSIDE"),
+			Self::Intersects => f.write_str("INTERSECTS"),
+			Self::Matches(Some(r)) => write!(f, "@{r}@"),
+			Self::Matches(None) => f.write_str("@@"),
+			Self::Knn(k) => write!(f, "<|{k}|>"),
+		}
 	}
 }
 
@@ -158,8 +166,10 @@ pub fn symbols(i: &str) -> IResult<&str, Operator> {
 			map(tag("*~"), |_| Operator::AllLike),
 			map(tag("?~"), |_| Operator::AnyLike),
 			map(char('~'), |_| Operator::Like),
+			matches,
 		)),
 		alt((
+			knn,
 			map(tag("<="), |_| Operator::LessThanOrEqual),
 			map(char('<'), |_| Operator::LessThan),
 			map(tag(">="), |_| Operator::MoreThanOrEqual),
@@ -192,6 +202,20 @@ pub fn symbols(i: &str) -> IResult<&str, Operator> {
 	Ok((i, v))
 }
 
+fn matches(i: &str) -> IResult<&str, Operator> {
+	let (i, _) = char('@')(i)?;
+	let (i, v) = opt(u8)(i)?;
+	let (i, _) = char('@')(i)?;
+	Ok((i, Operator::Matches(v)))
+}
+
+fn knn(i: &str) -> IResult<&str, Operator> {
+	let (i, _) = tag("<|")(i)?;
+	let (i, v) = u32(i)?;
+	let (i, _) = tag("|>")(i)?;
+	Ok((i, Operator::Knn(v)))
+}
+
 pub fn phrases(i: &str) -> IResult<&str, Operator> {
 	let (i, _) = shouldbespace(i)?;
 	let (i, v) = alt((
@@ -49,6 +49,18 @@ impl Range {
 			},
 		})))
 	}
+
+	/// Restricts the range to the records after the specified id
+	pub(crate) fn after(self, id: &Id) -> Range {
+		Range {
+			beg: match self.beg {
+				Bound::Included(v) if v > *id => Bound::Included(v),
+				Bound::Excluded(v) if v >= *id => Bound::Excluded(v),
+				_ => Bound::Excluded(id.clone()),
+			},
+			..self
+		}
+	}
 }
 
 impl PartialOrd for Range {
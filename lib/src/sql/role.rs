@@ -0,0 +1,67 @@
+use crate::sql::common::commas;
+use crate::sql::error::IResult;
+use nom::branch::alt;
+use nom::bytes::complete::tag_no_case;
+use nom::combinator::map;
+use nom::multi::separated_list1;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The role granted to a user, ordered from least to most privileged
+#[derive(
+	Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord, Serialize, Deserialize, Hash,
+)]
+pub enum Role {
+	/// Can view data and definitions
+	Viewer,
+	/// Can view and edit data and definitions, but not users
+	Editor,
+	/// Can view and edit everything, including users
+	#[default]
+	Owner,
+}
+
+impl Role {
+	/// Returns the most privileged of the specified roles
+	pub fn highest(roles: &[Role]) -> Role {
+		roles.iter().max().copied().unwrap_or(Role::Viewer)
+	}
+}
+
+impl fmt::Display for Role {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(match self {
+			Self::Viewer => "VIEWER",
+			Self::Editor => "EDITOR",
+			Self::Owner => "OWNER",
+		})
+	}
+}
+
+pub fn role(i: &str) -> IResult<&str, Role> {
+	alt((
+		map(tag_no_case("VIEWER"), |_| Role::Viewer),
+		map(tag_no_case("EDITOR"), |_| Role::Editor),
+		map(tag_no_case("OWNER"), |_| Role::Owner),
+	))(i)
+}
+
+pub fn roles(i: &str) -> IResult<&str, Vec<Role>> {
+	separated_list1(commas, role)(i)
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn roles_multiple() {
+		let sql = "EDITOR, viewer";
+		let res = roles(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(out, vec![Role::Editor, Role::Viewer]);
+		assert_eq!(Role::highest(&out), Role::Editor);
+	}
+}
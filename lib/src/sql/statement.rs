@@ -13,6 +13,7 @@ use crate::sql::statements::commit::{commit, CommitStatement};
 use crate::sql::statements::create::{create, CreateStatement};
 use crate::sql::statements::define::{define, DefineStatement};
 use crate::sql::statements::delete::{delete, DeleteStatement};
+use crate::sql::statements::foreach::{foreach, ForeachStatement};
 use crate::sql::statements::ifelse::{ifelse, IfelseStatement};
 use crate::sql::statements::info::{info, InfoStatement};
 use crate::sql::statements::insert::{insert, InsertStatement};
@@ -20,11 +21,15 @@ use crate::sql::statements::kill::{kill, KillStatement};
 use crate::sql::statements::live::{live, LiveStatement};
 use crate::sql::statements::option::{option, OptionStatement};
 use crate::sql::statements::output::{output, OutputStatement};
+use crate::sql::statements::r#break::{r#break, BreakStatement};
+use crate::sql::statements::r#continue::{r#continue, ContinueStatement};
 use crate::sql::statements::relate::{relate, RelateStatement};
 use crate::sql::statements::remove::{remove, RemoveStatement};
 use crate::sql::statements::select::{select, SelectStatement};
 use crate::sql::statements::set::{set, SetStatement};
+use crate::sql::statements::show::{show, ShowStatement};
 use crate::sql::statements::sleep::{sleep, SleepStatement};
+use crate::sql::statements::throw::{throw, ThrowStatement};
 use crate::sql::statements::update::{update, UpdateStatement};
 use crate::sql::statements::yuse::{yuse, UseStatement};
 use crate::sql::value::Value;
@@ -75,11 +80,14 @@ pub fn statements(i: &str) -> IResult<&str, Statements> {
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Store, Hash)]
 pub enum Statement {
 	Begin(BeginStatement),
+	Break(BreakStatement),
 	Cancel(CancelStatement),
 	Commit(CommitStatement),
+	Continue(ContinueStatement),
 	Create(CreateStatement),
 	Define(DefineStatement),
 	Delete(DeleteStatement),
+	Foreach(ForeachStatement),
 	Ifelse(IfelseStatement),
 	Info(InfoStatement),
 	Insert(InsertStatement),
@@ -91,7 +99,9 @@ pub enum Statement {
 	Remove(RemoveStatement),
 	Select(SelectStatement),
 	Set(SetStatement),
+	Show(ShowStatement),
 	Sleep(SleepStatement),
+	Throw(ThrowStatement),
 	Update(UpdateStatement),
 	Use(UseStatement),
 }
@@ -109,11 +119,45 @@ impl Statement {
 		}
 	}
 
+	/// Returns the keyword which identifies the type of this statement
+	pub(crate) fn name(&self) -> &'static str {
+		match self {
+			Self::Begin(_) => "begin",
+			Self::Break(_) => "break",
+			Self::Cancel(_) => "cancel",
+			Self::Commit(_) => "commit",
+			Self::Continue(_) => "continue",
+			Self::Create(_) => "create",
+			Self::Define(_) => "define",
+			Self::Delete(_) => "delete",
+			Self::Foreach(_) => "for",
+			Self::Ifelse(_) => "if",
+			Self::Info(_) => "info",
+			Self::Insert(_) => "insert",
+			Self::Kill(_) => "kill",
+			Self::Live(_) => "live",
+			Self::Option(_) => "option",
+			Self::Output(_) => "return",
+			Self::Relate(_) => "relate",
+			Self::Remove(_) => "remove",
+			Self::Select(_) => "select",
+			Self::Set(_) => "let",
+			Self::Show(_) => "show",
+			Self::Sleep(_) => "sleep",
+			Self::Throw(_) => "throw",
+			Self::Update(_) => "update",
+			Self::Use(_) => "use",
+		}
+	}
+
 	pub(crate) fn writeable(&self) -> bool {
 		match self {
+			Self::Break(_) => false,
+			Self::Continue(_) => false,
 			Self::Create(v) => v.writeable(),
 			Self::Define(_) => true,
 			Self::Delete(v) => v.writeable(),
+			Self::Foreach(v) => v.writeable(),
 			Self::Ifelse(v) => v.writeable(),
 			Self::Info(_) => false,
 			Self::Insert(v) => v.writeable(),
@@ -125,13 +169,23 @@ impl Statement {
 			Self::Remove(_) => true,
 			Self::Select(v) => v.writeable(),
 			Self::Set(v) => v.writeable(),
+			Self::Show(_) => false,
 			Self::Sleep(_) => false,
+			Self::Throw(v) => v.writeable(),
 			Self::Update(v) => v.writeable(),
 			Self::Use(_) => false,
 			_ => unreachable!(),
 		}
 	}
 
+	/// Check whether the output records of this statement can be streamed
+	pub(crate) fn streamable(&self) -> bool {
+		match self {
+			Self::Select(v) => !v.writeable(),
+			_ => false,
+		}
+	}
+
 	pub(crate) async fn compute(
 		&self,
 		ctx: &Context<'_>,
@@ -140,9 +194,12 @@ impl Statement {
 		doc: Option<&Value>,
 	) -> Result<Value, Error> {
 		match self {
+			Self::Break(v) => v.compute(ctx, opt, txn, doc).await,
+			Self::Continue(v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Create(v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Delete(v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Define(v) => v.compute(ctx, opt, txn, doc).await,
+			Self::Foreach(v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Ifelse(v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Info(v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Insert(v) => v.compute(ctx, opt, txn, doc).await,
@@ -153,7 +210,9 @@ impl Statement {
 			Self::Remove(v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Select(v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Set(v) => v.compute(ctx, opt, txn, doc).await,
+			Self::Show(v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Sleep(v) => v.compute(ctx, opt, txn, doc).await,
+			Self::Throw(v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Update(v) => v.compute(ctx, opt, txn, doc).await,
 			_ => unreachable!(),
 		}
@@ -164,11 +223,14 @@ impl Display for Statement {
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
 		match self {
 			Self::Begin(v) => write!(Pretty::from(f), "{v}"),
+			Self::Break(v) => write!(Pretty::from(f), "{v}"),
 			Self::Cancel(v) => write!(Pretty::from(f), "{v}"),
 			Self::Commit(v) => write!(Pretty::from(f), "{v}"),
+			Self::Continue(v) => write!(Pretty::from(f), "{v}"),
 			Self::Create(v) => write!(Pretty::from(f), "{v}"),
 			Self::Define(v) => write!(Pretty::from(f), "{v}"),
 			Self::Delete(v) => write!(Pretty::from(f), "{v}"),
+			Self::Foreach(v) => write!(Pretty::from(f), "{v}"),
 			Self::Insert(v) => write!(Pretty::from(f), "{v}"),
 			Self::Ifelse(v) => write!(Pretty::from(f), "{v}"),
 			Self::Info(v) => write!(Pretty::from(f), "{v}"),
@@ -180,7 +242,9 @@ impl Display for Statement {
 			Self::Remove(v) => write!(Pretty::from(f), "{v}"),
 			Self::Select(v) => write!(Pretty::from(f), "{v}"),
 			Self::Set(v) => write!(Pretty::from(f), "{v}"),
+			Self::Show(v) => write!(Pretty::from(f), "{v}"),
 			Self::Sleep(v) => write!(Pretty::from(f), "{v}"),
+			Self::Throw(v) => write!(Pretty::from(f), "{v}"),
 			Self::Update(v) => write!(Pretty::from(f), "{v}"),
 			Self::Use(v) => write!(Pretty::from(f), "{v}"),
 		}
@@ -191,26 +255,35 @@ pub fn statement(i: &str) -> IResult<&str, Statement> {
 	delimited(
 		mightbespace,
 		alt((
-			map(begin, Statement::Begin),
-			map(cancel, Statement::Cancel),
-			map(commit, Statement::Commit),
-			map(create, Statement::Create),
-			map(define, Statement::Define),
-			map(delete, Statement::Delete),
-			map(ifelse, Statement::Ifelse),
-			map(info, Statement::Info),
-			map(insert, Statement::Insert),
-			map(kill, Statement::Kill),
-			map(live, Statement::Live),
-			map(option, Statement::Option),
-			map(output, Statement::Output),
-			map(relate, Statement::Relate),
-			map(remove, Statement::Remove),
-			map(select, Statement::Select),
-			map(set, Statement::Set),
-			map(sleep, Statement::Sleep),
-			map(update, Statement::Update),
-			map(yuse, Statement::Use),
+			alt((
+				map(begin, Statement::Begin),
+				map(r#break, Statement::Break),
+				map(cancel, Statement::Cancel),
+				map(commit, Statement::Commit),
+				map(r#continue, Statement::Continue),
+				map(create, Statement::Create),
+				map(define, Statement::Define),
+				map(delete, Statement::Delete),
+				map(foreach, Statement::Foreach),
+				map(ifelse, Statement::Ifelse),
+				map(info, Statement::Info),
+				map(insert, Statement::Insert),
+				map(kill, Statement::Kill),
+			)),
+			alt((
+				map(live, Statement::Live),
+				map(option, Statement::Option),
+				map(output, Statement::Output),
+				map(relate, Statement::Relate),
+				map(remove, Statement::Remove),
+				map(select, Statement::Select),
+				map(set, Statement::Set),
+				map(show, Statement::Show),
+				map(sleep, Statement::Sleep),
+				map(throw, Statement::Throw),
+				map(update, Statement::Update),
+				map(yuse, Statement::Use),
+			)),
 		)),
 		mightbespace,
 	)(i)
@@ -0,0 +1,53 @@
+use crate::ctx::Context;
+use crate::dbs::Options;
+use crate::dbs::Transaction;
+use crate::err::Error;
+use crate::sql::ending;
+use crate::sql::error::IResult;
+use crate::sql::value::Value;
+use derive::Store;
+use nom::bytes::complete::tag_no_case;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Store, Hash)]
+pub struct ContinueStatement;
+
+impl ContinueStatement {
+	pub(crate) async fn compute(
+		&self,
+		_ctx: &Context<'_>,
+		_opt: &Options,
+		_txn: &Transaction,
+		_doc: Option<&Value>,
+	) -> Result<Value, Error> {
+		Err(Error::Continue)
+	}
+}
+
+impl fmt::Display for ContinueStatement {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("CONTINUE")
+	}
+}
+
+pub fn r#continue(i: &str) -> IResult<&str, ContinueStatement> {
+	let (i, _) = tag_no_case("CONTINUE")(i)?;
+	let (i, _) = ending::ident(i)?;
+	Ok((i, ContinueStatement))
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn continue_statement() {
+		let sql = "CONTINUE";
+		let res = r#continue(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!("CONTINUE", format!("{}", out))
+	}
+}
@@ -10,6 +10,7 @@ use crate::sql::comment::shouldbespace;
 use crate::sql::data::{data, Data};
 use crate::sql::error::IResult;
 use crate::sql::output::{output, Output};
+use crate::sql::role::Role;
 use crate::sql::timeout::{timeout, Timeout};
 use crate::sql::value::{whats, Value, Values};
 use derive::Store;
@@ -52,7 +53,7 @@ impl CreateStatement {
 		// Selected DB?
 		opt.needs(Level::Db)?;
 		// Allowed to run?
-		opt.check(Level::No)?;
+		opt.check_role(Level::No, Role::Editor)?;
 		// Create a new iterator
 		let mut i = Iterator::new();
 		// Ensure futures are stored
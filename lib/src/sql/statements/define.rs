@@ -4,23 +4,31 @@ use crate::dbs::Options;
 use crate::dbs::Transaction;
 use crate::err::Error;
 use crate::sql::algorithm::{algorithm, Algorithm};
-use crate::sql::base::{base, base_or_scope, Base};
+use crate::sql::base::{base_or_root, base_or_scope, Base};
 use crate::sql::block::{block, Block};
+use crate::sql::changefeed::{changefeed, ChangeFeed};
 use crate::sql::comment::{mightbespace, shouldbespace};
 use crate::sql::common::commas;
 use crate::sql::duration::{duration, Duration};
 use crate::sql::error::IResult;
 use crate::sql::escape::escape_str;
+use crate::sql::filter::{filters, Filter};
 use crate::sql::fmt::is_pretty;
 use crate::sql::fmt::pretty_indent;
+use crate::sql::fmt::Fmt;
 use crate::sql::ident;
 use crate::sql::ident::{ident, Ident};
 use crate::sql::idiom;
 use crate::sql::idiom::{Idiom, Idioms};
+use crate::sql::index;
+use crate::sql::index::Index;
 use crate::sql::kind::{kind, Kind};
 use crate::sql::permission::{permissions, Permissions};
+use crate::sql::role::{roles, Role};
 use crate::sql::statements::UpdateStatement;
 use crate::sql::strand::strand_raw;
+use crate::sql::tabletype::{tabletype, TableType};
+use crate::sql::tokenizer::{tokenizers, Tokenizer};
 use crate::sql::value::{value, values, Value, Values};
 use crate::sql::view::{view, View};
 use argon2::password_hash::{PasswordHasher, SaltString};
@@ -33,7 +41,7 @@ use nom::character::complete::char;
 use nom::combinator::{map, opt};
 use nom::multi::many0;
 use nom::multi::separated_list0;
-use nom::sequence::tuple;
+use nom::sequence::{preceded, tuple};
 use rand::distributions::Alphanumeric;
 use rand::rngs::OsRng;
 use rand::Rng;
@@ -46,10 +54,11 @@ pub enum DefineStatement {
 	Namespace(DefineNamespaceStatement),
 	Database(DefineDatabaseStatement),
 	Function(DefineFunctionStatement),
-	Login(DefineLoginStatement),
+	User(DefineUserStatement),
 	Token(DefineTokenStatement),
 	Scope(DefineScopeStatement),
 	Param(DefineParamStatement),
+	Analyzer(DefineAnalyzerStatement),
 	Table(DefineTableStatement),
 	Event(DefineEventStatement),
 	Field(DefineFieldStatement),
@@ -68,10 +77,11 @@ impl DefineStatement {
 			Self::Namespace(ref v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Database(ref v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Function(ref v) => v.compute(ctx, opt, txn, doc).await,
-			Self::Login(ref v) => v.compute(ctx, opt, txn, doc).await,
+			Self::User(ref v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Token(ref v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Scope(ref v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Param(ref v) => v.compute(ctx, opt, txn, doc).await,
+			Self::Analyzer(ref v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Table(ref v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Event(ref v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Field(ref v) => v.compute(ctx, opt, txn, doc).await,
@@ -86,10 +96,11 @@ impl fmt::Display for DefineStatement {
 			Self::Namespace(v) => Display::fmt(v, f),
 			Self::Database(v) => Display::fmt(v, f),
 			Self::Function(v) => Display::fmt(v, f),
-			Self::Login(v) => Display::fmt(v, f),
+			Self::User(v) => Display::fmt(v, f),
 			Self::Token(v) => Display::fmt(v, f),
 			Self::Scope(v) => Display::fmt(v, f),
 			Self::Param(v) => Display::fmt(v, f),
+			Self::Analyzer(v) => Display::fmt(v, f),
 			Self::Table(v) => Display::fmt(v, f),
 			Self::Event(v) => Display::fmt(v, f),
 			Self::Field(v) => Display::fmt(v, f),
@@ -103,10 +114,11 @@ pub fn define(i: &str) -> IResult<&str, DefineStatement> {
 		map(namespace, DefineStatement::Namespace),
 		map(database, DefineStatement::Database),
 		map(function, DefineStatement::Function),
-		map(login, DefineStatement::Login),
+		map(user, DefineStatement::User),
 		map(token, DefineStatement::Token),
 		map(scope, DefineStatement::Scope),
 		map(param, DefineStatement::Param),
+		map(analyzer, DefineStatement::Analyzer),
 		map(table, DefineStatement::Table),
 		map(event, DefineStatement::Event),
 		map(field, DefineStatement::Field),
@@ -135,7 +147,7 @@ impl DefineNamespaceStatement {
 		// No need for NS/DB
 		opt.needs(Level::Kv)?;
 		// Allowed to run?
-		opt.check(Level::Kv)?;
+		opt.check_role(Level::Kv, Role::Editor)?;
 		// Process the statement
 		let key = crate::key::ns::new(&self.name);
 		txn.clone().lock().await.set(key, self).await?;
@@ -172,6 +184,7 @@ fn namespace(i: &str) -> IResult<&str, DefineNamespaceStatement> {
 #[format(Named)]
 pub struct DefineDatabaseStatement {
 	pub name: Ident,
+	pub changefeed: Option<ChangeFeed>,
 }
 
 impl DefineDatabaseStatement {
@@ -185,7 +198,7 @@ impl DefineDatabaseStatement {
 		// Selected NS?
 		opt.needs(Level::Ns)?;
 		// Allowed to run?
-		opt.check(Level::Ns)?;
+		opt.check_role(Level::Ns, Role::Editor)?;
 		// Clone transaction
 		let run = txn.clone();
 		// Claim transaction
@@ -201,7 +214,11 @@ impl DefineDatabaseStatement {
 
 impl fmt::Display for DefineDatabaseStatement {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		write!(f, "DEFINE DATABASE {}", self.name)
+		write!(f, "DEFINE DATABASE {}", self.name)?;
+		if let Some(ref v) = self.changefeed {
+			write!(f, " {v}")?;
+		}
+		Ok(())
 	}
 }
 
@@ -211,10 +228,12 @@ fn database(i: &str) -> IResult<&str, DefineDatabaseStatement> {
 	let (i, _) = alt((tag_no_case("DB"), tag_no_case("DATABASE")))(i)?;
 	let (i, _) = shouldbespace(i)?;
 	let (i, name) = ident(i)?;
+	let (i, changefeed) = opt(preceded(shouldbespace, changefeed))(i)?;
 	Ok((
 		i,
 		DefineDatabaseStatement {
 			name,
+			changefeed,
 		},
 	))
 }
@@ -242,7 +261,7 @@ impl DefineFunctionStatement {
 		// Selected DB?
 		opt.needs(Level::Db)?;
 		// Allowed to run?
-		opt.check(Level::Db)?;
+		opt.check_role(Level::Db, Role::Editor)?;
 		// Clone transaction
 		let run = txn.clone();
 		// Claim transaction
@@ -310,14 +329,26 @@ fn function(i: &str) -> IResult<&str, DefineFunctionStatement> {
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Store, Hash)]
 #[format(Named)]
-pub struct DefineLoginStatement {
+pub struct DefineUserStatement {
 	pub name: Ident,
 	pub base: Base,
 	pub hash: String,
 	pub code: String,
+	#[serde(default = "owner")]
+	pub roles: Vec<Role>,
 }
 
-impl DefineLoginStatement {
+/// Users defined with DEFINE LOGIN, before roles existed, were owners
+fn owner() -> Vec<Role> {
+	vec![Role::Owner]
+}
+
+impl DefineUserStatement {
+	/// Returns the role which is granted to this user
+	pub(crate) fn role(&self) -> Role {
+		Role::highest(&self.roles)
+	}
+
 	pub(crate) async fn compute(
 		&self,
 		_ctx: &Context<'_>,
@@ -326,11 +357,24 @@ impl DefineLoginStatement {
 		_doc: Option<&Value>,
 	) -> Result<Value, Error> {
 		match self.base {
+			Base::Kv => {
+				// Allowed to run?
+				opt.check_role(Level::Kv, Role::Owner)?;
+				// Clone transaction
+				let run = txn.clone();
+				// Claim transaction
+				let mut run = run.lock().await;
+				// Process the statement
+				let key = crate::key::us::new(&self.name);
+				run.set(key, self).await?;
+				// Ok all good
+				Ok(Value::None)
+			}
 			Base::Ns => {
 				// Selected DB?
 				opt.needs(Level::Ns)?;
 				// Allowed to run?
-				opt.check(Level::Kv)?;
+				opt.check_role(Level::Ns, Role::Owner)?;
 				// Clone transaction
 				let run = txn.clone();
 				// Claim transaction
@@ -346,7 +390,7 @@ impl DefineLoginStatement {
 				// Selected DB?
 				opt.needs(Level::Db)?;
 				// Allowed to run?
-				opt.check(Level::Ns)?;
+				opt.check_role(Level::Db, Role::Owner)?;
 				// Clone transaction
 				let run = txn.clone();
 				// Claim transaction
@@ -364,26 +408,34 @@ impl DefineLoginStatement {
 	}
 }
 
-impl fmt::Display for DefineLoginStatement {
+impl fmt::Display for DefineUserStatement {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		write!(f, "DEFINE LOGIN {} ON {} PASSHASH {}", self.name, self.base, escape_str(&self.hash))
+		write!(
+			f,
+			"DEFINE USER {} ON {} PASSHASH {} ROLES {}",
+			self.name,
+			self.base,
+			escape_str(&self.hash),
+			Fmt::comma_separated(&self.roles)
+		)
 	}
 }
 
-fn login(i: &str) -> IResult<&str, DefineLoginStatement> {
+fn user(i: &str) -> IResult<&str, DefineUserStatement> {
 	let (i, _) = tag_no_case("DEFINE")(i)?;
 	let (i, _) = shouldbespace(i)?;
-	let (i, _) = tag_no_case("LOGIN")(i)?;
+	let (i, kind) = alt((tag_no_case("USER"), tag_no_case("LOGIN")))(i)?;
 	let (i, _) = shouldbespace(i)?;
 	let (i, name) = ident(i)?;
 	let (i, _) = shouldbespace(i)?;
 	let (i, _) = tag_no_case("ON")(i)?;
 	let (i, _) = shouldbespace(i)?;
-	let (i, base) = base(i)?;
-	let (i, opts) = login_opts(i)?;
+	let (i, base) = base_or_root(i)?;
+	let (i, opts) = user_opts(i)?;
+	let (i, roles) = opt(user_roles)(i)?;
 	Ok((
 		i,
-		DefineLoginStatement {
+		DefineUserStatement {
 			name,
 			base,
 			code: rand::thread_rng()
@@ -392,40 +444,52 @@ fn login(i: &str) -> IResult<&str, DefineLoginStatement> {
 				.map(char::from)
 				.collect::<String>(),
 			hash: match opts {
-				DefineLoginOption::Passhash(v) => v,
-				DefineLoginOption::Password(v) => Argon2::default()
+				DefineUserOption::Passhash(v) => v,
+				DefineUserOption::Password(v) => Argon2::default()
 					.hash_password(v.as_ref(), &SaltString::generate(&mut OsRng))
 					.unwrap()
 					.to_string(),
 			},
+			// Logins defined with the legacy syntax have full access
+			roles: roles.unwrap_or_else(|| match kind.eq_ignore_ascii_case("LOGIN") {
+				true => vec![Role::Owner],
+				false => vec![Role::Viewer],
+			}),
 		},
 	))
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
-pub enum DefineLoginOption {
+pub enum DefineUserOption {
 	Password(String),
 	Passhash(String),
 }
 
-fn login_opts(i: &str) -> IResult<&str, DefineLoginOption> {
-	alt((login_pass, login_hash))(i)
+fn user_opts(i: &str) -> IResult<&str, DefineUserOption> {
+	alt((user_pass, user_hash))(i)
 }
 
-fn login_pass(i: &str) -> IResult<&str, DefineLoginOption> {
+fn user_pass(i: &str) -> IResult<&str, DefineUserOption> {
 	let (i, _) = shouldbespace(i)?;
 	let (i, _) = tag_no_case("PASSWORD")(i)?;
 	let (i, _) = shouldbespace(i)?;
 	let (i, v) = strand_raw(i)?;
-	Ok((i, DefineLoginOption::Password(v)))
+	Ok((i, DefineUserOption::Password(v)))
 }
 
-fn login_hash(i: &str) -> IResult<&str, DefineLoginOption> {
+fn user_hash(i: &str) -> IResult<&str, DefineUserOption> {
 	let (i, _) = shouldbespace(i)?;
 	let (i, _) = tag_no_case("PASSHASH")(i)?;
 	let (i, _) = shouldbespace(i)?;
 	let (i, v) = strand_raw(i)?;
-	Ok((i, DefineLoginOption::Passhash(v)))
+	Ok((i, DefineUserOption::Passhash(v)))
+}
+
+fn user_roles(i: &str) -> IResult<&str, Vec<Role>> {
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("ROLES")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	roles(i)
 }
 
 // --------------------------------------------------
@@ -454,7 +518,7 @@ impl DefineTokenStatement {
 				// Selected DB?
 				opt.needs(Level::Ns)?;
 				// Allowed to run?
-				opt.check(Level::Kv)?;
+				opt.check_role(Level::Kv, Role::Owner)?;
 				// Clone transaction
 				let run = txn.clone();
 				// Claim transaction
@@ -470,7 +534,7 @@ impl DefineTokenStatement {
 				// Selected DB?
 				opt.needs(Level::Db)?;
 				// Allowed to run?
-				opt.check(Level::Ns)?;
+				opt.check_role(Level::Ns, Role::Owner)?;
 				// Clone transaction
 				let run = txn.clone();
 				// Claim transaction
@@ -487,7 +551,7 @@ impl DefineTokenStatement {
 				// Selected DB?
 				opt.needs(Level::Db)?;
 				// Allowed to run?
-				opt.check(Level::Db)?;
+				opt.check_role(Level::Db, Role::Owner)?;
 				// Clone transaction
 				let run = txn.clone();
 				// Claim transaction
@@ -573,7 +637,7 @@ impl DefineScopeStatement {
 		// Selected DB?
 		opt.needs(Level::Db)?;
 		// Allowed to run?
-		opt.check(Level::Db)?;
+		opt.check_role(Level::Db, Role::Editor)?;
 		// Clone transaction
 		let run = txn.clone();
 		// Claim transaction
@@ -693,7 +757,7 @@ impl DefineParamStatement {
 		// Selected DB?
 		opt.needs(Level::Db)?;
 		// Allowed to run?
-		opt.check(Level::Db)?;
+		opt.check_role(Level::Db, Role::Editor)?;
 		// Clone transaction
 		let run = txn.clone();
 		// Claim transaction
@@ -738,13 +802,96 @@ fn param(i: &str) -> IResult<&str, DefineParamStatement> {
 // --------------------------------------------------
 // --------------------------------------------------
 
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Store, Hash)]
+#[format(Named)]
+pub struct DefineAnalyzerStatement {
+	pub name: Ident,
+	pub tokenizers: Option<Vec<Tokenizer>>,
+	pub filters: Option<Vec<Filter>>,
+}
+
+impl DefineAnalyzerStatement {
+	pub(crate) async fn compute(
+		&self,
+		_ctx: &Context<'_>,
+		opt: &Options,
+		txn: &Transaction,
+		_doc: Option<&Value>,
+	) -> Result<Value, Error> {
+		// Selected DB?
+		opt.needs(Level::Db)?;
+		// Allowed to run?
+		opt.check_role(Level::Db, Role::Editor)?;
+		// Clone transaction
+		let run = txn.clone();
+		// Claim transaction
+		let mut run = run.lock().await;
+		// Process the statement
+		let key = crate::key::az::new(opt.ns(), opt.db(), &self.name);
+		run.add_ns(opt.ns(), opt.strict).await?;
+		run.add_db(opt.ns(), opt.db(), opt.strict).await?;
+		run.set(key, self).await?;
+		// Ok all good
+		Ok(Value::None)
+	}
+}
+
+impl fmt::Display for DefineAnalyzerStatement {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "DEFINE ANALYZER {}", self.name)?;
+		if let Some(ref v) = self.tokenizers {
+			let tokens: Vec<String> = v.iter().map(|f| f.to_string()).collect();
+			write!(f, " TOKENIZERS {}", tokens.join(","))?;
+		}
+		if let Some(ref v) = self.filters {
+			let filters: Vec<String> = v.iter().map(|f| f.to_string()).collect();
+			write!(f, " FILTERS {}", filters.join(","))?;
+		}
+		Ok(())
+	}
+}
+
+fn analyzer(i: &str) -> IResult<&str, DefineAnalyzerStatement> {
+	let (i, _) = tag_no_case("DEFINE")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("ANALYZER")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, name) = ident(i)?;
+	let (i, tokenizers) = opt(preceded(
+		shouldbespace,
+		preceded(tag_no_case("TOKENIZERS"), preceded(shouldbespace, tokenizers)),
+	))(i)?;
+	let (i, filters) = opt(preceded(
+		shouldbespace,
+		preceded(tag_no_case("FILTERS"), preceded(shouldbespace, filters)),
+	))(i)?;
+	Ok((
+		i,
+		DefineAnalyzerStatement {
+			name,
+			tokenizers,
+			filters,
+		},
+	))
+}
+
+// --------------------------------------------------
+// --------------------------------------------------
+// --------------------------------------------------
+
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Store, Hash)]
 #[format(Named)]
 pub struct DefineTableStatement {
 	pub name: Ident,
 	pub drop: bool,
 	pub full: bool,
+	#[serde(default)]
+	pub kind: TableType,
 	pub view: Option<View>,
+	pub retention: Option<Duration>,
+	pub changefeed: Option<ChangeFeed>,
+	#[serde(default)]
+	pub audit: bool,
 	pub permissions: Permissions,
 }
 
@@ -759,7 +906,7 @@ impl DefineTableStatement {
 		// Selected DB?
 		opt.needs(Level::Db)?;
 		// Allowed to run?
-		opt.check(Level::Db)?;
+		opt.check_role(Level::Db, Role::Editor)?;
 		// Clone transaction
 		let run = txn.clone();
 		// Claim transaction
@@ -819,9 +966,21 @@ impl fmt::Display for DefineTableStatement {
 		} else {
 			" SCHEMALESS"
 		})?;
+		if self.kind != TableType::Any {
+			write!(f, " {}", self.kind)?
+		}
 		if let Some(ref v) = self.view {
 			write!(f, " {v}")?
 		}
+		if let Some(ref v) = self.retention {
+			write!(f, " RETENTION {v}")?
+		}
+		if let Some(ref v) = self.changefeed {
+			write!(f, " {v}")?
+		}
+		if self.audit {
+			f.write_str(" AUDIT")?
+		}
 		if !self.permissions.is_full() {
 			let _indent = if is_pretty() {
 				Some(pretty_indent())
@@ -861,10 +1020,32 @@ fn table(i: &str) -> IResult<&str, DefineTableStatement> {
 					_ => None,
 				})
 				.unwrap_or_default(),
+			kind: opts
+				.iter()
+				.find_map(|x| match x {
+					DefineTableOption::TableType(ref v) => Some(v.to_owned()),
+					_ => None,
+				})
+				.unwrap_or_default(),
 			view: opts.iter().find_map(|x| match x {
 				DefineTableOption::View(ref v) => Some(v.to_owned()),
 				_ => None,
 			}),
+			retention: opts.iter().find_map(|x| match x {
+				DefineTableOption::Retention(ref v) => Some(v.to_owned()),
+				_ => None,
+			}),
+			changefeed: opts.iter().find_map(|x| match x {
+				DefineTableOption::ChangeFeed(ref v) => Some(v.to_owned()),
+				_ => None,
+			}),
+			audit: opts
+				.iter()
+				.find_map(|x| match x {
+					DefineTableOption::Audit => Some(true),
+					_ => None,
+				})
+				.unwrap_or_default(),
 			permissions: opts
 				.iter()
 				.find_map(|x| match x {
@@ -882,11 +1063,25 @@ pub enum DefineTableOption {
 	View(View),
 	Schemaless,
 	Schemafull,
+	TableType(TableType),
+	Retention(Duration),
+	ChangeFeed(ChangeFeed),
+	Audit,
 	Permissions(Permissions),
 }
 
 fn table_opts(i: &str) -> IResult<&str, DefineTableOption> {
-	alt((table_drop, table_view, table_schemaless, table_schemafull, table_permissions))(i)
+	alt((
+		table_drop,
+		table_view,
+		table_schemaless,
+		table_schemafull,
+		table_type,
+		table_retention,
+		table_changefeed,
+		table_audit,
+		table_permissions,
+	))(i)
 }
 
 fn table_drop(i: &str) -> IResult<&str, DefineTableOption> {
@@ -913,6 +1108,32 @@ fn table_schemafull(i: &str) -> IResult<&str, DefineTableOption> {
 	Ok((i, DefineTableOption::Schemafull))
 }
 
+fn table_type(i: &str) -> IResult<&str, DefineTableOption> {
+	let (i, _) = shouldbespace(i)?;
+	let (i, v) = tabletype(i)?;
+	Ok((i, DefineTableOption::TableType(v)))
+}
+
+fn table_retention(i: &str) -> IResult<&str, DefineTableOption> {
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("RETENTION")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, v) = duration(i)?;
+	Ok((i, DefineTableOption::Retention(v)))
+}
+
+fn table_changefeed(i: &str) -> IResult<&str, DefineTableOption> {
+	let (i, _) = shouldbespace(i)?;
+	let (i, v) = changefeed(i)?;
+	Ok((i, DefineTableOption::ChangeFeed(v)))
+}
+
+fn table_audit(i: &str) -> IResult<&str, DefineTableOption> {
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("AUDIT")(i)?;
+	Ok((i, DefineTableOption::Audit))
+}
+
 fn table_permissions(i: &str) -> IResult<&str, DefineTableOption> {
 	let (i, _) = shouldbespace(i)?;
 	let (i, v) = permissions(i)?;
@@ -943,7 +1164,7 @@ impl DefineEventStatement {
 		// Selected DB?
 		opt.needs(Level::Db)?;
 		// Allowed to run?
-		opt.check(Level::Db)?;
+		opt.check_role(Level::Db, Role::Editor)?;
 		// Clone transaction
 		let run = txn.clone();
 		// Claim transaction
@@ -1029,7 +1250,7 @@ impl DefineFieldStatement {
 		// Selected DB?
 		opt.needs(Level::Db)?;
 		// Allowed to run?
-		opt.check(Level::Db)?;
+		opt.check_role(Level::Db, Role::Editor)?;
 		// Clone transaction
 		let run = txn.clone();
 		// Claim transaction
@@ -1177,7 +1398,8 @@ pub struct DefineIndexStatement {
 	pub name: Ident,
 	pub what: Ident,
 	pub cols: Idioms,
-	pub uniq: bool,
+	#[serde(alias = "uniq", deserialize_with = "index::legacy")]
+	pub index: Index,
 }
 
 impl DefineIndexStatement {
@@ -1191,7 +1413,7 @@ impl DefineIndexStatement {
 		// Selected DB?
 		opt.needs(Level::Db)?;
 		// Allowed to run?
-		opt.check(Level::Db)?;
+		opt.check_role(Level::Db, Role::Editor)?;
 		// Clone transaction
 		let run = txn.clone();
 		// Claim transaction
@@ -1233,8 +1455,8 @@ impl DefineIndexStatement {
 impl Display for DefineIndexStatement {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		write!(f, "DEFINE INDEX {} ON {} FIELDS {}", self.name, self.what, self.cols)?;
-		if self.uniq {
-			write!(f, " UNIQUE")?
+		if Index::Idx != self.index {
+			write!(f, " {}", self.index)?
 		}
 		Ok(())
 	}
@@ -1255,14 +1477,14 @@ fn index(i: &str) -> IResult<&str, DefineIndexStatement> {
 	let (i, _) = alt((tag_no_case("COLUMNS"), tag_no_case("FIELDS")))(i)?;
 	let (i, _) = shouldbespace(i)?;
 	let (i, cols) = idiom::locals(i)?;
-	let (i, uniq) = opt(tuple((shouldbespace, tag_no_case("UNIQUE"))))(i)?;
+	let (i, index) = opt(preceded(shouldbespace, index::index))(i)?;
 	Ok((
 		i,
 		DefineIndexStatement {
 			name,
 			what,
 			cols,
-			uniq: uniq.is_some(),
+			index: index.unwrap_or(Index::Idx),
 		},
 	))
 }
@@ -1279,4 +1501,77 @@ mod tests {
 		});
 		assert_eq!(22, stm.to_vec().len());
 	}
+
+	#[test]
+	fn check_define_user_legacy() {
+		#[derive(Serialize)]
+		struct Legacy {
+			name: Ident,
+			base: Base,
+			hash: String,
+			code: String,
+		}
+		let val = bung::to_vec_named(&Legacy {
+			name: Ident::from("test"),
+			base: Base::Ns,
+			hash: String::from("hash"),
+			code: String::from("code"),
+		})
+		.unwrap();
+		let stm = DefineUserStatement::from(val);
+		assert_eq!(stm.roles, vec![Role::Owner]);
+		assert_eq!(stm.role(), Role::Owner);
+	}
+
+	#[test]
+	fn check_define_table_legacy() {
+		#[derive(Serialize)]
+		struct Legacy {
+			name: Ident,
+			drop: bool,
+			full: bool,
+			view: Option<View>,
+			permissions: Permissions,
+		}
+		let val = bung::to_vec_named(&Legacy {
+			name: Ident::from("test"),
+			drop: false,
+			full: true,
+			view: None,
+			permissions: Permissions::default(),
+		})
+		.unwrap();
+		let stm = DefineTableStatement::from(val);
+		assert_eq!(stm.kind, TableType::Any);
+		assert!(stm.full);
+	}
+
+	#[test]
+	fn check_define_index_legacy() {
+		#[derive(Serialize)]
+		struct Legacy {
+			name: Ident,
+			what: Ident,
+			cols: Idioms,
+			uniq: bool,
+		}
+		let val = bung::to_vec_named(&Legacy {
+			name: Ident::from("test"),
+			what: Ident::from("test"),
+			cols: Idioms(vec![Idiom::from(String::from("test"))]),
+			uniq: true,
+		})
+		.unwrap();
+		let stm = DefineIndexStatement::from(val);
+		assert_eq!(stm.index, Index::Uniq);
+	}
+
+	#[test]
+	fn check_define_index_serialize() {
+		let sql = "DEFINE INDEX test ON test FIELDS test SEARCH ANALYZER simple BM25(1.2,0.75)";
+		let (_, stm) = index(sql).unwrap();
+		assert_eq!(DefineIndexStatement::from(stm.to_vec()), stm);
+		let (_, stm) = index("DEFINE INDEX test ON test FIELDS test UNIQUE").unwrap();
+		assert_eq!(DefineIndexStatement::from(stm.to_vec()), stm);
+	}
 }
@@ -0,0 +1,117 @@
+use crate::ctx::Context;
+use crate::dbs::Options;
+use crate::dbs::Transaction;
+use crate::err::Error;
+use crate::sql::block::{block, Block};
+use crate::sql::comment::{mightbespace, shouldbespace};
+use crate::sql::error::IResult;
+use crate::sql::param::{param, Param};
+use crate::sql::value::{value, Value};
+use async_recursion::async_recursion;
+use derive::Store;
+use nom::bytes::complete::tag_no_case;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Store, Hash)]
+pub struct ForeachStatement {
+	pub param: Param,
+	pub range: Value,
+	pub block: Block,
+}
+
+impl ForeachStatement {
+	pub(crate) fn writeable(&self) -> bool {
+		self.range.writeable() || self.block.writeable()
+	}
+
+	#[cfg_attr(not(target_arch = "wasm32"), async_recursion)]
+	#[cfg_attr(target_arch = "wasm32", async_recursion(?Send))]
+	pub(crate) async fn compute(
+		&self,
+		ctx: &Context<'_>,
+		opt: &Options,
+		txn: &Transaction,
+		doc: Option<&'async_recursion Value>,
+	) -> Result<Value, Error> {
+		// Check the loop data
+		match self.range.compute(ctx, opt, txn, doc).await? {
+			Value::Array(arr) => {
+				// Loop over the values
+				for v in arr.into_iter() {
+					// Check if the query has been cancelled
+					if ctx.is_done() {
+						break;
+					}
+					// Duplicate context
+					let mut ctx = Context::new(ctx);
+					// Set the current parameter
+					ctx.add_value(self.param.to_raw(), v);
+					// Process the block
+					match self.block.compute(&ctx, opt, txn, doc).await {
+						Err(Error::Continue) => continue,
+						Err(Error::Break) => break,
+						Err(e) => return Err(e),
+						Ok(_) => (),
+					}
+				}
+				// Ok all good
+				Ok(Value::None)
+			}
+			v => Err(Error::ForeachStatement {
+				value: v.to_string(),
+			}),
+		}
+	}
+}
+
+impl Display for ForeachStatement {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "FOR {} IN {} {}", self.param, self.range, self.block)
+	}
+}
+
+pub fn foreach(i: &str) -> IResult<&str, ForeachStatement> {
+	let (i, _) = tag_no_case("FOR")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, param) = param(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("IN")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, range) = value(i)?;
+	let (i, _) = mightbespace(i)?;
+	let (i, block) = block(i)?;
+	Ok((
+		i,
+		ForeachStatement {
+			param,
+			range,
+			block,
+		},
+	))
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn foreach_statement_first() {
+		let sql = "FOR $test IN [1, 2, 3, 4, 5] { UPDATE person:test SET scores += $test; }";
+		let res = foreach(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(sql, format!("{}", out))
+	}
+
+	#[test]
+	fn foreach_statement_subquery() {
+		let sql =
+			"FOR $test IN (SELECT * FROM person) { IF $test.age < 18 THEN { CONTINUE; } END; }";
+		let res = foreach(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(sql, format!("{}", out))
+	}
+}
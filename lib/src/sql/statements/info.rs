@@ -44,12 +44,18 @@ impl InfoStatement {
 				let mut run = run.lock().await;
 				// Create the result set
 				let mut res = Object::default();
-				// Process the statement
+				// Process the namespaces
 				let mut tmp = Object::default();
 				for v in run.all_ns().await?.iter() {
 					tmp.insert(v.name.to_string(), v.to_string().into());
 				}
 				res.insert("ns".to_owned(), tmp.into());
+				// Process the users
+				let mut tmp = Object::default();
+				for v in run.all_us().await?.iter() {
+					tmp.insert(v.name.to_string(), v.to_string().into());
+				}
+				res.insert("us".to_owned(), tmp.into());
 				// Ok all good
 				Value::from(res).ok()
 			}
@@ -70,7 +76,7 @@ impl InfoStatement {
 					tmp.insert(v.name.to_string(), v.to_string().into());
 				}
 				res.insert("db".to_owned(), tmp.into());
-				// Process the logins
+				// Process the users
 				let mut tmp = Object::default();
 				for v in run.all_nl(opt.ns()).await?.iter() {
 					tmp.insert(v.name.to_string(), v.to_string().into());
@@ -96,7 +102,13 @@ impl InfoStatement {
 				let mut run = run.lock().await;
 				// Create the result set
 				let mut res = Object::default();
-				// Process the logins
+				// Process the analyzers
+				let mut tmp = Object::default();
+				for v in run.all_az(opt.ns(), opt.db()).await?.iter() {
+					tmp.insert(v.name.to_string(), v.to_string().into());
+				}
+				res.insert("az".to_owned(), tmp.into());
+				// Process the users
 				let mut tmp = Object::default();
 				for v in run.all_dl(opt.ns(), opt.db()).await?.iter() {
 					tmp.insert(v.name.to_string(), v.to_string().into());
@@ -10,6 +10,7 @@ use crate::sql::comment::shouldbespace;
 use crate::sql::data::{single, update, values, Data};
 use crate::sql::error::IResult;
 use crate::sql::output::{output, Output};
+use crate::sql::role::Role;
 use crate::sql::table::{table, Table};
 use crate::sql::timeout::{timeout, Timeout};
 use crate::sql::value::Value;
@@ -55,7 +56,7 @@ impl InsertStatement {
 		// Selected DB?
 		opt.needs(Level::Db)?;
 		// Allowed to run?
-		opt.check(Level::No)?;
+		opt.check_role(Level::No, Role::Editor)?;
 		// Create a new iterator
 		let mut i = Iterator::new();
 		// Ensure futures are stored
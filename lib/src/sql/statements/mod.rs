@@ -1,9 +1,12 @@
 pub(crate) mod begin;
+pub(crate) mod r#break;
 pub(crate) mod cancel;
 pub(crate) mod commit;
+pub(crate) mod r#continue;
 pub(crate) mod create;
 pub(crate) mod define;
 pub(crate) mod delete;
+pub(crate) mod foreach;
 pub(crate) mod ifelse;
 pub(crate) mod info;
 pub(crate) mod insert;
@@ -15,7 +18,9 @@ pub(crate) mod relate;
 pub(crate) mod remove;
 pub(crate) mod select;
 pub(crate) mod set;
+pub(crate) mod show;
 pub(crate) mod sleep;
+pub(crate) mod throw;
 pub(crate) mod update;
 pub(crate) mod yuse;
 
@@ -24,6 +29,7 @@ pub use self::cancel::CancelStatement;
 pub use self::commit::CommitStatement;
 pub use self::create::CreateStatement;
 pub use self::delete::DeleteStatement;
+pub use self::foreach::ForeachStatement;
 pub use self::ifelse::IfelseStatement;
 pub use self::info::InfoStatement;
 pub use self::insert::InsertStatement;
@@ -31,34 +37,40 @@ pub use self::kill::KillStatement;
 pub use self::live::LiveStatement;
 pub use self::option::OptionStatement;
 pub use self::output::OutputStatement;
+pub use self::r#break::BreakStatement;
+pub use self::r#continue::ContinueStatement;
 pub use self::relate::RelateStatement;
 pub use self::select::SelectStatement;
 pub use self::set::SetStatement;
+pub use self::show::ShowStatement;
+pub use self::throw::ThrowStatement;
 pub use self::update::UpdateStatement;
 pub use self::yuse::UseStatement;
 
+pub use self::define::DefineAnalyzerStatement;
 pub use self::define::DefineDatabaseStatement;
 pub use self::define::DefineEventStatement;
 pub use self::define::DefineFieldStatement;
 pub use self::define::DefineFunctionStatement;
 pub use self::define::DefineIndexStatement;
-pub use self::define::DefineLoginStatement;
 pub use self::define::DefineNamespaceStatement;
 pub use self::define::DefineParamStatement;
 pub use self::define::DefineScopeStatement;
 pub use self::define::DefineStatement;
 pub use self::define::DefineTableStatement;
 pub use self::define::DefineTokenStatement;
+pub use self::define::DefineUserStatement;
 
+pub use self::remove::RemoveAnalyzerStatement;
 pub use self::remove::RemoveDatabaseStatement;
 pub use self::remove::RemoveEventStatement;
 pub use self::remove::RemoveFieldStatement;
 pub use self::remove::RemoveFunctionStatement;
 pub use self::remove::RemoveIndexStatement;
-pub use self::remove::RemoveLoginStatement;
 pub use self::remove::RemoveNamespaceStatement;
 pub use self::remove::RemoveParamStatement;
 pub use self::remove::RemoveScopeStatement;
 pub use self::remove::RemoveStatement;
 pub use self::remove::RemoveTableStatement;
 pub use self::remove::RemoveTokenStatement;
+pub use self::remove::RemoveUserStatement;
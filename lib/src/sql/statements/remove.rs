@@ -3,13 +3,14 @@ use crate::dbs::Level;
 use crate::dbs::Options;
 use crate::dbs::Transaction;
 use crate::err::Error;
-use crate::sql::base::{base, base_or_scope, Base};
+use crate::sql::base::{base_or_root, base_or_scope, Base};
 use crate::sql::comment::{mightbespace, shouldbespace};
 use crate::sql::error::IResult;
 use crate::sql::ident;
 use crate::sql::ident::{ident, Ident};
 use crate::sql::idiom;
 use crate::sql::idiom::Idiom;
+use crate::sql::role::Role;
 use crate::sql::value::Value;
 use derive::Store;
 use nom::branch::alt;
@@ -27,10 +28,11 @@ pub enum RemoveStatement {
 	Namespace(RemoveNamespaceStatement),
 	Database(RemoveDatabaseStatement),
 	Function(RemoveFunctionStatement),
-	Login(RemoveLoginStatement),
+	User(RemoveUserStatement),
 	Token(RemoveTokenStatement),
 	Scope(RemoveScopeStatement),
 	Param(RemoveParamStatement),
+	Analyzer(RemoveAnalyzerStatement),
 	Table(RemoveTableStatement),
 	Event(RemoveEventStatement),
 	Field(RemoveFieldStatement),
@@ -49,10 +51,11 @@ impl RemoveStatement {
 			Self::Namespace(ref v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Database(ref v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Function(ref v) => v.compute(ctx, opt, txn, doc).await,
-			Self::Login(ref v) => v.compute(ctx, opt, txn, doc).await,
+			Self::User(ref v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Token(ref v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Scope(ref v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Param(ref v) => v.compute(ctx, opt, txn, doc).await,
+			Self::Analyzer(ref v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Table(ref v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Event(ref v) => v.compute(ctx, opt, txn, doc).await,
 			Self::Field(ref v) => v.compute(ctx, opt, txn, doc).await,
@@ -67,10 +70,11 @@ impl Display for RemoveStatement {
 			Self::Namespace(v) => Display::fmt(v, f),
 			Self::Database(v) => Display::fmt(v, f),
 			Self::Function(v) => Display::fmt(v, f),
-			Self::Login(v) => Display::fmt(v, f),
+			Self::User(v) => Display::fmt(v, f),
 			Self::Token(v) => Display::fmt(v, f),
 			Self::Scope(v) => Display::fmt(v, f),
 			Self::Param(v) => Display::fmt(v, f),
+			Self::Analyzer(v) => Display::fmt(v, f),
 			Self::Table(v) => Display::fmt(v, f),
 			Self::Event(v) => Display::fmt(v, f),
 			Self::Field(v) => Display::fmt(v, f),
@@ -84,10 +88,11 @@ pub fn remove(i: &str) -> IResult<&str, RemoveStatement> {
 		map(namespace, RemoveStatement::Namespace),
 		map(database, RemoveStatement::Database),
 		map(function, RemoveStatement::Function),
-		map(login, RemoveStatement::Login),
+		map(user, RemoveStatement::User),
 		map(token, RemoveStatement::Token),
 		map(scope, RemoveStatement::Scope),
 		map(param, RemoveStatement::Param),
+		map(analyzer, RemoveStatement::Analyzer),
 		map(table, RemoveStatement::Table),
 		map(event, RemoveStatement::Event),
 		map(field, RemoveStatement::Field),
@@ -116,7 +121,7 @@ impl RemoveNamespaceStatement {
 		// No need for NS/DB
 		opt.needs(Level::Kv)?;
 		// Allowed to run?
-		opt.check(Level::Kv)?;
+		opt.check_role(Level::Kv, Role::Editor)?;
 		// Clone transaction
 		let run = txn.clone();
 		// Claim transaction
@@ -173,7 +178,7 @@ impl RemoveDatabaseStatement {
 		// Selected NS?
 		opt.needs(Level::Ns)?;
 		// Allowed to run?
-		opt.check(Level::Ns)?;
+		opt.check_role(Level::Ns, Role::Editor)?;
 		// Clone transaction
 		let run = txn.clone();
 		// Claim transaction
@@ -230,7 +235,7 @@ impl RemoveFunctionStatement {
 		// Selected DB?
 		opt.needs(Level::Db)?;
 		// Allowed to run?
-		opt.check(Level::Db)?;
+		opt.check_role(Level::Db, Role::Editor)?;
 		// Clone transaction
 		let run = txn.clone();
 		// Claim transaction
@@ -277,12 +282,12 @@ fn function(i: &str) -> IResult<&str, RemoveFunctionStatement> {
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Store, Hash)]
 #[format(Named)]
-pub struct RemoveLoginStatement {
+pub struct RemoveUserStatement {
 	pub name: Ident,
 	pub base: Base,
 }
 
-impl RemoveLoginStatement {
+impl RemoveUserStatement {
 	pub(crate) async fn compute(
 		&self,
 		_ctx: &Context<'_>,
@@ -291,11 +296,24 @@ impl RemoveLoginStatement {
 		_doc: Option<&Value>,
 	) -> Result<Value, Error> {
 		match self.base {
+			Base::Kv => {
+				// Allowed to run?
+				opt.check_role(Level::Kv, Role::Owner)?;
+				// Clone transaction
+				let run = txn.clone();
+				// Claim transaction
+				let mut run = run.lock().await;
+				// Delete the definition
+				let key = crate::key::us::new(&self.name);
+				run.del(key).await?;
+				// Ok all good
+				Ok(Value::None)
+			}
 			Base::Ns => {
 				// Selected NS?
 				opt.needs(Level::Ns)?;
 				// Allowed to run?
-				opt.check(Level::Kv)?;
+				opt.check_role(Level::Ns, Role::Owner)?;
 				// Clone transaction
 				let run = txn.clone();
 				// Claim transaction
@@ -310,7 +328,7 @@ impl RemoveLoginStatement {
 				// Selected DB?
 				opt.needs(Level::Db)?;
 				// Allowed to run?
-				opt.check(Level::Ns)?;
+				opt.check_role(Level::Db, Role::Owner)?;
 				// Clone transaction
 				let run = txn.clone();
 				// Claim transaction
@@ -326,25 +344,25 @@ impl RemoveLoginStatement {
 	}
 }
 
-impl fmt::Display for RemoveLoginStatement {
+impl fmt::Display for RemoveUserStatement {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		write!(f, "REMOVE LOGIN {} ON {}", self.name, self.base)
+		write!(f, "REMOVE USER {} ON {}", self.name, self.base)
 	}
 }
 
-fn login(i: &str) -> IResult<&str, RemoveLoginStatement> {
+fn user(i: &str) -> IResult<&str, RemoveUserStatement> {
 	let (i, _) = tag_no_case("REMOVE")(i)?;
 	let (i, _) = shouldbespace(i)?;
-	let (i, _) = tag_no_case("LOGIN")(i)?;
+	let (i, _) = alt((tag_no_case("USER"), tag_no_case("LOGIN")))(i)?;
 	let (i, _) = shouldbespace(i)?;
 	let (i, name) = ident(i)?;
 	let (i, _) = shouldbespace(i)?;
 	let (i, _) = tag_no_case("ON")(i)?;
 	let (i, _) = shouldbespace(i)?;
-	let (i, base) = base(i)?;
+	let (i, base) = base_or_root(i)?;
 	Ok((
 		i,
-		RemoveLoginStatement {
+		RemoveUserStatement {
 			name,
 			base,
 		},
@@ -375,7 +393,7 @@ impl RemoveTokenStatement {
 				// Selected NS?
 				opt.needs(Level::Ns)?;
 				// Allowed to run?
-				opt.check(Level::Kv)?;
+				opt.check_role(Level::Kv, Role::Owner)?;
 				// Clone transaction
 				let run = txn.clone();
 				// Claim transaction
@@ -390,7 +408,7 @@ impl RemoveTokenStatement {
 				// Selected DB?
 				opt.needs(Level::Db)?;
 				// Allowed to run?
-				opt.check(Level::Ns)?;
+				opt.check_role(Level::Ns, Role::Owner)?;
 				// Clone transaction
 				let run = txn.clone();
 				// Claim transaction
@@ -405,7 +423,7 @@ impl RemoveTokenStatement {
 				// Selected DB?
 				opt.needs(Level::Db)?;
 				// Allowed to run?
-				opt.check(Level::Db)?;
+				opt.check_role(Level::Db, Role::Owner)?;
 				// Clone transaction
 				let run = txn.clone();
 				// Claim transaction
@@ -467,7 +485,7 @@ impl RemoveScopeStatement {
 		// Selected DB?
 		opt.needs(Level::Db)?;
 		// Allowed to run?
-		opt.check(Level::Db)?;
+		opt.check_role(Level::Db, Role::Editor)?;
 		// Clone transaction
 		let run = txn.clone();
 		// Claim transaction
@@ -524,7 +542,7 @@ impl RemoveParamStatement {
 		// Selected DB?
 		opt.needs(Level::Db)?;
 		// Allowed to run?
-		opt.check(Level::Db)?;
+		opt.check_role(Level::Db, Role::Editor)?;
 		// Clone transaction
 		let run = txn.clone();
 		// Claim transaction
@@ -562,6 +580,60 @@ fn param(i: &str) -> IResult<&str, RemoveParamStatement> {
 // --------------------------------------------------
 // --------------------------------------------------
 
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Store, Hash)]
+#[format(Named)]
+pub struct RemoveAnalyzerStatement {
+	pub name: Ident,
+}
+
+impl RemoveAnalyzerStatement {
+	pub(crate) async fn compute(
+		&self,
+		_ctx: &Context<'_>,
+		opt: &Options,
+		txn: &Transaction,
+		_doc: Option<&Value>,
+	) -> Result<Value, Error> {
+		// Selected DB?
+		opt.needs(Level::Db)?;
+		// Allowed to run?
+		opt.check_role(Level::Db, Role::Editor)?;
+		// Clone transaction
+		let run = txn.clone();
+		// Claim transaction
+		let mut run = run.lock().await;
+		// Delete the definition
+		let key = crate::key::az::new(opt.ns(), opt.db(), &self.name);
+		run.del(key).await?;
+		// Ok all good
+		Ok(Value::None)
+	}
+}
+
+impl fmt::Display for RemoveAnalyzerStatement {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "REMOVE ANALYZER {}", self.name)
+	}
+}
+
+fn analyzer(i: &str) -> IResult<&str, RemoveAnalyzerStatement> {
+	let (i, _) = tag_no_case("REMOVE")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("ANALYZER")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, name) = ident(i)?;
+	Ok((
+		i,
+		RemoveAnalyzerStatement {
+			name,
+		},
+	))
+}
+
+// --------------------------------------------------
+// --------------------------------------------------
+// --------------------------------------------------
+
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Store, Hash)]
 #[format(Named)]
 pub struct RemoveTableStatement {
@@ -579,7 +651,7 @@ impl RemoveTableStatement {
 		// Selected DB?
 		opt.needs(Level::Db)?;
 		// Allowed to run?
-		opt.check(Level::Db)?;
+		opt.check_role(Level::Db, Role::Editor)?;
 		// Clone transaction
 		let run = txn.clone();
 		// Claim transaction
@@ -637,7 +709,7 @@ impl RemoveEventStatement {
 		// Selected DB?
 		opt.needs(Level::Db)?;
 		// Allowed to run?
-		opt.check(Level::Db)?;
+		opt.check_role(Level::Db, Role::Editor)?;
 		// Clone transaction
 		let run = txn.clone();
 		// Claim transaction
@@ -701,7 +773,7 @@ impl RemoveFieldStatement {
 		// Selected DB?
 		opt.needs(Level::Db)?;
 		// Allowed to run?
-		opt.check(Level::Db)?;
+		opt.check_role(Level::Db, Role::Editor)?;
 		// Clone transaction
 		let run = txn.clone();
 		// Claim transaction
@@ -766,7 +838,7 @@ impl RemoveIndexStatement {
 		// Selected DB?
 		opt.needs(Level::Db)?;
 		// Allowed to run?
-		opt.check(Level::Db)?;
+		opt.check_role(Level::Db, Role::Editor)?;
 		// Clone transaction
 		let run = txn.clone();
 		// Claim transaction
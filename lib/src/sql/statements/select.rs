@@ -6,19 +6,25 @@ use crate::dbs::Options;
 use crate::dbs::Statement;
 use crate::dbs::Transaction;
 use crate::err::Error;
+use crate::idx::planner::QueryPlanner;
+use crate::sql::after::{after, After};
 use crate::sql::comment::shouldbespace;
 use crate::sql::cond::{cond, Cond};
 use crate::sql::error::IResult;
+use crate::sql::explain::{explain, Explain};
 use crate::sql::fetch::{fetch, Fetchs};
 use crate::sql::field::{fields, Field, Fields};
 use crate::sql::group::{group, Groups};
 use crate::sql::limit::{limit, Limit};
 use crate::sql::order::{order, Orders};
+use crate::sql::range::Range;
 use crate::sql::special::check_group_by_fields;
 use crate::sql::special::check_order_by_fields;
 use crate::sql::special::check_split_on_fields;
 use crate::sql::split::{split, Splits};
 use crate::sql::start::{start, Start};
+use crate::sql::table::Table;
+use crate::sql::thing::Thing;
 use crate::sql::timeout::{timeout, Timeout};
 use crate::sql::value::{selects, Value, Values};
 use crate::sql::version::{version, Version};
@@ -28,6 +34,7 @@ use nom::combinator::opt;
 use nom::sequence::preceded;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::ops::Bound;
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Store, Hash)]
 pub struct SelectStatement {
@@ -39,10 +46,12 @@ pub struct SelectStatement {
 	pub order: Option<Orders>,
 	pub limit: Option<Limit>,
 	pub start: Option<Start>,
+	pub after: Option<After>,
 	pub fetch: Option<Fetchs>,
 	pub version: Option<Version>,
 	pub timeout: Option<Timeout>,
 	pub parallel: bool,
+	pub explain: Option<Explain>,
 }
 
 impl SelectStatement {
@@ -83,13 +92,44 @@ impl SelectStatement {
 		let mut i = Iterator::new();
 		// Ensure futures are stored
 		let opt = &opt.futures(false);
+		// Process any AFTER clause
+		let after = match &self.after {
+			Some(v) => {
+				// Records are only output in id order without these clauses
+				let clause = match (&self.split, &self.group, &self.order) {
+					(Some(_), _, _) => Some("SPLIT ON"),
+					(_, Some(_), _) => Some("GROUP BY"),
+					(_, _, Some(_)) => Some("ORDER BY"),
+					_ => None,
+				};
+				if let Some(clause) = clause {
+					return Err(Error::AfterUnsupported {
+						clause: clause.to_owned(),
+					});
+				}
+				Some(v.process(ctx, opt, txn, doc).await?)
+			}
+			None => None,
+		};
+		// Create a new query planner
+		let mut planner = match self.version {
+			// Indexes only reflect the current data
+			Some(_) => QueryPlanner::new(opt, &None),
+			None => QueryPlanner::new(opt, &self.cond),
+		};
 		// Loop over the select targets
 		for w in self.what.0.iter() {
 			let v = w.compute(ctx, opt, txn, doc).await?;
 			match v {
-				Value::Table(v) => i.ingest(Iterable::Table(v)),
+				Value::Table(v) => match self.resume(&v, after.as_ref()) {
+					Some(r) => i.ingest(Iterable::Range(r)),
+					None => i.ingest(planner.get_iterable(ctx, txn, v).await?),
+				},
 				Value::Thing(v) => i.ingest(Iterable::Thing(v)),
-				Value::Range(v) => i.ingest(Iterable::Range(*v)),
+				Value::Range(v) => match &after {
+					Some(t) if t.tb == v.tb => i.ingest(Iterable::Range(v.after(&t.id))),
+					_ => i.ingest(Iterable::Range(*v)),
+				},
 				Value::Edges(v) => i.ingest(Iterable::Edges(*v)),
 				Value::Model(v) => {
 					for v in v {
@@ -99,7 +139,10 @@ impl SelectStatement {
 				Value::Array(v) => {
 					for v in v {
 						match v {
-							Value::Table(v) => i.ingest(Iterable::Table(v)),
+							Value::Table(v) => match self.resume(&v, after.as_ref()) {
+								Some(r) => i.ingest(Iterable::Range(r)),
+								None => i.ingest(planner.get_iterable(ctx, txn, v).await?),
+							},
 							Value::Thing(v) => i.ingest(Iterable::Thing(v)),
 							Value::Edges(v) => i.ingest(Iterable::Edges(*v)),
 							Value::Model(v) => {
@@ -114,10 +157,32 @@ impl SelectStatement {
 				v => i.ingest(Iterable::Value(v)),
 			};
 		}
+		// Stream the results if enabled
+		if let Some(chn) = ctx.get_stream() {
+			i.stream(chn.clone());
+		}
+		// Attach the full-text search results
+		let mut ctx = Context::new(ctx);
+		if let Some(exe) = planner.executor() {
+			ctx.set_query_executor(exe);
+		}
 		// Assign the statement
 		let stm = Statement::from(self);
 		// Output the results
-		i.output(ctx, opt, txn, &stm).await
+		i.output(&ctx, opt, txn, &stm).await
+	}
+
+	/// Resumes a table scan after the AFTER clause cursor, so that the
+	/// records before the cursor do not need to be fetched and skipped
+	fn resume(&self, tb: &Table, after: Option<&Thing>) -> Option<Range> {
+		match after {
+			Some(t) if t.tb == tb.0 => Some(Range {
+				tb: t.tb.clone(),
+				beg: Bound::Excluded(t.id.clone()),
+				end: Bound::Unbounded,
+			}),
+			_ => None,
+		}
 	}
 }
 
@@ -142,6 +207,9 @@ impl fmt::Display for SelectStatement {
 		if let Some(ref v) = self.start {
 			write!(f, " {v}")?
 		}
+		if let Some(ref v) = self.after {
+			write!(f, " {v}")?
+		}
 		if let Some(ref v) = self.fetch {
 			write!(f, " {v}")?
 		}
@@ -154,6 +222,9 @@ impl fmt::Display for SelectStatement {
 		if self.parallel {
 			f.write_str(" PARALLEL")?
 		}
+		if let Some(ref v) = self.explain {
+			write!(f, " {v}")?
+		}
 		Ok(())
 	}
 }
@@ -175,10 +246,12 @@ pub fn select(i: &str) -> IResult<&str, SelectStatement> {
 	check_order_by_fields(i, &expr, &order)?;
 	let (i, limit) = opt(preceded(shouldbespace, limit))(i)?;
 	let (i, start) = opt(preceded(shouldbespace, start))(i)?;
+	let (i, after) = opt(preceded(shouldbespace, after))(i)?;
 	let (i, fetch) = opt(preceded(shouldbespace, fetch))(i)?;
 	let (i, version) = opt(preceded(shouldbespace, version))(i)?;
 	let (i, timeout) = opt(preceded(shouldbespace, timeout))(i)?;
 	let (i, parallel) = opt(preceded(shouldbespace, tag_no_case("PARALLEL")))(i)?;
+	let (i, explain) = opt(preceded(shouldbespace, explain))(i)?;
 	Ok((
 		i,
 		SelectStatement {
@@ -190,10 +263,12 @@ pub fn select(i: &str) -> IResult<&str, SelectStatement> {
 			order,
 			limit,
 			start,
+			after,
 			fetch,
 			version,
 			timeout,
 			parallel: parallel.is_some(),
+			explain,
 		},
 	))
 }
@@ -247,4 +322,22 @@ mod tests {
 		let out = res.unwrap().1;
 		assert_eq!(sql, format!("{}", out))
 	}
+
+	#[test]
+	fn select_statement_explain() {
+		let sql = "SELECT * FROM test WHERE name = 'Tobie' PARALLEL EXPLAIN FULL";
+		let res = select(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(sql, format!("{}", out))
+	}
+
+	#[test]
+	fn select_statement_after() {
+		let sql = "SELECT * FROM test LIMIT 10 AFTER test:thingy";
+		let res = select(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(sql, format!("{}", out))
+	}
 }
@@ -0,0 +1,323 @@
+use crate::ctx::Context;
+use crate::dbs::Level;
+use crate::dbs::Options;
+use crate::dbs::Transaction;
+use crate::err::Error;
+use crate::kvs;
+use crate::sql::comment::shouldbespace;
+use crate::sql::datetime::{datetime, Datetime};
+use crate::sql::error::IResult;
+use crate::sql::ident::{ident, Ident};
+use crate::sql::value::Value;
+use derive::Store;
+use nom::branch::alt;
+use nom::bytes::complete::tag_no_case;
+use nom::character::complete::char;
+use nom::character::complete::u32;
+use nom::character::complete::u64;
+use nom::combinator::{map, opt};
+use nom::sequence::preceded;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash)]
+pub enum ShowSince {
+	Timestamp(Datetime),
+	Versionstamp(u64),
+}
+
+impl fmt::Display for ShowSince {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Timestamp(v) => write!(f, "{v}"),
+			Self::Versionstamp(v) => write!(f, "{v}"),
+		}
+	}
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash)]
+pub enum ShowKind {
+	/// The change feed of the table
+	Changes,
+	/// The audit trail of the table
+	Audit,
+}
+
+impl fmt::Display for ShowKind {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Changes => f.write_str("CHANGES"),
+			Self::Audit => f.write_str("AUDIT"),
+		}
+	}
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Store, Hash)]
+pub struct ShowStatement {
+	pub kind: ShowKind,
+	pub table: Ident,
+	pub since: ShowSince,
+	pub limit: Option<u32>,
+}
+
+impl ShowStatement {
+	pub(crate) async fn compute(
+		&self,
+		ctx: &Context<'_>,
+		opt: &Options,
+		txn: &Transaction,
+		_doc: Option<&Value>,
+	) -> Result<Value, Error> {
+		// Selected DB?
+		opt.needs(Level::Db)?;
+		// Allowed to run?
+		opt.check(Level::Db)?;
+		// Clone transaction
+		let run = txn.clone();
+		// Claim transaction
+		let mut run = run.lock().await;
+		// Check that the table exists
+		run.get_tb(opt.ns(), opt.db(), &self.table).await?;
+		// Output the audit trail
+		if self.kind == ShowKind::Audit {
+			return self.audit(ctx, opt, &mut run).await;
+		}
+		// Prepare the start and end keys
+		let beg = match self.since {
+			ShowSince::Versionstamp(vs) => {
+				crate::key::cf::new(opt.ns(), opt.db(), &self.table, vs).into()
+			}
+			ShowSince::Timestamp(_) => crate::key::cf::prefix(opt.ns(), opt.db(), &self.table),
+		};
+		let end = crate::key::cf::suffix(opt.ns(), opt.db(), &self.table);
+		// Prepare the next holder key
+		let mut nxt: Option<Vec<u8>> = None;
+		// Get the maximum number of entries
+		let mut num = self.limit.unwrap_or(u32::MAX);
+		// Create the result set
+		let mut out: Vec<Value> = vec![];
+		// Loop until no more keys
+		while num > 0 {
+			// Check if the context is finished
+			if ctx.is_done() {
+				break;
+			}
+			// Get the next 1000 key-value entries
+			let res = match nxt {
+				None => {
+					let min = beg.clone();
+					let max = end.clone();
+					run.scan(min..max, 1000).await?
+				}
+				Some(ref mut beg) => {
+					beg.push(0x00);
+					let min = beg.clone();
+					let max = end.clone();
+					run.scan(min..max, 1000).await?
+				}
+			};
+			// Get total results
+			let n = res.len();
+			// Exit when settled
+			if n == 0 {
+				break;
+			}
+			// Loop over results
+			for (i, (k, v)) in res.into_iter().enumerate() {
+				// Ready the next
+				if n == i + 1 {
+					nxt = Some(k.clone());
+				}
+				// Parse the data from the store
+				let key: crate::key::cf::Cf = (&k).into();
+				let mut val = match Value::from(v) {
+					Value::Object(v) => v,
+					_ => continue,
+				};
+				// Ignore changes made before the timestamp
+				if let ShowSince::Timestamp(ts) = &self.since {
+					match val.get("timestamp") {
+						Some(Value::Datetime(v)) if v >= ts => {}
+						_ => continue,
+					}
+				}
+				// Output the changes at this versionstamp
+				out.push(Value::from(map! {
+					String::from("versionstamp") => Value::from(key.vs),
+					String::from("changes") => val.remove("changes").unwrap_or_default(),
+				}));
+				// Check the limit
+				num -= 1;
+				if num == 0 {
+					break;
+				}
+			}
+		}
+		// Ok all good
+		Ok(out.into())
+	}
+}
+
+impl ShowStatement {
+	/// Outputs the audit records of the table, in the order they were written
+	async fn audit(
+		&self,
+		ctx: &Context<'_>,
+		opt: &Options,
+		run: &mut kvs::Transaction,
+	) -> Result<Value, Error> {
+		// Prepare the start and end keys
+		let beg = match self.since {
+			ShowSince::Versionstamp(sq) => {
+				crate::key::au::prefix_sq(opt.ns(), opt.db(), &self.table, sq)
+			}
+			ShowSince::Timestamp(_) => crate::key::au::prefix(opt.ns(), opt.db(), &self.table),
+		};
+		let end = crate::key::au::suffix(opt.ns(), opt.db(), &self.table);
+		// Prepare the next holder key
+		let mut nxt: Option<Vec<u8>> = None;
+		// Get the maximum number of entries
+		let mut num = self.limit.unwrap_or(u32::MAX);
+		// Create the result set
+		let mut out: Vec<Value> = vec![];
+		// Loop until no more keys
+		while num > 0 {
+			// Check if the context is finished
+			if ctx.is_done() {
+				break;
+			}
+			// Get the next 1000 key-value entries
+			let min = match nxt {
+				None => beg.clone(),
+				Some(ref mut beg) => {
+					beg.push(0x00);
+					beg.clone()
+				}
+			};
+			let res = run.scan(min..end.clone(), 1000).await?;
+			// Get total results
+			let n = res.len();
+			// Exit when settled
+			if n == 0 {
+				break;
+			}
+			// Loop over results
+			for (i, (k, v)) in res.into_iter().enumerate() {
+				// Ready the next
+				if n == i + 1 {
+					nxt = Some(k);
+				}
+				// Parse the data from the store
+				let val = Value::from(v);
+				// Ignore records written before the timestamp
+				if let ShowSince::Timestamp(ts) = &self.since {
+					match val.pick(&["timestamp".into()]) {
+						Value::Datetime(v) if &v >= ts => {}
+						_ => continue,
+					}
+				}
+				// Output the audit record
+				out.push(val);
+				// Check the limit
+				num -= 1;
+				if num == 0 {
+					break;
+				}
+			}
+		}
+		// Ok all good
+		Ok(out.into())
+	}
+}
+
+impl fmt::Display for ShowStatement {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "SHOW {} FOR TABLE {} SINCE {}", self.kind, self.table, self.since)?;
+		if let Some(ref v) = self.limit {
+			write!(f, " LIMIT {v}")?
+		}
+		Ok(())
+	}
+}
+
+pub fn show(i: &str) -> IResult<&str, ShowStatement> {
+	let (i, _) = tag_no_case("SHOW")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, kind) = alt((
+		map(tag_no_case("CHANGES"), |_| ShowKind::Changes),
+		map(tag_no_case("AUDIT"), |_| ShowKind::Audit),
+	))(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("FOR")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = alt((tag_no_case("TABLE"), tag_no_case("TB")))(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, table) = ident(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, _) = tag_no_case("SINCE")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	let (i, since) = alt((
+		map(preceded(opt(char('d')), datetime), ShowSince::Timestamp),
+		map(u64, ShowSince::Versionstamp),
+	))(i)?;
+	let (i, limit) = opt(preceded(
+		shouldbespace,
+		preceded(tag_no_case("LIMIT"), preceded(shouldbespace, u32)),
+	))(i)?;
+	Ok((
+		i,
+		ShowStatement {
+			kind,
+			table,
+			since,
+			limit,
+		},
+	))
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn show_changes_since_versionstamp() {
+		let sql = "SHOW CHANGES FOR TABLE person SINCE 10 LIMIT 5";
+		let res = show(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(out.since, ShowSince::Versionstamp(10));
+		assert_eq!(out.limit, Some(5));
+		assert_eq!("SHOW CHANGES FOR TABLE person SINCE 10 LIMIT 5", format!("{}", out))
+	}
+
+	#[test]
+	fn show_changes_since_timestamp() {
+		let sql = "SHOW CHANGES FOR TABLE person SINCE d\"2023-01-01T00:00:00Z\"";
+		let res = show(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(
+			out.since,
+			ShowSince::Timestamp(Datetime::try_from("2023-01-01T00:00:00Z").unwrap())
+		);
+		assert_eq!(out.limit, None);
+		assert_eq!("SHOW CHANGES FOR TABLE person SINCE '2023-01-01T00:00:00Z'", format!("{}", out))
+	}
+
+	#[test]
+	fn show_audit_since_timestamp() {
+		let sql = "SHOW AUDIT FOR TABLE person SINCE '2023-01-01T00:00:00Z' LIMIT 10";
+		let res = show(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(out.kind, ShowKind::Audit);
+		assert_eq!(out.limit, Some(10));
+		assert_eq!(
+			"SHOW AUDIT FOR TABLE person SINCE '2023-01-01T00:00:00Z' LIMIT 10",
+			format!("{}", out)
+		);
+		let out = show("SHOW AUDIT FOR TABLE person SINCE 10").unwrap().1;
+		assert_eq!(out.since, ShowSince::Versionstamp(10));
+	}
+}
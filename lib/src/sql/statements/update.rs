@@ -6,11 +6,13 @@ use crate::dbs::Options;
 use crate::dbs::Statement;
 use crate::dbs::Transaction;
 use crate::err::Error;
+use crate::idx::planner::QueryPlanner;
 use crate::sql::comment::shouldbespace;
 use crate::sql::cond::{cond, Cond};
 use crate::sql::data::{data, Data};
 use crate::sql::error::IResult;
 use crate::sql::output::{output, Output};
+use crate::sql::role::Role;
 use crate::sql::timeout::{timeout, Timeout};
 use crate::sql::value::{whats, Value, Values};
 use derive::Store;
@@ -53,16 +55,18 @@ impl UpdateStatement {
 		// Selected DB?
 		opt.needs(Level::Db)?;
 		// Allowed to run?
-		opt.check(Level::No)?;
+		opt.check_role(Level::No, Role::Editor)?;
 		// Create a new iterator
 		let mut i = Iterator::new();
 		// Ensure futures are stored
 		let opt = &opt.futures(false);
+		// Create a new query planner
+		let mut planner = QueryPlanner::new(opt, &self.cond);
 		// Loop over the update targets
 		for w in self.what.0.iter() {
 			let v = w.compute(ctx, opt, txn, doc).await?;
 			match v {
-				Value::Table(v) => i.ingest(Iterable::Table(v)),
+				Value::Table(v) => i.ingest(planner.get_iterable(ctx, txn, v).await?),
 				Value::Thing(v) => i.ingest(Iterable::Thing(v)),
 				Value::Range(v) => i.ingest(Iterable::Range(*v)),
 				Value::Edges(v) => i.ingest(Iterable::Edges(*v)),
@@ -74,7 +78,7 @@ impl UpdateStatement {
 				Value::Array(v) => {
 					for v in v {
 						match v {
-							Value::Table(v) => i.ingest(Iterable::Table(v)),
+							Value::Table(v) => i.ingest(planner.get_iterable(ctx, txn, v).await?),
 							Value::Thing(v) => i.ingest(Iterable::Thing(v)),
 							Value::Edges(v) => i.ingest(Iterable::Edges(*v)),
 							Value::Model(v) => {
@@ -113,10 +117,15 @@ impl UpdateStatement {
 				}
 			};
 		}
+		// Attach the full-text search results
+		let mut ctx = Context::new(ctx);
+		if let Some(exe) = planner.executor() {
+			ctx.set_query_executor(exe);
+		}
 		// Assign the statement
 		let stm = Statement::from(self);
 		// Output the results
-		i.output(ctx, opt, txn, &stm).await
+		i.output(&ctx, opt, txn, &stm).await
 	}
 }
 
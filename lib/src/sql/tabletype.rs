@@ -0,0 +1,137 @@
+use crate::sql::comment::shouldbespace;
+use crate::sql::common::verbar;
+use crate::sql::error::IResult;
+use crate::sql::fmt::Fmt;
+use crate::sql::table::{table, Table};
+use nom::branch::alt;
+use nom::bytes::complete::tag_no_case;
+use nom::combinator::{map, opt};
+use nom::multi::separated_list1;
+use nom::sequence::preceded;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The kind of records which can be stored in a table
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+pub enum TableType {
+	/// The table can store both normal records and relations
+	#[default]
+	Any,
+	/// The table can only store normal records
+	Normal,
+	/// The table can only store relations
+	Relation(Relation),
+}
+
+/// The tables which the records at either end of a relation must belong to
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+pub struct Relation {
+	pub from: Option<Vec<Table>>,
+	pub to: Option<Vec<Table>>,
+}
+
+impl TableType {
+	/// Checks if this table can store normal records
+	pub(crate) fn allows_normal(&self) -> bool {
+		matches!(self, TableType::Any | TableType::Normal)
+	}
+	/// Checks if this table can store relations
+	pub(crate) fn allows_relation(&self) -> bool {
+		matches!(self, TableType::Any | TableType::Relation(_))
+	}
+}
+
+impl fmt::Display for TableType {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			TableType::Any => f.write_str("TYPE ANY"),
+			TableType::Normal => f.write_str("TYPE NORMAL"),
+			TableType::Relation(v) => {
+				f.write_str("TYPE RELATION")?;
+				if let Some(v) = &v.from {
+					write!(f, " IN {}", Fmt::verbar_separated(v))?;
+				}
+				if let Some(v) = &v.to {
+					write!(f, " OUT {}", Fmt::verbar_separated(v))?;
+				}
+				Ok(())
+			}
+		}
+	}
+}
+
+pub fn tabletype(i: &str) -> IResult<&str, TableType> {
+	let (i, _) = tag_no_case("TYPE")(i)?;
+	let (i, _) = shouldbespace(i)?;
+	alt((
+		map(tag_no_case("ANY"), |_| TableType::Any),
+		map(tag_no_case("NORMAL"), |_| TableType::Normal),
+		map(relation, TableType::Relation),
+	))(i)
+}
+
+fn relation(i: &str) -> IResult<&str, Relation> {
+	let (i, _) = tag_no_case("RELATION")(i)?;
+	let (i, from) = opt(preceded(shouldbespace, relation_in))(i)?;
+	let (i, to) = opt(preceded(shouldbespace, relation_out))(i)?;
+	Ok((
+		i,
+		Relation {
+			from,
+			to,
+		},
+	))
+}
+
+fn relation_in(i: &str) -> IResult<&str, Vec<Table>> {
+	let (i, _) = alt((tag_no_case("IN"), tag_no_case("FROM")))(i)?;
+	let (i, _) = shouldbespace(i)?;
+	separated_list1(verbar, table)(i)
+}
+
+fn relation_out(i: &str) -> IResult<&str, Vec<Table>> {
+	let (i, _) = alt((tag_no_case("OUT"), tag_no_case("TO")))(i)?;
+	let (i, _) = shouldbespace(i)?;
+	separated_list1(verbar, table)(i)
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn tabletype_normal() {
+		let sql = "TYPE NORMAL";
+		let res = tabletype(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!("TYPE NORMAL", format!("{}", out));
+		assert_eq!(out, TableType::Normal);
+	}
+
+	#[test]
+	fn tabletype_relation() {
+		let sql = "TYPE RELATION FROM person | company TO post";
+		let res = tabletype(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!("TYPE RELATION IN person | company OUT post", format!("{}", out));
+		assert_eq!(
+			out,
+			TableType::Relation(Relation {
+				from: Some(vec![Table::from("person"), Table::from("company")]),
+				to: Some(vec![Table::from("post")]),
+			})
+		);
+	}
+
+	#[test]
+	fn tabletype_relation_any() {
+		let sql = "TYPE RELATION";
+		let res = tabletype(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!("TYPE RELATION", format!("{}", out));
+	}
+}
@@ -0,0 +1,58 @@
+use crate::sql::common::commas;
+use crate::sql::error::IResult;
+use nom::branch::alt;
+use nom::bytes::complete::tag_no_case;
+use nom::combinator::map;
+use nom::multi::separated_list1;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash)]
+pub enum Tokenizer {
+	Blank,
+	Camel,
+	Class,
+	Punct,
+}
+
+impl fmt::Display for Tokenizer {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(match self {
+			Self::Blank => "BLANK",
+			Self::Camel => "CAMEL",
+			Self::Class => "CLASS",
+			Self::Punct => "PUNCT",
+		})
+	}
+}
+
+fn tokenizer(i: &str) -> IResult<&str, Tokenizer> {
+	alt((
+		map(tag_no_case("BLANK"), |_| Tokenizer::Blank),
+		map(tag_no_case("CAMEL"), |_| Tokenizer::Camel),
+		map(tag_no_case("CLASS"), |_| Tokenizer::Class),
+		map(tag_no_case("PUNCT"), |_| Tokenizer::Punct),
+	))(i)
+}
+
+pub fn tokenizers(i: &str) -> IResult<&str, Vec<Tokenizer>> {
+	separated_list1(commas, tokenizer)(i)
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	#[test]
+	fn tokenizers_list() {
+		let sql = "blank,CLASS, camel ,punct";
+		let res = tokenizers(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(
+			out,
+			vec![Tokenizer::Blank, Tokenizer::Class, Tokenizer::Camel, Tokenizer::Punct]
+		);
+	}
+}
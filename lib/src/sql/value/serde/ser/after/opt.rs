@@ -0,0 +1,55 @@
+use crate::err::Error;
+use crate::sql::value::serde::ser;
+use crate::sql::After;
+use serde::ser::Impossible;
+use serde::ser::Serialize;
+
+pub struct Serializer;
+
+impl ser::Serializer for Serializer {
+	type Ok = Option<After>;
+	type Error = Error;
+
+	type SerializeSeq = Impossible<Option<After>, Error>;
+	type SerializeTuple = Impossible<Option<After>, Error>;
+	type SerializeTupleStruct = Impossible<Option<After>, Error>;
+	type SerializeTupleVariant = Impossible<Option<After>, Error>;
+	type SerializeMap = Impossible<Option<After>, Error>;
+	type SerializeStruct = Impossible<Option<After>, Error>;
+	type SerializeStructVariant = Impossible<Option<After>, Error>;
+
+	const EXPECTED: &'static str = "an `Option<After>`";
+
+	#[inline]
+	fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+		Ok(None)
+	}
+
+	#[inline]
+	fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
+	where
+		T: ?Sized + Serialize,
+	{
+		Ok(Some(After(value.serialize(ser::value::Serializer.wrap())?)))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use ser::Serializer as _;
+
+	#[test]
+	fn none() {
+		let option: Option<After> = None;
+		let serialized = option.serialize(Serializer.wrap()).unwrap();
+		assert_eq!(option, serialized);
+	}
+
+	#[test]
+	fn some() {
+		let option = Some(After::default());
+		let serialized = option.serialize(Serializer.wrap()).unwrap();
+		assert_eq!(option, serialized);
+	}
+}
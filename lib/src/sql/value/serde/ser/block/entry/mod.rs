@@ -2,6 +2,8 @@ pub mod vec;
 
 use crate::err::Error;
 use crate::sql::block::Entry;
+use crate::sql::statements::BreakStatement;
+use crate::sql::statements::ContinueStatement;
 use crate::sql::value::serde::ser;
 use serde::ser::Error as _;
 use serde::ser::Impossible;
@@ -61,6 +63,12 @@ impl ser::Serializer for Serializer {
 			"Output" => {
 				Ok(Entry::Output(value.serialize(ser::statement::output::Serializer.wrap())?))
 			}
+			"Foreach" => {
+				Ok(Entry::Foreach(value.serialize(ser::statement::foreach::Serializer.wrap())?))
+			}
+			"Break" => Ok(Entry::Break(BreakStatement)),
+			"Continue" => Ok(Entry::Continue(ContinueStatement)),
+			"Throw" => Ok(Entry::Throw(value.serialize(ser::statement::throw::Serializer.wrap())?)),
 			variant => Err(Error::custom(format!("unexpected variant `{name}::{variant}`"))),
 		}
 	}
@@ -141,4 +149,32 @@ mod tests {
 		let serialized = entry.serialize(Serializer.wrap()).unwrap();
 		assert_eq!(entry, serialized);
 	}
+
+	#[test]
+	fn foreach() {
+		let entry = Entry::Foreach(Default::default());
+		let serialized = entry.serialize(Serializer.wrap()).unwrap();
+		assert_eq!(entry, serialized);
+	}
+
+	#[test]
+	fn r#break() {
+		let entry = Entry::Break(BreakStatement);
+		let serialized = entry.serialize(Serializer.wrap()).unwrap();
+		assert_eq!(entry, serialized);
+	}
+
+	#[test]
+	fn r#continue() {
+		let entry = Entry::Continue(ContinueStatement);
+		let serialized = entry.serialize(Serializer.wrap()).unwrap();
+		assert_eq!(entry, serialized);
+	}
+
+	#[test]
+	fn throw() {
+		let entry = Entry::Throw(Default::default());
+		let serialized = entry.serialize(Serializer.wrap()).unwrap();
+		assert_eq!(entry, serialized);
+	}
 }
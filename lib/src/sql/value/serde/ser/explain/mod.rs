@@ -0,0 +1,49 @@
+pub(super) mod opt;
+
+use crate::err::Error;
+use crate::sql::value::serde::ser;
+use serde::ser::Impossible;
+use serde::ser::Serialize;
+
+pub(super) struct Serializer;
+
+impl ser::Serializer for Serializer {
+	type Ok = bool;
+	type Error = Error;
+
+	type SerializeSeq = Impossible<bool, Error>;
+	type SerializeTuple = Impossible<bool, Error>;
+	type SerializeTupleStruct = Impossible<bool, Error>;
+	type SerializeTupleVariant = Impossible<bool, Error>;
+	type SerializeMap = Impossible<bool, Error>;
+	type SerializeStruct = Impossible<bool, Error>;
+	type SerializeStructVariant = Impossible<bool, Error>;
+
+	const EXPECTED: &'static str = "a struct `Explain`";
+
+	#[inline]
+	fn serialize_newtype_struct<T>(
+		self,
+		_name: &'static str,
+		value: &T,
+	) -> Result<Self::Ok, Self::Error>
+	where
+		T: ?Sized + Serialize,
+	{
+		value.serialize(ser::primitive::bool::Serializer.wrap())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sql::Explain;
+	use ser::Serializer as _;
+
+	#[test]
+	fn full() {
+		let explain = Explain(true);
+		let serialized = explain.serialize(Serializer.wrap()).unwrap();
+		assert_eq!(explain.0, serialized);
+	}
+}
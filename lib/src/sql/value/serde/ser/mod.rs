@@ -1,3 +1,4 @@
+mod after;
 mod block;
 mod cond;
 mod constant;
@@ -7,6 +8,7 @@ mod decimal;
 mod dir;
 mod duration;
 mod edges;
+mod explain;
 mod expression;
 mod fetch;
 mod field;
@@ -3,6 +3,7 @@ use crate::sql::value::serde::ser;
 use crate::sql::Operator;
 use serde::ser::Error as _;
 use serde::ser::Impossible;
+use serde::ser::Serialize;
 
 pub(super) struct Serializer;
 
@@ -67,6 +68,28 @@ impl ser::Serializer for Serializer {
 			variant => Err(Error::custom(format!("unexpected unit variant `{name}::{variant}`"))),
 		}
 	}
+
+	#[inline]
+	fn serialize_newtype_variant<T>(
+		self,
+		name: &'static str,
+		_variant_index: u32,
+		variant: &'static str,
+		value: &T,
+	) -> Result<Self::Ok, Error>
+	where
+		T: ?Sized + Serialize,
+	{
+		match variant {
+			"Matches" => {
+				Ok(Operator::Matches(value.serialize(ser::primitive::u8::opt::Serializer.wrap())?))
+			}
+			"Knn" => Ok(Operator::Knn(value.serialize(ser::primitive::u32::Serializer.wrap())?)),
+			variant => {
+				Err(Error::custom(format!("unexpected newtype variant `{name}::{variant}`")))
+			}
+		}
+	}
 }
 
 #[cfg(test)]
@@ -215,6 +238,20 @@ mod tests {
 		assert_eq!(dir, serialized);
 	}
 
+	#[test]
+	fn matches() {
+		let dir = Operator::Matches(Some(1));
+		let serialized = dir.serialize(Serializer.wrap()).unwrap();
+		assert_eq!(dir, serialized);
+	}
+
+	#[test]
+	fn knn() {
+		let dir = Operator::Knn(10);
+		let serialized = dir.serialize(Serializer.wrap()).unwrap();
+		assert_eq!(dir, serialized);
+	}
+
 	#[test]
 	fn less_than() {
 		let dir = Operator::LessThan;
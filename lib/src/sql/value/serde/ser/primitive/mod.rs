@@ -3,5 +3,6 @@ pub mod f64;
 pub mod i64;
 pub mod u32;
 pub mod u64;
+pub mod u8;
 
 mod opt;
@@ -1 +1,2 @@
 pub mod u64;
+pub mod u8;
@@ -0,0 +1,54 @@
+use crate::err::Error;
+use crate::sql::value::serde::ser;
+use serde::ser::Impossible;
+use serde::ser::Serialize;
+
+pub struct Serializer;
+
+impl ser::Serializer for Serializer {
+	type Ok = Option<u8>;
+	type Error = Error;
+
+	type SerializeSeq = Impossible<Option<u8>, Error>;
+	type SerializeTuple = Impossible<Option<u8>, Error>;
+	type SerializeTupleStruct = Impossible<Option<u8>, Error>;
+	type SerializeTupleVariant = Impossible<Option<u8>, Error>;
+	type SerializeMap = Impossible<Option<u8>, Error>;
+	type SerializeStruct = Impossible<Option<u8>, Error>;
+	type SerializeStructVariant = Impossible<Option<u8>, Error>;
+
+	const EXPECTED: &'static str = "an `Option<u8>`";
+
+	#[inline]
+	fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+		Ok(None)
+	}
+
+	#[inline]
+	fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
+	where
+		T: ?Sized + Serialize,
+	{
+		Ok(Some(value.serialize(ser::primitive::u8::Serializer.wrap())?))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use ser::Serializer as _;
+
+	#[test]
+	fn none() {
+		let option: Option<u8> = None;
+		let serialized = option.serialize(Serializer.wrap()).unwrap();
+		assert_eq!(option, serialized);
+	}
+
+	#[test]
+	fn some() {
+		let option = Some(u8::default());
+		let serialized = option.serialize(Serializer.wrap()).unwrap();
+		assert_eq!(option, serialized);
+	}
+}
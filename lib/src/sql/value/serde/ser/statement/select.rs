@@ -1,7 +1,9 @@
 use crate::err::Error;
 use crate::sql::statements::SelectStatement;
 use crate::sql::value::serde::ser;
+use crate::sql::After;
 use crate::sql::Cond;
+use crate::sql::Explain;
 use crate::sql::Fetchs;
 use crate::sql::Fields;
 use crate::sql::Groups;
@@ -53,10 +55,12 @@ pub struct SerializeSelectStatement {
 	order: Option<Orders>,
 	limit: Option<Limit>,
 	start: Option<Start>,
+	after: Option<After>,
 	fetch: Option<Fetchs>,
 	version: Option<Version>,
 	timeout: Option<Timeout>,
 	parallel: Option<bool>,
+	explain: Option<Explain>,
 }
 
 impl serde::ser::SerializeStruct for SerializeSelectStatement {
@@ -92,6 +96,9 @@ impl serde::ser::SerializeStruct for SerializeSelectStatement {
 			"start" => {
 				self.start = value.serialize(ser::start::opt::Serializer.wrap())?;
 			}
+			"after" => {
+				self.after = value.serialize(ser::after::opt::Serializer.wrap())?;
+			}
 			"fetch" => {
 				self.fetch = value.serialize(ser::fetch::vec::opt::Serializer.wrap())?.map(Fetchs);
 			}
@@ -104,6 +111,9 @@ impl serde::ser::SerializeStruct for SerializeSelectStatement {
 			"parallel" => {
 				self.parallel = Some(value.serialize(ser::primitive::bool::Serializer.wrap())?);
 			}
+			"explain" => {
+				self.explain = value.serialize(ser::explain::opt::Serializer.wrap())?;
+			}
 			key => {
 				return Err(Error::custom(format!("unexpected field `SelectStatement::{key}`")));
 			}
@@ -123,9 +133,11 @@ impl serde::ser::SerializeStruct for SerializeSelectStatement {
 				order: self.order,
 				limit: self.limit,
 				start: self.start,
+				after: self.after,
 				fetch: self.fetch,
 				version: self.version,
 				timeout: self.timeout,
+				explain: self.explain,
 			}),
 			_ => Err(Error::custom("`SelectStatement` missing required field(s)")),
 		}
@@ -203,6 +215,16 @@ mod tests {
 		assert_eq!(value, stmt);
 	}
 
+	#[test]
+	fn with_after() {
+		let stmt = SelectStatement {
+			after: Some(Default::default()),
+			..Default::default()
+		};
+		let value: SelectStatement = stmt.serialize(Serializer.wrap()).unwrap();
+		assert_eq!(value, stmt);
+	}
+
 	#[test]
 	fn with_fetch() {
 		let stmt = SelectStatement {
@@ -232,4 +254,14 @@ mod tests {
 		let value: SelectStatement = stmt.serialize(Serializer.wrap()).unwrap();
 		assert_eq!(value, stmt);
 	}
+
+	#[test]
+	fn with_explain() {
+		let stmt = SelectStatement {
+			explain: Some(Default::default()),
+			..Default::default()
+		};
+		let value: SelectStatement = stmt.serialize(Serializer.wrap()).unwrap();
+		assert_eq!(value, stmt);
+	}
 }
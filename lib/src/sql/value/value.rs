@@ -1090,6 +1090,130 @@ impl Value {
 		}
 	}
 
+	// -----------------------------------
+	// Strict coercion of value
+	// -----------------------------------
+
+	/// Try to coerce this value to the specified `Kind`, without any lossy or implicit casts
+	pub(crate) fn coerce_to(self, kind: &Kind) -> Result<Value, Error> {
+		match kind {
+			// Any value is allowed
+			Kind::Any => Ok(self),
+			// Booleans are allowed
+			Kind::Bool if self.is_bool() => Ok(self),
+			// Integers are allowed
+			Kind::Int if self.is_int() => Ok(self),
+			// Floats which are whole numbers can be represented as integers
+			Kind::Int => match self {
+				Value::Number(Number::Float(v))
+					if v.fract() == 0.0 && v >= i64::MIN as f64 && v < i64::MAX as f64 =>
+				{
+					Ok(Value::from(v as i64))
+				}
+				Value::Number(Number::Decimal(ref v)) if v.is_integer() => match v.to_i64() {
+					Some(v) => Ok(Value::from(v)),
+					None => self.coerce_err(kind),
+				},
+				_ => self.coerce_err(kind),
+			},
+			// Floats are allowed
+			Kind::Float if self.is_float() => Ok(self),
+			// Integers which fit in the float mantissa can be represented as floats
+			Kind::Float => match self {
+				Value::Number(Number::Int(v)) if v.unsigned_abs() <= 1 << f64::MANTISSA_DIGITS => {
+					Ok(Value::from(v as f64))
+				}
+				_ => self.coerce_err(kind),
+			},
+			// Decimals are allowed
+			Kind::Decimal if self.is_decimal() => Ok(self),
+			// Integers can always be represented as decimals
+			Kind::Decimal => match self {
+				Value::Number(Number::Int(v)) => match BigDecimal::from_i64(v) {
+					Some(v) => Ok(Value::from(Number::Decimal(v))),
+					None => self.coerce_err(kind),
+				},
+				_ => self.coerce_err(kind),
+			},
+			// Any number is allowed
+			Kind::Number if self.is_number() => Ok(self),
+			// Only strings are allowed
+			Kind::String if self.is_strand() => Ok(self),
+			// Only datetimes are allowed
+			Kind::Datetime if self.is_datetime() => Ok(self),
+			// Only durations are allowed
+			Kind::Duration if matches!(self, Value::Duration(_)) => Ok(self),
+			// Only objects are allowed
+			Kind::Object if self.is_object() => Ok(self),
+			// Only geometry points are allowed
+			Kind::Point if matches!(self, Value::Geometry(Geometry::Point(_))) => Ok(self),
+			// Only bytes are allowed
+			Kind::Bytes if matches!(self, Value::Bytes(_)) => Ok(self),
+			// Only uuids are allowed
+			Kind::Uuid if self.is_uuid() => Ok(self),
+			// Records are allowed if correct type
+			Kind::Record(t) if t.is_empty() && self.is_thing() => Ok(self),
+			Kind::Record(t) if self.is_record_type(t) => Ok(self),
+			// Geometries are allowed if correct type
+			Kind::Geometry(t) if t.is_empty() && self.is_geometry() => Ok(self),
+			Kind::Geometry(t) if self.is_geometry_type(t) => Ok(self),
+			// Optional values can be empty
+			Kind::Option(k) => match self {
+				Value::None | Value::Null => Ok(Value::None),
+				v => v.coerce_to(k),
+			},
+			// The first type which the value conforms to is used
+			Kind::Either(k) => {
+				for k in k {
+					if let Ok(v) = self.clone().coerce_to(k) {
+						return Ok(v);
+					}
+				}
+				self.coerce_err(kind)
+			}
+			// Arrays are checked recursively
+			Kind::Array(k, l) if self.is_array() => self.coerce_to_array_type(kind, k, l),
+			// Sets are checked recursively, once duplicates are removed
+			Kind::Set(k, l) => match self {
+				Value::Array(v) => Value::from(v.uniq()).coerce_to_array_type(kind, k, l),
+				_ => self.coerce_err(kind),
+			},
+			// Anything else raises an error
+			_ => self.coerce_err(kind),
+		}
+	}
+
+	/// Try to coerce each value in this `Array` to the specified `Kind`, checking the length
+	fn coerce_to_array_type(
+		self,
+		kind: &Kind,
+		item: &Kind,
+		size: &Option<u64>,
+	) -> Result<Value, Error> {
+		match self {
+			// The array is longer than allowed
+			Value::Array(ref v) if size.map_or(false, |l| v.len() as u64 > l) => {
+				self.coerce_err(kind)
+			}
+			// Each value in the array must conform
+			Value::Array(v) => v
+				.into_iter()
+				.map(|v| v.coerce_to(item))
+				.collect::<Result<Array, Error>>()
+				.map(Value::from),
+			// Anything else raises an error
+			_ => self.coerce_err(kind),
+		}
+	}
+
+	/// Returns an error for a value which does not conform to the specified `Kind`
+	fn coerce_err<T>(self, kind: &Kind) -> Result<T, Error> {
+		Err(Error::CoerceTo {
+			from: self,
+			into: kind.to_string().into(),
+		})
+	}
+
 	/// Try to convert this value to an `i64`
 	pub(crate) fn convert_to_i64(self) -> Result<i64, Error> {
 		match self {
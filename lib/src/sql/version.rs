@@ -2,12 +2,21 @@ use crate::sql::comment::shouldbespace;
 use crate::sql::datetime::{datetime, Datetime};
 use crate::sql::error::IResult;
 use nom::bytes::complete::tag_no_case;
+use nom::character::complete::char;
+use nom::combinator::opt;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 pub struct Version(pub Datetime);
 
+impl Version {
+	/// Convert the Version to a nanosecond timestamp
+	pub(crate) fn to_u64(&self) -> u64 {
+		u64::try_from(self.0.timestamp_nanos()).unwrap_or_default()
+	}
+}
+
 impl fmt::Display for Version {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		write!(f, "VERSION {}", self.0)
@@ -17,6 +26,7 @@ impl fmt::Display for Version {
 pub fn version(i: &str) -> IResult<&str, Version> {
 	let (i, _) = tag_no_case("VERSION")(i)?;
 	let (i, _) = shouldbespace(i)?;
+	let (i, _) = opt(char('d'))(i)?;
 	let (i, v) = datetime(i)?;
 	Ok((i, Version(v)))
 }
@@ -35,4 +45,14 @@ mod tests {
 		assert_eq!(out, Version(Datetime::try_from("2020-01-01T00:00:00Z").unwrap()));
 		assert_eq!("VERSION '2020-01-01T00:00:00Z'", format!("{}", out));
 	}
+
+	#[test]
+	fn version_statement_prefixed() {
+		let sql = "VERSION d\"2020-01-01T00:00:00Z\"";
+		let res = version(sql);
+		assert!(res.is_ok());
+		let out = res.unwrap().1;
+		assert_eq!(out, Version(Datetime::try_from("2020-01-01T00:00:00Z").unwrap()));
+		assert_eq!("VERSION '2020-01-01T00:00:00Z'", format!("{}", out));
+	}
 }
@@ -1,10 +1,13 @@
 #[allow(unused_imports, dead_code)]
 mod api_integration {
+	use futures::StreamExt;
 	use serde::Deserialize;
 	use serde::Serialize;
 	use serde_json::json;
 	use std::borrow::Cow;
 	use std::ops::Bound;
+	use std::time::Duration;
+	use surrealdb::dbs::Action;
 	use surrealdb::error::Api as ApiError;
 	use surrealdb::error::Db as DbError;
 	use surrealdb::opt::auth::Database;
@@ -72,6 +75,7 @@ mod api_integration {
 		}
 
 		include!("api/mod.rs");
+		include!("api/live.rs");
 		include!("api/auth.rs");
 	}
 
@@ -114,6 +118,8 @@ mod api_integration {
 		}
 
 		include!("api/mod.rs");
+		include!("api/live.rs");
+		include!("api/auth.rs");
 		include!("api/backup.rs");
 	}
 
@@ -129,6 +135,8 @@ mod api_integration {
 		}
 
 		include!("api/mod.rs");
+		include!("api/live.rs");
+		include!("api/auth.rs");
 		include!("api/backup.rs");
 	}
 
@@ -143,6 +151,8 @@ mod api_integration {
 		}
 
 		include!("api/mod.rs");
+		include!("api/live.rs");
+		include!("api/auth.rs");
 		include!("api/backup.rs");
 	}
 
@@ -157,6 +167,8 @@ mod api_integration {
 		}
 
 		include!("api/mod.rs");
+		include!("api/live.rs");
+		include!("api/auth.rs");
 		include!("api/backup.rs");
 	}
 
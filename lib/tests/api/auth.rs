@@ -1,5 +1,5 @@
 // Auth tests
-// Supported by both HTTP and WS protocols
+// Supported by the HTTP and WS protocols, and by embedded engines
 
 #[tokio::test]
 async fn invalidate() {
@@ -44,7 +44,7 @@ async fn signin_ns() {
 	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
 	let user = Ulid::new().to_string();
 	let pass = "password123";
-	let sql = format!("DEFINE LOGIN {user} ON NAMESPACE PASSWORD '{pass}'");
+	let sql = format!("DEFINE USER {user} ON NAMESPACE PASSWORD '{pass}' ROLES OWNER");
 	let response = db.query(sql).await.unwrap();
 	response.check().unwrap();
 	db.signin(Namespace {
@@ -63,7 +63,7 @@ async fn signin_db() {
 	db.use_ns(NS).use_db(&database).await.unwrap();
 	let user = Ulid::new().to_string();
 	let pass = "password123";
-	let sql = format!("DEFINE LOGIN {user} ON DATABASE PASSWORD '{pass}'");
+	let sql = format!("DEFINE USER {user} ON DATABASE PASSWORD '{pass}' ROLES OWNER");
 	let response = db.query(sql).await.unwrap();
 	response.check().unwrap();
 	db.signin(Database {
@@ -123,7 +123,7 @@ async fn authenticate() {
 	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
 	let user = Ulid::new().to_string();
 	let pass = "password123";
-	let sql = format!("DEFINE LOGIN {user} ON NAMESPACE PASSWORD '{pass}'");
+	let sql = format!("DEFINE USER {user} ON NAMESPACE PASSWORD '{pass}' ROLES OWNER");
 	let response = db.query(sql).await.unwrap();
 	response.check().unwrap();
 	let token = db
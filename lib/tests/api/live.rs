@@ -0,0 +1,33 @@
+// Tests for live queries, which are not supported by the HTTP protocol
+
+#[tokio::test]
+async fn live_select_table() {
+	let db = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	let table = "user";
+	let mut users = db.live(table).await.unwrap();
+	// Create a record
+	let _: Option<RecordId> = db.create((table, "john")).await.unwrap();
+	let notification = tokio::time::timeout(Duration::from_secs(1), users.next()).await.unwrap().unwrap();
+	assert_eq!(notification.id, *users.id());
+	assert_eq!(notification.action, Action::Create);
+	// Update the record
+	let _: Option<RecordId> = db.update((table, "john")).content(Record { name: "John Doe" }).await.unwrap();
+	let notification = tokio::time::timeout(Duration::from_secs(1), users.next()).await.unwrap().unwrap();
+	assert_eq!(notification.action, Action::Update);
+	assert_eq!(notification.result.pick(&["name".into()]), Value::from("John Doe"));
+	// Delete the record
+	let _: Option<RecordId> = db.delete((table, "john")).await.unwrap();
+	let notification = tokio::time::timeout(Duration::from_secs(1), users.next()).await.unwrap().unwrap();
+	assert_eq!(notification.action, Action::Delete);
+	assert_eq!(notification.result, Value::from(thing("user:john").unwrap()));
+}
+
+#[tokio::test]
+async fn kill_live_select() {
+	let db = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	let users = db.live("user").await.unwrap();
+	let id = users.id().clone();
+	db.kill(id).await.unwrap();
+}
@@ -93,6 +93,26 @@ async fn query_chaining() {
     response.check().unwrap();
 }
 
+#[tokio::test]
+async fn query_stream() {
+	let db = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	let mut stream = db
+		.query("CREATE user:one, user:two, user:three")
+		.query("SELECT VALUE id FROM user")
+		.stream()
+		.await
+		.unwrap();
+	let mut records = Vec::new();
+	while let Some(result) = stream.next().await {
+		records.push(result.unwrap());
+	}
+	assert_eq!(records.len(), 6);
+	let selected: Vec<_> = records.into_iter().filter(|(i, _)| *i == 1).map(|(_, v)| v).collect();
+	assert_eq!(selected.len(), 3);
+	assert!(selected.contains(&thing("user:two").unwrap().into()));
+}
+
 #[tokio::test]
 async fn create_record_no_id() {
 	let db = new_db().await;
@@ -0,0 +1,120 @@
+mod parse;
+use parse::Parse;
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::Value;
+
+#[tokio::test]
+async fn table_audit_trail() -> Result<(), Error> {
+	let sql = "
+		DEFINE TABLE person AUDIT;
+		CREATE person:test SET name = 'Tobie';
+		UPDATE person:test SET name = 'Jaime';
+		DELETE person:test;
+		CREATE other:test SET name = 'Tobie';
+		SHOW AUDIT FOR TABLE person SINCE '2000-01-01T00:00:00Z';
+		SHOW AUDIT FOR TABLE person SINCE '2000-01-01T00:00:00Z' LIMIT 1;
+		SHOW AUDIT FOR TABLE other SINCE '2000-01-01T00:00:00Z';
+		INFO FOR DB;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 9);
+	//
+	for _ in 0..5 {
+		let _ = res.remove(0).result?;
+	}
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[person:test, person:test, person:test]");
+	assert_eq!(tmp.pick(&["id".into()]), val);
+	let val = Value::parse("['CREATE', 'UPDATE', 'DELETE']");
+	assert_eq!(tmp.pick(&["action".into()]), val);
+	let val = Value::parse(
+		"[
+			NONE,
+			{ id: person:test, name: 'Tobie' },
+			{ id: person:test, name: 'Jaime' }
+		]",
+	);
+	assert_eq!(tmp.pick(&["before".into()]), val);
+	let val = Value::parse(
+		"[
+			{ id: person:test, name: 'Tobie' },
+			{ id: person:test, name: 'Jaime' },
+			NONE
+		]",
+	);
+	assert_eq!(tmp.pick(&["after".into()]), val);
+	let val = Value::parse(
+		"[{ op: 'change', path: '/name', value: '@@ -1,5 +1,5 @@\n-Tobi\n+Jaim\n e\n' }]",
+	);
+	assert_eq!(tmp.pick(&["patch".into()]).pick(&[1.into()]), val);
+	let val = Value::parse("['KV', 'KV', 'KV']");
+	assert_eq!(tmp.pick(&["level".into()]), val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("['CREATE']");
+	assert_eq!(tmp.pick(&["action".into()]), val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			az: {},
+			dl: {},
+			dt: {},
+			fc: {},
+			pa: {},
+			sc: {},
+			tb: {
+				other: 'DEFINE TABLE other SCHEMALESS PERMISSIONS NONE',
+				person: 'DEFINE TABLE person SCHEMALESS AUDIT'
+			},
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn table_audit_trail_is_kept() -> Result<(), Error> {
+	let sql = "
+		DEFINE TABLE person AUDIT;
+		BEGIN TRANSACTION;
+		CREATE person:test SET name = 'Tobie';
+		UPDATE person:test SET name = 'Jaime';
+		UPDATE person:test SET name = 'Tobie';
+		COMMIT TRANSACTION;
+		REMOVE TABLE person;
+		DEFINE TABLE person AUDIT;
+		SHOW AUDIT FOR TABLE person SINCE '2000-01-01T00:00:00Z';
+		SHOW AUDIT FOR TABLE person SINCE 3;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 8);
+	//
+	for _ in 0..6 {
+		let _ = res.remove(0).result?;
+	}
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("['CREATE', 'UPDATE', 'UPDATE']");
+	assert_eq!(tmp.pick(&["action".into()]), val);
+	let val = Value::parse("[1, 2, 3]");
+	assert_eq!(tmp.pick(&["sequence".into()]), val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:test, name: 'Tobie' }]");
+	assert_eq!(tmp.pick(&["after".into()]), val);
+	//
+	Ok(())
+}
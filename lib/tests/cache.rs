@@ -135,3 +135,64 @@ async fn clear_transaction_cache_field() -> Result<(), Error> {
 	//
 	Ok(())
 }
+
+#[tokio::test]
+async fn clear_datastore_cache_field() -> Result<(), Error> {
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	//
+	let sql = "DEFINE FIELD test ON person TYPE option<string> VALUE 'test';";
+	let res = &mut dbs.execute(sql, &ses, None, false).await?;
+	assert!(res.remove(0).result.is_ok());
+	//
+	let sql = "CREATE person:one CONTENT { x: 0 };";
+	let res = &mut dbs.execute(sql, &ses, None, false).await?;
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				id: person:one,
+				test: 'test',
+				x: 0
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let sql = "REMOVE FIELD test ON person;";
+	let res = &mut dbs.execute(sql, &ses, None, false).await?;
+	assert!(res.remove(0).result.is_ok());
+	//
+	let sql = "CREATE person:two CONTENT { x: 0 };";
+	let res = &mut dbs.execute(sql, &ses, None, false).await?;
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				id: person:two,
+				x: 0
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let sql = "
+		BEGIN;
+		DEFINE FIELD test ON person TYPE option<string> VALUE 'test';
+		CANCEL;
+		CREATE person:three CONTENT { x: 0 };
+	";
+	let res = &mut dbs.execute(sql, &ses, None, false).await?;
+	let tmp = res.remove(1).result?;
+	let val = Value::parse(
+		"[
+			{
+				id: person:three,
+				x: 0
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
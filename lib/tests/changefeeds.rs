@@ -0,0 +1,221 @@
+mod parse;
+use parse::Parse;
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::Datetime;
+use surrealdb::sql::Value;
+
+#[tokio::test]
+async fn table_change_feeds() -> Result<(), Error> {
+	let sql = "
+		DEFINE TABLE person CHANGEFEED 1h;
+		CREATE person:test SET name = 'Tobie';
+		UPDATE person:test SET name = 'Jaime';
+		DELETE person:test;
+		CREATE other:test SET name = 'Tobie';
+		SHOW CHANGES FOR TABLE person SINCE 0;
+		SHOW CHANGES FOR TABLE person SINCE 2 LIMIT 1;
+		INFO FOR DB;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 8);
+	//
+	skip_ok(res, 5)?;
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				versionstamp: 1,
+				changes: [{ update: { id: person:test, name: 'Tobie' } }]
+			},
+			{
+				versionstamp: 2,
+				changes: [{ update: { id: person:test, name: 'Jaime' } }]
+			},
+			{
+				versionstamp: 3,
+				changes: [{ delete: { id: person:test } }]
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				versionstamp: 2,
+				changes: [{ update: { id: person:test, name: 'Jaime' } }]
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			az: {},
+			dl: {},
+			dt: {},
+			fc: {},
+			pa: {},
+			sc: {},
+			tb: {
+				other: 'DEFINE TABLE other SCHEMALESS PERMISSIONS NONE',
+				person: 'DEFINE TABLE person SCHEMALESS CHANGEFEED 1h'
+			},
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn database_change_feeds() -> Result<(), Error> {
+	let sql = "
+		DEFINE DATABASE test CHANGEFEED 1h;
+		BEGIN;
+		CREATE person:test SET name = 'Tobie';
+		CREATE other:test SET name = 'Jaime';
+		UPDATE person:test SET name = 'Jaime';
+		COMMIT;
+		BEGIN;
+		CREATE person:cancelled;
+		CANCEL;
+		SHOW CHANGES FOR TABLE person SINCE 0;
+		SHOW CHANGES FOR TABLE other SINCE 0;
+		INFO FOR NS;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 8);
+	//
+	skip_ok(res, 4)?;
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_err());
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				versionstamp: 1,
+				changes: [
+					{ update: { id: person:test, name: 'Tobie' } },
+					{ update: { id: person:test, name: 'Jaime' } }
+				]
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				versionstamp: 1,
+				changes: [{ update: { id: other:test, name: 'Jaime' } }]
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			db: { test: 'DEFINE DATABASE test CHANGEFEED 1h' },
+			nl: {},
+			nt: {},
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn change_feeds_since_timestamp() -> Result<(), Error> {
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let sql = "
+		DEFINE TABLE person CHANGEFEED 1h;
+		CREATE person:one;
+	";
+	dbs.execute(sql, &ses, None, false).await?;
+	tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+	let now = Datetime::default();
+	tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+	let sql = format!(
+		"
+		CREATE person:two;
+		SHOW CHANGES FOR TABLE person SINCE {now};
+		SHOW CHANGES FOR TABLE unknown SINCE {now};
+	"
+	);
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 3);
+	//
+	skip_ok(res, 1)?;
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				versionstamp: 2,
+				changes: [{ update: { id: person:two } }]
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::TbNotFound { .. })));
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn change_feeds_expire() -> Result<(), Error> {
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let sql = "
+		DEFINE TABLE person CHANGEFEED 50ms;
+		CREATE person:one;
+	";
+	dbs.execute(sql, &ses, None, false).await?;
+	tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+	let sql = "
+		CREATE person:two;
+		SHOW CHANGES FOR TABLE person SINCE 0;
+	";
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 2);
+	//
+	skip_ok(res, 1)?;
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				versionstamp: 2,
+				changes: [{ update: { id: person:two } }]
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+fn skip_ok(res: &mut Vec<surrealdb::dbs::Response>, skip: usize) -> Result<(), Error> {
+	for _ in 0..skip {
+		let _ = res.remove(0).result?;
+	}
+	Ok(())
+}
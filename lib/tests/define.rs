@@ -23,6 +23,7 @@ async fn define_statement_namespace() -> Result<(), Error> {
 	let val = Value::parse(
 		"{
 			ns: { test: 'DEFINE NAMESPACE test' },
+			us: {},
 		}",
 	);
 	assert_eq!(tmp, val);
@@ -76,6 +77,7 @@ async fn define_statement_function() -> Result<(), Error> {
 	let tmp = res.remove(0).result?;
 	let val = Value::parse(
 		"{
+			az: {},
 			dl: {},
 			dt: {},
 			fc: { test: 'DEFINE FUNCTION fn::test($first: string, $last: string) { RETURN $first + $last; }' },
@@ -108,6 +110,7 @@ async fn define_statement_table_drop() -> Result<(), Error> {
 	let tmp = res.remove(0).result?;
 	let val = Value::parse(
 		"{
+			az: {},
 			dl: {},
 			dt: {},
 			fc: {},
@@ -121,6 +124,44 @@ async fn define_statement_table_drop() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn define_statement_table_type() -> Result<(), Error> {
+	let sql = "
+		DEFINE TABLE person TYPE NORMAL;
+		DEFINE TABLE likes TYPE RELATION FROM person TO post | comment;
+		INFO FOR DB;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			az: {},
+			dl: {},
+			dt: {},
+			fc: {},
+			pa: {},
+			sc: {},
+			tb: {
+				likes: 'DEFINE TABLE likes SCHEMALESS TYPE RELATION IN person OUT post | comment',
+				person: 'DEFINE TABLE person SCHEMALESS TYPE NORMAL',
+			},
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn define_statement_table_schemaless() -> Result<(), Error> {
 	let sql = "
@@ -138,6 +179,7 @@ async fn define_statement_table_schemaless() -> Result<(), Error> {
 	let tmp = res.remove(0).result?;
 	let val = Value::parse(
 		"{
+			az: {},
 			dl: {},
 			dt: {},
 			fc: {},
@@ -172,6 +214,7 @@ async fn define_statement_table_schemafull() -> Result<(), Error> {
 	let tmp = res.remove(0).result?;
 	let val = Value::parse(
 		"{
+			az: {},
 			dl: {},
 			dt: {},
 			fc: {},
@@ -202,6 +245,7 @@ async fn define_statement_table_schemaful() -> Result<(), Error> {
 	let tmp = res.remove(0).result?;
 	let val = Value::parse(
 		"{
+			az: {},
 			dl: {},
 			dt: {},
 			fc: {},
@@ -927,3 +971,63 @@ async fn define_statement_index_multiple_unique_existing() -> Result<(), Error>
 	//
 	Ok(())
 }
+
+#[tokio::test]
+async fn define_statement_user() -> Result<(), Error> {
+	let sql = "
+		DEFINE USER admin ON ROOT PASSHASH 'secret' ROLES OWNER;
+		DEFINE USER editor ON NAMESPACE PASSHASH 'secret' ROLES EDITOR, VIEWER;
+		DEFINE USER viewer ON DATABASE PASSHASH 'secret';
+		DEFINE LOGIN legacy ON DATABASE PASSHASH 'secret';
+		INFO FOR KV;
+		INFO FOR NS;
+		INFO FOR DB;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 7);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			ns: { test: 'DEFINE NAMESPACE test' },
+			us: { admin: \"DEFINE USER admin ON ROOT PASSHASH 'secret' ROLES OWNER\" },
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			db: { test: 'DEFINE DATABASE test' },
+			nl: { editor: \"DEFINE USER editor ON NAMESPACE PASSHASH 'secret' ROLES EDITOR, VIEWER\" },
+			nt: {},
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let tmp = tmp.pick(&["dl".into()]);
+	let val = Value::parse(
+		"{
+			legacy: \"DEFINE USER legacy ON DATABASE PASSHASH 'secret' ROLES OWNER\",
+			viewer: \"DEFINE USER viewer ON DATABASE PASSHASH 'secret' ROLES VIEWER\",
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
@@ -0,0 +1,128 @@
+mod parse;
+use parse::Parse;
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::Value;
+
+#[tokio::test]
+async fn select_explain() -> Result<(), Error> {
+	let sql = "
+		CREATE person:tobie SET name = 'Tobie';
+		CREATE person:jaime SET name = 'Jaime';
+		SELECT * FROM person, person:tobie, person:1..5 EXPLAIN;
+		SELECT * FROM person WHERE name = 'Jaime' EXPLAIN FULL;
+		SELECT * FROM person:tobie->likes EXPLAIN;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 5);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				detail: { table: 'person' },
+				operation: 'Iterate Table',
+			},
+			{
+				detail: { thing: person:tobie },
+				operation: 'Iterate Thing',
+			},
+			{
+				detail: { range: person:1..5 },
+				operation: 'Iterate Range',
+			},
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				detail: { table: 'person' },
+				operation: 'Iterate Table',
+			},
+			{
+				detail: { count: 2, filtered: 1 },
+				operation: 'Fetch',
+			},
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				detail: { edges: person:tobie->likes },
+				operation: 'Iterate Edges',
+			},
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_explain_index() -> Result<(), Error> {
+	let sql = "
+		DEFINE INDEX uniq_name ON TABLE person COLUMNS name UNIQUE;
+		CREATE person:tobie SET name = 'Tobie';
+		CREATE person:jaime SET name = 'Jaime';
+		SELECT * FROM person WHERE name = 'Jaime' EXPLAIN;
+		SELECT * FROM person WHERE name = 'Jaime' EXPLAIN FULL;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 5);
+	//
+	for _ in 0..3 {
+		let tmp = res.remove(0).result;
+		assert!(tmp.is_ok());
+	}
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				detail: {
+					plan: { index: 'uniq_name', ranges: 1 },
+					table: 'person',
+				},
+				operation: 'Iterate Index',
+			},
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				detail: {
+					plan: { index: 'uniq_name', ranges: 1 },
+					table: 'person',
+				},
+				operation: 'Iterate Index',
+			},
+			{
+				detail: { count: 1, filtered: 0 },
+				operation: 'Fetch',
+			},
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
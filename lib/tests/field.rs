@@ -286,3 +286,69 @@ async fn field_definition_empty_nested_flexible() -> Result<(), Error> {
 	//
 	Ok(())
 }
+
+#[tokio::test]
+async fn field_definition_strict_types() -> Result<(), Error> {
+	let sql = "
+		DEFINE FIELD age ON person TYPE int;
+		DEFINE FIELD score ON person TYPE option<float>;
+		DEFINE FIELD friends ON person TYPE option<array<record<user>, 2>>;
+		CREATE person:test SET age = 18.5;
+		CREATE person:test SET age = 18, score = '4.5';
+		CREATE person:test SET age = 18, friends = [user:one, post:one];
+		CREATE person:test SET age = 18, friends = [user:one, user:two, user:three];
+		CREATE person:test SET age = 18.0, score = 4, friends = [user:one, user:two];
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 8);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "Found 18.5 for field `age`, with record `person:test`, but expected a int"
+	));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "Found '4.5' for field `score`, with record `person:test`, but expected a float"
+	));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "Found post:one for field `friends`, with record `person:test`, but expected a record<user>"
+	));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "Found [user:one, user:two, user:three] for field `friends`, with record `person:test`, but expected a array<record<user>, 2>"
+	));
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				age: 18,
+				friends: [user:one, user:two],
+				id: person:test,
+				score: 4.0,
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
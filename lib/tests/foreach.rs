@@ -0,0 +1,125 @@
+mod parse;
+use parse::Parse;
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::Value;
+
+#[tokio::test]
+async fn foreach_simple() -> Result<(), Error> {
+	let sql = "
+		FOR $test IN [1, 2, 3, 4, 5] {
+			IF $test == 2 THEN {
+				CONTINUE;
+			} END;
+			IF $test == 4 THEN {
+				BREAK;
+			} END;
+			CREATE type::thing('person', $test) SET value = $test;
+		};
+		SELECT * FROM person;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::None;
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				id: person:1,
+				value: 1,
+			},
+			{
+				id: person:3,
+				value: 3,
+			},
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn foreach_subquery() -> Result<(), Error> {
+	let sql = "
+		CREATE person:tobie SET age = 30;
+		CREATE person:jaime SET age = 15;
+		FOR $test IN (SELECT * FROM person WHERE age > 18) {
+			CREATE log SET person = $test.id;
+		};
+		SELECT person FROM log;
+		FOR $test IN 'invalid' {
+			CREATE log;
+		};
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 5);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ person: person:tobie }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::ForeachStatement { .. })));
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn foreach_in_function() -> Result<(), Error> {
+	let sql = "
+		DEFINE FUNCTION fn::total($values: array) {
+			FOR $value IN $values {
+				IF $value < 0 THEN {
+					THROW 'Negative value: ' + <string> $value;
+				} END;
+				CREATE item SET value = $value;
+			};
+			RETURN count((SELECT * FROM item));
+		};
+		RETURN fn::total([1, 2, 3]);
+		RETURN fn::total([4, -5, 6]);
+		BREAK;
+		CONTINUE;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 5);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(3);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::Thrown(e)) if e == "Negative value: -5"));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::Break)));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::Continue)));
+	//
+	Ok(())
+}
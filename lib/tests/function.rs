@@ -1413,6 +1413,78 @@ async fn function_parse_geo_bearing() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_parse_geo_bounding_box() -> Result<(), Error> {
+	let sql = r#"
+		RETURN geo::bounding_box({
+			type: 'LineString',
+			coordinates: [[0, 1], [2, 0], [1, 3]]
+		});
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 1);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			type: 'Polygon',
+			coordinates: [[ [0, 0], [0, 3], [2, 3], [2, 0], [0, 0] ]]
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_parse_geo_buffer() -> Result<(), Error> {
+	let sql = r#"
+		LET $area = geo::buffer((-0.118092, 51.509865), 100);
+		RETURN geo::contains($area, (-0.118092, 51.5104));
+		RETURN geo::contains($area, (-0.118092, 51.5110));
+		RETURN geo::area($area) > 30000 AND geo::area($area) < 31416;
+		RETURN geo::contains(geo::buffer({
+			type: 'LineString',
+			coordinates: [[-0.118092, 51.509865], [-0.108092, 51.509865]]
+		}, 100), (-0.113092, 51.5104));
+		RETURN geo::buffer((-0.118092, 51.509865), -1);
+		RETURN geo::buffer({ type: 'LineString', coordinates: [[0, 0], [1, 1], [2, 0]] }, 0);
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 7);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(true);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(false);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(true);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(true);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::InvalidArguments { .. })));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::InvalidArguments { .. })));
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_parse_geo_centroid() -> Result<(), Error> {
 	let sql = r#"
@@ -1445,6 +1517,63 @@ async fn function_parse_geo_centroid() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_parse_geo_contains() -> Result<(), Error> {
+	let sql = r#"
+		RETURN geo::contains({ type: 'Polygon', coordinates: [[ [0, 0], [2, 0], [2, 2], [0, 2], [0, 0] ]] }, (1, 1));
+		RETURN geo::contains({ type: 'Polygon', coordinates: [[ [0, 0], [2, 0], [2, 2], [0, 2], [0, 0] ]] }, (3, 1));
+		RETURN geo::contains({ type: 'Polygon', coordinates: [[ [0, 0], [2, 0], [2, 2], [0, 2], [0, 0] ]] }, { type: 'LineString', coordinates: [[0.5, 0.5], [1.5, 1.5]] });
+		RETURN geo::contains('test', (1, 1));
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 4);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(true);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(false);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(true);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::None;
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_parse_geo_convex_hull() -> Result<(), Error> {
+	let sql = r#"
+		RETURN geo::convex_hull({
+			type: 'MultiPoint',
+			coordinates: [[0, 0], [2, 0], [1, 1], [2, 2], [0, 2]]
+		});
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 1);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			type: 'Polygon',
+			coordinates: [[ [2, 0], [2, 2], [0, 2], [0, 0], [2, 0] ]]
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_parse_geo_distance() -> Result<(), Error> {
 	let sql = r#"
@@ -1471,6 +1600,157 @@ async fn function_parse_geo_distance() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_parse_geo_intersection() -> Result<(), Error> {
+	let sql = r#"
+		RETURN geo::intersection({ type: 'Polygon', coordinates: [[ [0, 0], [2, 0], [2, 2], [0, 2], [0, 0] ]] }, { type: 'Polygon', coordinates: [[ [1, 1], [3, 1], [3, 3], [1, 3], [1, 1] ]] });
+		RETURN geo::intersection({ type: 'Polygon', coordinates: [[ [0, 0], [2, 0], [2, 2], [0, 2], [0, 0] ]] }, (1, 1));
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			type: 'Polygon',
+			coordinates: [[ [2, 1], [2, 2], [1, 2], [1, 1], [2, 1] ]]
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::None;
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_parse_geo_length() -> Result<(), Error> {
+	let sql = r#"
+		RETURN geo::length({
+			type: 'LineString',
+			coordinates: [[-0.136439, 51.509865], [-73.971321, 40.776676]]
+		});
+		RETURN geo::length((-0.136439, 51.509865));
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(5562851.11270021);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(0.0);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_parse_geo_simplify() -> Result<(), Error> {
+	let sql = r#"
+		RETURN geo::simplify({
+			type: 'LineString',
+			coordinates: [[0, 0], [1, 0.01], [2, 0], [3, 1]]
+		}, 2000);
+		RETURN geo::simplify({
+			type: 'LineString',
+			coordinates: [[0, 0], [1, 0.01], [2, 0], [3, 1]]
+		}, 1000);
+		RETURN geo::simplify((0, 0), -1);
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			type: 'LineString',
+			coordinates: [ [0, 0], [2, 0], [3, 1] ]
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			type: 'LineString',
+			coordinates: [ [0, 0], [1, 0.01], [2, 0], [3, 1] ]
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::InvalidArguments { .. })));
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_parse_geo_union() -> Result<(), Error> {
+	let sql = r#"
+		RETURN geo::union({ type: 'Polygon', coordinates: [[ [0, 0], [2, 0], [2, 2], [0, 2], [0, 0] ]] }, { type: 'Polygon', coordinates: [[ [4, 0], [5, 0], [5, 1], [4, 0] ]] });
+		RETURN geo::union({ type: 'Polygon', coordinates: [[ [0, 0], [2, 0], [2, 2], [0, 2], [0, 0] ]] }, 'test');
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			type: 'MultiPolygon',
+			coordinates: [
+				[[ [2, 0], [2, 2], [0, 2], [0, 0], [2, 0] ]],
+				[[ [5, 0], [5, 1], [4, 0], [5, 0] ]]
+			]
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::None;
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_parse_geo_within_distance() -> Result<(), Error> {
+	let sql = r#"
+		RETURN geo::within_distance((-0.136439, 51.509865), (-73.971321, 40.776676), 5600000);
+		RETURN geo::within_distance((-0.136439, 51.509865), (-73.971321, 40.776676), 5500000);
+		RETURN geo::within_distance({ type: 'Polygon', coordinates: [[ [0, 0], [2, 0], [2, 2], [0, 2], [0, 0] ]] }, (1, 1), 0);
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(true);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(false);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(true);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_parse_geo_hash_encode() -> Result<(), Error> {
 	let sql = r#"
@@ -1516,6 +1796,74 @@ async fn function_parse_geo_hash_decode() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_parse_geo_wkt() -> Result<(), Error> {
+	let sql = r#"
+		RETURN geo::wkt::encode({
+			type: 'Polygon',
+			coordinates: [[ [0, 0], [2, 0], [2, 2], [0, 0] ]]
+		});
+		RETURN geo::wkt::decode('POINT (-0.118092 51.509865)');
+		RETURN geo::wkt::decode(geo::wkt::encode({
+			type: 'MultiLineString',
+			coordinates: [[ [0, 0], [2, 0] ], [ [2, 2], [0, 0] ]]
+		}));
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from("POLYGON((0 0, 2 0, 2 2, 0 0))");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			type: 'Point',
+			coordinates: [-0.118092, 51.509865]
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			type: 'MultiLineString',
+			coordinates: [[ [0, 0], [2, 0] ], [ [2, 2], [0, 0] ]]
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_parse_geo_wkb() -> Result<(), Error> {
+	let sql = r#"
+		RETURN geo::wkb::decode(geo::wkb::encode({
+			type: 'Polygon',
+			coordinates: [[ [0, 0], [2, 0], [2, 2], [0, 0] ]]
+		}));
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 1);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			type: 'Polygon',
+			coordinates: [[ [0, 0], [2, 0], [2, 2], [0, 0] ]]
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
 // --------------------------------------------------
 // is
 // --------------------------------------------------
@@ -3457,6 +3805,50 @@ async fn function_time_hour() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn function_time_max() -> Result<(), Error> {
+	let sql = r#"
+		RETURN time::max([]);
+		RETURN time::max(["1987-06-22T08:30:45Z", "1988-06-22T08:30:45Z"]);
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::None;
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("'1988-06-22T08:30:45Z'");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_time_min() -> Result<(), Error> {
+	let sql = r#"
+		RETURN time::min([]);
+		RETURN time::min(["1987-06-22T08:30:45Z", "1988-06-22T08:30:45Z"]);
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::None;
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("'1987-06-22T08:30:45Z'");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
 #[tokio::test]
 async fn function_time_minute() -> Result<(), Error> {
 	let sql = r#"
@@ -4067,3 +4459,103 @@ async fn function_type_thing() -> Result<(), Error> {
 	//
 	Ok(())
 }
+
+#[tokio::test]
+async fn function_vector_distance() -> Result<(), Error> {
+	let sql = r#"
+		RETURN vector::distance::euclidean([0, 0], [3, 4]);
+		RETURN vector::distance::manhattan([1, 2, 3], [4, 0, 3]);
+		RETURN vector::distance::euclidean([1, 2], [1, 2, 3]);
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(5.0);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(5.0);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "Incorrect arguments for function vector::distance::euclidean(). The two vectors must be of the same dimension."
+	));
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_vector_dot() -> Result<(), Error> {
+	let sql = r#"
+		RETURN vector::dot([1, 2, 3], [4, 5, 6]);
+		RETURN vector::dot([], []);
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(32.0);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(0.0);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_vector_magnitude() -> Result<(), Error> {
+	let sql = r#"
+		RETURN vector::magnitude([3, 4]);
+		RETURN vector::normalize([3, 4]);
+		RETURN vector::normalize([0, 0]);
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(5.0);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[0.6, 0.8]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[0.0, 0.0]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn function_vector_similarity_cosine() -> Result<(), Error> {
+	let sql = r#"
+		RETURN vector::similarity::cosine([1, 0], [0, 1]);
+		RETURN vector::similarity::cosine([3, 4], [6, 8]);
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(0.0);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::from(1.0);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
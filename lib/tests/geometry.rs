@@ -309,3 +309,168 @@ async fn geometry_multipolygon() -> Result<(), Error> {
 	//
 	Ok(())
 }
+
+#[tokio::test]
+async fn select_where_geo_index() -> Result<(), Error> {
+	let sql = "
+		DEFINE INDEX geo_location ON city FIELDS location GEO;
+		CREATE city:london SET location = (-0.1275, 51.5072);
+		CREATE city:paris SET location = (2.3522, 48.8566);
+		CREATE city:newyork SET location = (-74.0060, 40.7128);
+		LET $area = {
+			type: 'Polygon',
+			coordinates: [[
+				[-1.0, 51.0], [1.0, 51.0], [1.0, 52.0], [-1.0, 52.0], [-1.0, 51.0]
+			]]
+		};
+		SELECT id FROM city WHERE location INSIDE $area;
+		SELECT id FROM city WHERE location INSIDE $area EXPLAIN;
+		SELECT id FROM city WHERE geo::distance(location, (-0.1275, 51.5072)) < 400000;
+		SELECT id FROM city WHERE location <|2|> (2.0, 48.0);
+		UPDATE city:london SET location = (-3.1883, 55.9533);
+		SELECT id FROM city WHERE location INSIDE $area;
+		SELECT id FROM city WHERE location <|1|> (-3.0, 56.0);
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 12);
+	//
+	for _ in 0..5 {
+		let tmp = res.remove(0).result;
+		assert!(tmp.is_ok());
+	}
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: city:london }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				detail: {
+					plan: { index: 'geo_location', matches: 1 },
+					table: 'city',
+				},
+				operation: 'Iterate Index',
+			},
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: city:london }, { id: city:paris }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: city:paris }, { id: city:london }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: city:london }]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_where_geo_index_intersects() -> Result<(), Error> {
+	let sql = "
+		DEFINE INDEX geo_area ON park FIELDS area GEO;
+		CREATE park:hyde SET area = {
+			type: 'Polygon',
+			coordinates: [[
+				[-0.1870, 51.5025], [-0.1530, 51.5025], [-0.1530, 51.5110], [-0.1870, 51.5110], [-0.1870, 51.5025]
+			]]
+		};
+		CREATE park:central SET area = {
+			type: 'Polygon',
+			coordinates: [[
+				[-73.9819, 40.7681], [-73.9580, 40.8006], [-73.9498, 40.7969], [-73.9737, 40.7644], [-73.9819, 40.7681]
+			]]
+		};
+		SELECT id FROM park WHERE area INTERSECTS {
+			type: 'LineString',
+			coordinates: [[-0.1700, 51.4900], [-0.1700, 51.5200]]
+		};
+		SELECT id FROM park WHERE area <|1|> (-73.9, 40.7);
+		DELETE park:central;
+		SELECT id FROM park WHERE area <|1|> (-73.9, 40.7);
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 7);
+	//
+	for _ in 0..3 {
+		let tmp = res.remove(0).result;
+		assert!(tmp.is_ok());
+	}
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: park:hyde }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: park:central }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: park:hyde }]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn geo_index_errors() -> Result<(), Error> {
+	let sql = "
+		DEFINE INDEX geo_location ON city FIELDS location GEO;
+		CREATE city:london SET location = 5;
+		SELECT id FROM city WHERE location <|1|> 5;
+		INFO FOR TABLE city;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 4);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "The value '5' is not a valid geometry"
+	));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "The value '5' is not a valid geometry"
+	));
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			ev: {},
+			fd: {},
+			ft: {},
+			ix: { geo_location: 'DEFINE INDEX geo_location ON city FIELDS location GEO' },
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
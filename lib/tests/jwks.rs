@@ -0,0 +1,80 @@
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use surrealdb::dbs::{Auth, Session};
+use surrealdb::err::Error;
+use surrealdb::iam::token::Claims;
+use surrealdb::kvs::Datastore;
+
+fn token(kid: &str, secret: &[u8]) -> String {
+	let mut header = Header::new(Algorithm::HS256);
+	header.kid = Some(kid.to_owned());
+	let claims = Claims {
+		exp: Some(chrono::Utc::now().timestamp() + 3600),
+		ns: Some("test".to_owned()),
+		db: Some("test".to_owned()),
+		tk: Some("provider".to_owned()),
+		..Default::default()
+	};
+	encode(&header, &claims, &EncodingKey::from_secret(secret)).unwrap()
+}
+
+#[tokio::test]
+async fn define_token_jwks() -> Result<(), Error> {
+	let sql = r#"
+		DEFINE TOKEN provider ON DATABASE TYPE JWKS VALUE '{
+			"keys": [
+				{ "kty": "oct", "kid": "first", "alg": "HS256", "k": "Zmlyc3Qtc2VjcmV0" },
+				{ "kty": "oct", "kid": "second", "alg": "HS256", "k": "c2Vjb25kLXNlY3JldA" }
+			]
+		}';
+	"#;
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 1);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	// The key is chosen using the kid header
+	let mut ses = Session::default();
+	surrealdb::iam::verify::token(&dbs, &mut ses, &token("first", b"first-secret")).await?;
+	assert_eq!(ses.au.as_ref(), &Auth::Db("test".to_owned(), "test".to_owned()));
+	let mut ses = Session::default();
+	surrealdb::iam::verify::token(&dbs, &mut ses, &token("second", b"second-secret")).await?;
+	assert_eq!(ses.au.as_ref(), &Auth::Db("test".to_owned(), "test".to_owned()));
+	// The token must be signed with the key for its kid
+	let mut ses = Session::default();
+	let res =
+		surrealdb::iam::verify::token(&dbs, &mut ses, &token("first", b"second-secret")).await;
+	assert!(res.is_err());
+	// The kid must exist in the key set
+	let mut ses = Session::default();
+	let res = surrealdb::iam::verify::token(&dbs, &mut ses, &token("third", b"first-secret")).await;
+	assert!(matches!(res, Err(Error::InvalidAuth)));
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn define_token_jwks_sources() -> Result<(), Error> {
+	let path = std::env::temp_dir().join("surrealdb-jwks-sources-test.json");
+	let keys = r#"{ "keys": [{ "kty": "oct", "kid": "first", "alg": "HS256", "k": "Zmlyc3Qtc2VjcmV0" }] }"#;
+	std::fs::write(&path, keys).unwrap();
+	let src = format!("file://{}", path.display());
+	let sql = format!("DEFINE TOKEN provider ON DATABASE TYPE JWKS VALUE '{src}'");
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	// Key sets can not be loaded from files by default
+	let dbs = Datastore::new("memory").await?;
+	dbs.execute(&sql, &ses, None, false).await?.remove(0).result?;
+	let mut tmp = Session::default();
+	let res = surrealdb::iam::verify::token(&dbs, &mut tmp, &token("first", b"first-secret")).await;
+	assert!(matches!(res, Err(Error::InvalidAuth)));
+	// Key sets can be loaded from allowed files
+	let dbs = Datastore::new("memory").await?.with_jwks_sources(vec![src]);
+	dbs.execute(&sql, &ses, None, false).await?.remove(0).result?;
+	let mut tmp = Session::default();
+	surrealdb::iam::verify::token(&dbs, &mut tmp, &token("first", b"first-secret")).await?;
+	assert_eq!(tmp.au.as_ref(), &Auth::Db("test".to_owned(), "test".to_owned()));
+	//
+	std::fs::remove_file(&path).unwrap();
+	Ok(())
+}
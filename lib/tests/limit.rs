@@ -93,3 +93,59 @@ async fn select_limit_fetch() -> Result<(), Error> {
 	//
 	Ok(())
 }
+
+#[tokio::test]
+async fn select_limit_after() -> Result<(), Error> {
+	let sql = "
+		CREATE person:1, person:2, person:3, person:4, person:5;
+		SELECT VALUE id FROM person LIMIT 2 AFTER person:2;
+		SELECT VALUE id FROM person:1..5 LIMIT 2 AFTER person:3;
+		SELECT VALUE id FROM person:3..5 AFTER person:1;
+		SELECT VALUE id FROM person AFTER person:5;
+		SELECT VALUE id FROM person AFTER 'person';
+		SELECT VALUE id FROM person ORDER BY id DESC AFTER person:2;
+		SELECT count() FROM person GROUP ALL AFTER person:2;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 8);
+	//
+	let _ = res.remove(0).result?;
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[person:3, person:4]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[person:4]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[person:3, person:4]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "Found person but the AFTER clause must evaluate to a record id"
+	));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "The AFTER clause can not be used together with ORDER BY"
+	));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "The AFTER clause can not be used together with GROUP BY"
+	));
+	//
+	Ok(())
+}
@@ -0,0 +1,154 @@
+mod parse;
+use parse::Parse;
+use surrealdb::dbs::Action;
+use surrealdb::dbs::Notification;
+use surrealdb::dbs::QueryType;
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::Uuid;
+use surrealdb::sql::Value;
+
+async fn live(dbs: &Datastore, ses: &Session, sql: &str) -> Result<Uuid, Error> {
+	let res = &mut dbs.execute(sql, ses, None, false).await?;
+	assert_eq!(res.len(), 1);
+	let res = res.remove(0);
+	assert_eq!(res.query_type, QueryType::Live);
+	match res.result? {
+		Value::Uuid(id) => Ok(id),
+		v => panic!("expected a live query id, found {v}"),
+	}
+}
+
+#[tokio::test]
+async fn live_query_sends_notifications() -> Result<(), Error> {
+	let dbs = Datastore::new("memory").await?.with_notifications();
+	let ses = Session::for_kv().with_ns("test").with_db("test").with_rt(true);
+	let id = live(&dbs, &ses, "LIVE SELECT * FROM person").await?;
+	let sql = "
+		CREATE person:test SET name = 'Tobie';
+		UPDATE person:test SET name = 'Jaime';
+		DELETE person:test;
+	";
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let chn = dbs.notifications().unwrap();
+	//
+	let tmp = chn.try_recv().unwrap();
+	let val = Notification {
+		id: id.clone(),
+		action: Action::Create,
+		result: Value::parse("{ id: person:test, name: 'Tobie' }"),
+	};
+	assert_eq!(tmp, val);
+	//
+	let tmp = chn.try_recv().unwrap();
+	let val = Notification {
+		id: id.clone(),
+		action: Action::Update,
+		result: Value::parse("{ id: person:test, name: 'Jaime' }"),
+	};
+	assert_eq!(tmp, val);
+	//
+	let tmp = chn.try_recv().unwrap();
+	let val = Notification {
+		id: id.clone(),
+		action: Action::Delete,
+		result: Value::parse("person:test"),
+	};
+	assert_eq!(tmp, val);
+	//
+	assert!(chn.try_recv().is_err());
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn live_query_where_clause() -> Result<(), Error> {
+	let dbs = Datastore::new("memory").await?.with_notifications();
+	let ses = Session::for_kv().with_ns("test").with_db("test").with_rt(true);
+	let id = live(&dbs, &ses, "LIVE SELECT name FROM person WHERE age > 18").await?;
+	let sql = "
+		CREATE person:tobie SET name = 'Tobie', age = 30;
+		CREATE person:jaime SET name = 'Jaime', age = 10;
+		DELETE person;
+	";
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let chn = dbs.notifications().unwrap();
+	//
+	let tmp = chn.try_recv().unwrap();
+	let val = Notification {
+		id: id.clone(),
+		action: Action::Create,
+		result: Value::parse("{ name: 'Tobie' }"),
+	};
+	assert_eq!(tmp, val);
+	//
+	let tmp = chn.try_recv().unwrap();
+	let val = Notification {
+		id: id.clone(),
+		action: Action::Delete,
+		result: Value::parse("person:tobie"),
+	};
+	assert_eq!(tmp, val);
+	//
+	assert!(chn.try_recv().is_err());
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn live_query_only_sends_committed_changes() -> Result<(), Error> {
+	let dbs = Datastore::new("memory").await?.with_notifications();
+	let ses = Session::for_kv().with_ns("test").with_db("test").with_rt(true);
+	let id = live(&dbs, &ses, "LIVE SELECT * FROM person").await?;
+	let sql = "
+		BEGIN;
+		CREATE person:tobie;
+		CANCEL;
+		BEGIN;
+		CREATE person:jaime;
+		COMMIT;
+	";
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let chn = dbs.notifications().unwrap();
+	//
+	let tmp = chn.try_recv().unwrap();
+	let val = Notification {
+		id,
+		action: Action::Create,
+		result: Value::parse("{ id: person:jaime }"),
+	};
+	assert_eq!(tmp, val);
+	//
+	assert!(chn.try_recv().is_err());
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn live_query_killed() -> Result<(), Error> {
+	let dbs = Datastore::new("memory").await?.with_notifications();
+	let ses = Session::for_kv().with_ns("test").with_db("test").with_rt(true);
+	let id = live(&dbs, &ses, "LIVE SELECT * FROM person").await?;
+	let sql = format!("KILL {id}; CREATE person:test;");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp, Value::None);
+	//
+	let chn = dbs.notifications().unwrap();
+	assert!(chn.try_recv().is_err());
+	//
+	let sql = format!("KILL {id};");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert!(matches!(res.remove(0).result, Err(Error::KillStatement { .. })));
+	//
+	Ok(())
+}
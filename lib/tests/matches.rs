@@ -0,0 +1,190 @@
+mod parse;
+use parse::Parse;
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::Part;
+use surrealdb::sql::Value;
+
+#[tokio::test]
+async fn select_where_matches_using_index() -> Result<(), Error> {
+	let sql = r"
+		CREATE blog:1 SET title = 'Hello World!';
+		CREATE blog:2 SET title = 'Foo Bar';
+		DEFINE ANALYZER simple TOKENIZERS blank,class FILTERS lowercase;
+		DEFINE INDEX blog_title ON blog FIELDS title SEARCH ANALYZER simple BM25(1.2,0.75) HIGHLIGHTS;
+		SELECT id, search::highlight('<em>', '</em>', 1) AS title FROM blog WHERE title @1@ 'Hello';
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 5);
+	//
+	for _ in 0..4 {
+		let _ = res.remove(0).result?;
+	}
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				id: blog:1,
+				title: '<em>Hello</em> World!'
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_where_matches_scoring() -> Result<(), Error> {
+	let sql = r"
+		DEFINE ANALYZER simple TOKENIZERS blank,class FILTERS lowercase,snowball(english);
+		DEFINE INDEX blog_content ON blog FIELDS content SEARCH ANALYZER simple BM25;
+		CREATE blog:1 SET content = 'The quick brown fox jumps over the lazy dog';
+		CREATE blog:2 SET content = 'A fox, and another fox, were running with the foxes';
+		CREATE blog:3 SET content = 'The dog was sleeping';
+		SELECT id, search::score(1) > 0 AS positive FROM blog WHERE content @1@ 'foxes' ORDER BY id;
+		SELECT id, search::score(1) AS score FROM blog WHERE content @1@ 'fox' ORDER BY score DESC;
+		SELECT id FROM blog WHERE content @@ 'lazy dog';
+		UPDATE blog:1 SET content = 'Nothing to see here';
+		SELECT id FROM blog WHERE content @@ 'dog';
+		DELETE blog:3;
+		SELECT id FROM blog WHERE content @@ 'dog';
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 12);
+	//
+	for _ in 0..5 {
+		let _ = res.remove(0).result?;
+	}
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				id: blog:1,
+				positive: true
+			},
+			{
+				id: blog:2,
+				positive: true
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?.pick(&[Part::from("id")]);
+	let val = Value::parse("[blog:2, blog:1]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: blog:1 }]");
+	assert_eq!(tmp, val);
+	//
+	let _ = res.remove(0).result?;
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: blog:3 }]");
+	assert_eq!(tmp, val);
+	//
+	let _ = res.remove(0).result?;
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_where_matches_without_index() -> Result<(), Error> {
+	let sql = r"
+		CREATE blog:1 SET title = 'Hello World!';
+		CREATE blog:2 SET title = 'Foo Bar';
+		SELECT id FROM blog WHERE title @@ 'bar' OR title @@ 'WORLD' ORDER BY id;
+		SELECT id FROM blog WHERE title @@ 'hello bar';
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 4);
+	//
+	for _ in 0..2 {
+		let _ = res.remove(0).result?;
+	}
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: blog:1 }, { id: blog:2 }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_where_matches_highlight_unsupported() -> Result<(), Error> {
+	let sql = r"
+		DEFINE ANALYZER simple TOKENIZERS blank FILTERS lowercase;
+		DEFINE INDEX blog_title ON blog FIELDS title SEARCH ANALYZER simple BM25;
+		CREATE blog:1 SET title = 'Hello World!';
+		SELECT search::highlight('<b>', '</b>') AS title FROM blog WHERE title @@ 'hello';
+		INFO FOR DB;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 5);
+	//
+	for _ in 0..3 {
+		let _ = res.remove(0).result?;
+	}
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "Database index `blog_title` can not be used for highlighting, as it was not defined with HIGHLIGHTS"
+	));
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			az: { simple: 'DEFINE ANALYZER simple TOKENIZERS BLANK FILTERS LOWERCASE' },
+			dl: {},
+			dt: {},
+			fc: {},
+			pa: {},
+			sc: {},
+			tb: { blog: 'DEFINE TABLE blog SCHEMALESS PERMISSIONS NONE' },
+		}",
+	);
+	assert_eq!(tmp, val);
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_where_matches_reference_out_of_range() -> Result<(), Error> {
+	let sql = r"
+		DEFINE ANALYZER simple TOKENIZERS blank,class FILTERS lowercase;
+		DEFINE INDEX blog_title ON blog FIELDS title SEARCH ANALYZER simple BM25(1.2,0.75) HIGHLIGHTS;
+		CREATE blog:1 SET title = 'Hello World!';
+		SELECT id FROM blog WHERE title @256@ 'Hello';
+		SELECT id, search::score(256) AS score FROM blog WHERE title @1@ 'Hello';
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = dbs.execute(&sql, &ses, None, false).await;
+	assert!(matches!(res, Err(Error::InvalidQuery { .. })));
+	//
+	let sql = sql.replace("SELECT id FROM blog WHERE title @256@ 'Hello';", "");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 4);
+	//
+	for _ in 0..3 {
+		let _ = res.remove(0).result?;
+	}
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::InvalidArguments { .. })));
+	//
+	Ok(())
+}
@@ -0,0 +1,197 @@
+mod parse;
+use parse::Parse;
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::Value;
+
+#[tokio::test]
+async fn select_where_unique_index() -> Result<(), Error> {
+	let sql = "
+		DEFINE INDEX uniq_email ON user FIELDS email UNIQUE;
+		CREATE user:1 SET email = 'tobie@surrealdb.com';
+		CREATE user:2 SET email = 'jaime@surrealdb.com';
+		SELECT * FROM user WHERE email = 'tobie@surrealdb.com';
+		SELECT * FROM user WHERE email = $email;
+		SELECT * FROM user WHERE 'jaime@surrealdb.com' = email AND id = user:1;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let mut vars = std::collections::BTreeMap::new();
+	vars.insert(String::from("email"), Value::from("jaime@surrealdb.com"));
+	let res = &mut dbs.execute(&sql, &ses, Some(vars), false).await?;
+	assert_eq!(res.len(), 6);
+	//
+	skip_ok(res, 3)?;
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				id: user:1,
+				email: 'tobie@surrealdb.com'
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				id: user:2,
+				email: 'jaime@surrealdb.com'
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_where_index_numbers() -> Result<(), Error> {
+	let sql = "
+		DEFINE INDEX idx_age ON person FIELDS age;
+		CREATE person:1 SET age = 10;
+		CREATE person:2 SET age = 10.0;
+		CREATE person:3 SET age = <decimal> 10;
+		CREATE person:4 SET age = 20;
+		CREATE person:5 SET age = 'old';
+		CREATE person:6 SET age = NULL;
+		SELECT id FROM person WHERE age = 10 ORDER BY id;
+		SELECT id FROM person WHERE age > 10 ORDER BY id;
+		SELECT id FROM person WHERE age >= 10.5 AND age <= 20 ORDER BY id;
+		SELECT id FROM person WHERE age < 10.0 ORDER BY id;
+		SELECT id FROM person WHERE age < 'a' ORDER BY id;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 12);
+	//
+	skip_ok(res, 7)?;
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:1 }, { id: person:2 }, { id: person:3 }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:4 }, { id: person:5 }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:4 }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:6 }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[{ id: person:1 }, { id: person:2 }, { id: person:3 }, { id: person:4 }, { id: person:6 }]",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_where_compound_index() -> Result<(), Error> {
+	let sql = "
+		DEFINE INDEX idx_name ON person FIELDS first, last;
+		CREATE person:1 SET first = 'Tobie', last = 'Morgan Hitchcock';
+		CREATE person:2 SET first = 'Jaime', last = 'Morgan Hitchcock';
+		CREATE person:3 SET first = 'Tobie', last = 'Smith';
+		SELECT id FROM person WHERE last = 'Morgan Hitchcock' AND first = 'Tobie';
+		SELECT id FROM person WHERE first = 'Tobie';
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 6);
+	//
+	skip_ok(res, 4)?;
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:1 }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:1 }, { id: person:3 }]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn update_and_delete_where_index() -> Result<(), Error> {
+	let sql = "
+		DEFINE INDEX idx_status ON task FIELDS status;
+		CREATE task:1 SET status = 'open';
+		CREATE task:2 SET status = 'open';
+		CREATE task:3 SET status = 'closed';
+		UPDATE task SET status = 'closed' WHERE status = 'open' RETURN id;
+		DELETE task WHERE status = 'closed' AND id != task:3;
+		SELECT id, status FROM task WHERE status = 'closed';
+		SELECT id FROM task WHERE status = 'open';
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 8);
+	//
+	skip_ok(res, 4)?;
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: task:1 }, { id: task:2 }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: task:3, status: 'closed' }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn update_where_index_moves_records() -> Result<(), Error> {
+	let sql = "
+		DEFINE INDEX idx_age ON person FIELDS age;
+		CREATE person:1 SET age = 11;
+		CREATE person:2 SET age = 12;
+		UPDATE person SET age += 10 WHERE age > 10 RETURN age;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 4);
+	//
+	skip_ok(res, 3)?;
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ age: 21 }, { age: 22 }]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+fn skip_ok(res: &mut Vec<surrealdb::dbs::Response>, skip: usize) -> Result<(), Error> {
+	for _ in 0..skip {
+		let _ = res.remove(0).result?;
+	}
+	Ok(())
+}
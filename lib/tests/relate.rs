@@ -102,3 +102,100 @@ async fn relate_and_overwrite() -> Result<(), Error> {
 	//
 	Ok(())
 }
+
+#[tokio::test]
+async fn relate_with_table_types() -> Result<(), Error> {
+	let sql = "
+		DEFINE TABLE person TYPE NORMAL;
+		DEFINE TABLE likes TYPE RELATION IN person OUT post | comment;
+		CREATE person:tobie;
+		RELATE person:tobie->likes->post:one SET id = likes:test;
+		CREATE likes:other;
+		RELATE post:one->likes->person:tobie;
+		RELATE person:tobie->likes->person:jaime;
+		RELATE person:tobie->person->post:one;
+		UPDATE likes:test SET time = 1;
+		UPDATE likes:test SET out = car:one;
+		UPDATE likes:test SET in = NONE;
+		UPDATE likes:test MERGE { in: car:one, out: car:two };
+		DEFINE TABLE likes TYPE RELATION IN person OUT comment;
+		UPDATE likes:test SET time = 2;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 14);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::None;
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::None;
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:tobie }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				id: likes:test,
+				in: person:tobie,
+				out: post:one,
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::TableIsRelation { .. })));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::RelationCheck { dir, .. }) if dir == "in"));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::RelationCheck { dir, .. }) if dir == "out"));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::TableIsNormal { .. })));
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				id: likes:test,
+				in: person:tobie,
+				out: post:one,
+				time: 1,
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	// The records at either end of a relation can not be changed
+	for _ in 0..3 {
+		let tmp = res.remove(0).result?;
+		let val = Value::parse(
+			"[
+				{
+					id: likes:test,
+					in: person:tobie,
+					out: post:one,
+					time: 1,
+				}
+			]",
+		);
+		assert_eq!(tmp, val);
+	}
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::None;
+	assert_eq!(tmp, val);
+	// Existing relations are checked against the table definition
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::RelationCheck { dir, .. }) if dir == "out"));
+	//
+	Ok(())
+}
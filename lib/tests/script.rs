@@ -2,6 +2,8 @@
 
 mod parse;
 use parse::Parse;
+use std::time::Duration;
+use surrealdb::dbs::ScriptOptions;
 use surrealdb::dbs::Session;
 use surrealdb::err::Error;
 use surrealdb::kvs::Datastore;
@@ -190,3 +192,142 @@ async fn script_function_module_os() -> Result<(), Error> {
 	//
 	Ok(())
 }
+
+#[tokio::test]
+async fn script_function_query() -> Result<(), Error> {
+	let sql = "
+		BEGIN;
+		CREATE person:tobie SET name = 'Tobie', age = 33;
+		CREATE person:jaime SET name = 'Jaime', age = 28;
+		CREATE article:test SET
+			author = function() {
+				const person = await surrealdb.select('person:tobie');
+				return person.name;
+			},
+			adults = function() {
+				return await surrealdb.query('SELECT VALUE name FROM person WHERE age > $age', { age: 30 });
+			},
+			people = function() {
+				return await surrealdb.query('LET $names = SELECT VALUE name FROM person; RETURN array::len($names);');
+			}
+		;
+		COMMIT;
+		SELECT * FROM function() {
+			return await surrealdb.query('USE NS other');
+		};
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 4);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				id: article:test,
+				author: 'Tobie',
+				adults: ['Tobie'],
+				people: 2,
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "Problem with embedded script function. An exception occurred: Found 'USE NS other' but this statement can not be run from within an embedded script function"
+	));
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn script_function_limits() -> Result<(), Error> {
+	let sql = "
+		SELECT * FROM function() {
+			while (true) {}
+		};
+		SELECT * FROM function() {
+			return new Array(1000000).fill('SurrealDB').join('');
+		};
+	";
+	let opt = ScriptOptions::default()
+		.with_timeout(Duration::from_millis(100))
+		.with_memory_limit(1_000_000);
+	let dbs = Datastore::new("memory").await?.with_script_options(opt);
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "Problem with embedded script function. The script exceeded the maximum execution time"
+	));
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_err());
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn script_function_capabilities() -> Result<(), Error> {
+	let sql = "
+		SELECT * FROM function() {
+			const { platform } = await import('os');
+			return platform();
+		};
+		SELECT * FROM function() {
+			return fetch('https://api.example.com/data');
+		};
+		SELECT * FROM function() {
+			return fetch('https://example.com/data');
+		};
+	";
+	let opt = ScriptOptions::default()
+		.with_fetch_hosts(vec!["api.example.com".to_owned()])
+		.with_os(false);
+	let dbs = Datastore::new("memory").await?.with_script_options(opt);
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_err());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "Problem with embedded script function. An exception occurred: Fetching 'https://example.com/data' is not allowed"
+	));
+	//
+	let sql = "
+		SELECT * FROM function() {
+			return fetch('https://example.com/data');
+		};
+	";
+	let opt = ScriptOptions::default().with_fetch(false);
+	let dbs = Datastore::new("memory").await?.with_script_options(opt);
+	let res = &mut dbs.execute(sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 1);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "Problem with embedded script function. An exception occurred: 'fetch' is not defined"
+	));
+	//
+	Ok(())
+}
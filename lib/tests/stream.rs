@@ -0,0 +1,75 @@
+mod parse;
+use parse::Parse;
+use surrealdb::dbs::Chunk;
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::Value;
+
+async fn stream(dbs: &Datastore, sql: &str) -> Result<Vec<Chunk>, Error> {
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let (chn, rcv) = surrealdb::channel::new(1);
+	let (res, out) = futures::join!(dbs.execute_stream(sql, &ses, None, false, chn), async {
+		let mut out = vec![];
+		while let Ok(chunk) = rcv.recv().await {
+			out.push(chunk);
+		}
+		out
+	});
+	res?;
+	Ok(out)
+}
+
+#[tokio::test]
+async fn stream_select_records() -> Result<(), Error> {
+	let sql = "
+		CREATE person:1, person:2, person:3;
+		SELECT VALUE id FROM person;
+		SELECT VALUE id FROM person LIMIT 1 START 1;
+		SELECT VALUE id FROM person ORDER BY id DESC;
+		BEGIN;
+		SELECT VALUE id FROM person;
+		COMMIT;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let mut res = stream(&dbs, sql).await?.into_iter();
+	//
+	let Some(Chunk::Response(0, tmp)) = res.next() else {
+		panic!("expected the response of the first statement");
+	};
+	assert_eq!(tmp.result?, Value::parse("[{ id: person:1 }, { id: person:2 }, { id: person:3 }]"));
+	//
+	for id in ["person:1", "person:2", "person:3"] {
+		let Some(Chunk::Record(1, tmp)) = res.next() else {
+			panic!("expected a streamed record of the second statement");
+		};
+		assert_eq!(tmp, Value::parse(id));
+	}
+	let Some(Chunk::Response(1, tmp)) = res.next() else {
+		panic!("expected the response of the second statement");
+	};
+	assert_eq!(tmp.result?, Value::parse("[]"));
+	//
+	let Some(Chunk::Record(2, tmp)) = res.next() else {
+		panic!("expected a streamed record of the third statement");
+	};
+	assert_eq!(tmp, Value::parse("person:2"));
+	let Some(Chunk::Response(2, tmp)) = res.next() else {
+		panic!("expected the response of the third statement");
+	};
+	assert_eq!(tmp.result?, Value::parse("[]"));
+	//
+	let Some(Chunk::Response(3, tmp)) = res.next() else {
+		panic!("expected the response of the fourth statement");
+	};
+	assert_eq!(tmp.result?, Value::parse("[person:3, person:2, person:1]"));
+	//
+	let Some(Chunk::Response(4, tmp)) = res.next() else {
+		panic!("expected the response of the fifth statement");
+	};
+	assert_eq!(tmp.result?, Value::parse("[person:1, person:2, person:3]"));
+	//
+	assert!(res.next().is_none());
+	//
+	Ok(())
+}
@@ -233,6 +233,7 @@ async fn loose_mode_all_ok() -> Result<(), Error> {
 	let val = Value::parse(
 		"{
 			ns: { test: 'DEFINE NAMESPACE test' },
+			us: {},
 		}",
 	);
 	assert_eq!(tmp, val);
@@ -250,6 +251,7 @@ async fn loose_mode_all_ok() -> Result<(), Error> {
 	let tmp = res.remove(0).result?;
 	let val = Value::parse(
 		"{
+			az: {},
 			dl: {},
 			dt: {},
 			fc: {},
@@ -0,0 +1,88 @@
+mod parse;
+use parse::Parse;
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::Value;
+
+async fn sync(dbs: &Datastore, since: Option<u64>) -> Result<Vec<u8>, Error> {
+	let (snd, rcv) = surrealdb::channel::new(1);
+	let (res, out) = futures::join!(dbs.sync(since, snd), async move {
+		let mut out = Vec::new();
+		while let Ok(v) = rcv.recv().await {
+			out.extend(v);
+		}
+		out
+	});
+	res?;
+	Ok(out)
+}
+
+#[tokio::test]
+async fn sync_snapshot_and_follow() -> Result<(), Error> {
+	let src = Datastore::new("memory").await?.with_sync_log();
+	let dst = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	// Load a full snapshot
+	let sql = "CREATE person:one SET name = 'Tobie'; CREATE person:two SET name = 'Jaime';";
+	src.execute(sql, &ses, None, false).await?;
+	let data = sync(&src, None).await?;
+	let seq = dst.load(&data).await?;
+	assert_eq!(seq, Some(2));
+	let res = &mut dst.execute("SELECT * FROM person", &ses, None, false).await?;
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{ id: person:one, name: 'Tobie' },
+			{ id: person:two, name: 'Jaime' }
+		]",
+	);
+	assert_eq!(tmp, val);
+	// Follow the changes made since the snapshot
+	let sql = "UPDATE person:one SET name = 'Tobias'; DELETE person:two;";
+	src.execute(sql, &ses, None, false).await?;
+	let data = sync(&src, seq).await?;
+	let seq = dst.load(&data).await?;
+	assert_eq!(seq, Some(4));
+	let res = &mut dst.execute("SELECT * FROM person", &ses, None, false).await?;
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:one, name: 'Tobias' }]");
+	assert_eq!(tmp, val);
+	// Nothing has changed since the latest position
+	let data = sync(&src, seq).await?;
+	assert_eq!(dst.load(&data).await?, Some(4));
+	Ok(())
+}
+
+#[tokio::test]
+async fn sync_snapshot_replaces_existing_data() -> Result<(), Error> {
+	let src = Datastore::new("memory").await?;
+	let dst = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	src.execute("CREATE person:one", &ses, None, false).await?;
+	dst.execute("CREATE person:two", &ses, None, false).await?;
+	let data = sync(&src, None).await?;
+	assert_eq!(dst.load(&data).await?, Some(0));
+	let res = &mut dst.execute("SELECT * FROM person", &ses, None, false).await?;
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:one }]");
+	assert_eq!(tmp, val);
+	Ok(())
+}
+
+#[tokio::test]
+async fn sync_rejects_truncated_data() -> Result<(), Error> {
+	let src = Datastore::new("memory").await?;
+	let dst = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	src.execute("CREATE person:one", &ses, None, false).await?;
+	dst.execute("CREATE person:two", &ses, None, false).await?;
+	let mut data = sync(&src, None).await?;
+	data.truncate(data.len() - 1);
+	assert!(matches!(dst.load(&data).await, Err(Error::InvalidSync)));
+	let res = &mut dst.execute("SELECT * FROM person", &ses, None, false).await?;
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:two }]");
+	assert_eq!(tmp, val);
+	Ok(())
+}
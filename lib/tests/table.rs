@@ -104,3 +104,86 @@ async fn define_foreign_table() -> Result<(), Error> {
 	//
 	Ok(())
 }
+
+#[tokio::test]
+async fn define_foreign_table_aggregates() -> Result<(), Error> {
+	let sql = "
+		DEFINE TABLE score_by_team AS
+			SELECT
+				team,
+				math::min(score) AS min,
+				math::max(score) AS max,
+				math::variance(score) AS variance,
+				math::stddev(score) AS stddev,
+				array::distinct(tag) AS tags,
+				time::max(time) AS last
+			FROM score
+			GROUP BY team
+		;
+		CREATE score:one SET team = 'red', score = 2, tag = 'a', time = '2023-01-01T00:00:00Z';
+		CREATE score:two SET team = 'red', score = 4, tag = 'b', time = '2023-03-01T00:00:00Z';
+		CREATE score:three SET team = 'red', score = 6, tag = 'a', time = '2023-02-01T00:00:00Z';
+		SELECT * FROM score_by_team;
+		DELETE score:three;
+		DELETE score:two;
+		SELECT * FROM score_by_team;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 8);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				id: score_by_team:['red'],
+				last: '2023-03-01T00:00:00Z',
+				max: 6,
+				min: 2,
+				stddev: 2.0,
+				tags: ['a', 'b'],
+				team: 'red',
+				variance: 4.0,
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				id: score_by_team:['red'],
+				last: '2023-01-01T00:00:00Z',
+				max: 2,
+				min: 2,
+				stddev: 0.0,
+				tags: ['a'],
+				team: 'red',
+				variance: 0,
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
@@ -139,13 +139,14 @@ async fn strict_typing_defined() -> Result<(), Error> {
 		DEFINE FIELD scores ON person TYPE set<float, 5>;
 		UPDATE person:test SET age = NONE, enabled = NONE, name = NONE, scored = [1,1,2,2,3,3,4,4,5,5];
 		UPDATE person:test SET age = '18', enabled = NONE, name = NONE, scored = [1,1,2,2,3,3,4,4,5,5];
-		UPDATE person:test SET age = '18', enabled = true, name = NONE, scored = [1,1,2,2,3,3,4,4,5,5];
-		UPDATE person:test SET age = '18', enabled = true, name = 'Tobie Morgan Hitchcock', scores = [1,1,2,2,3,3,4,4,5,5];
+		UPDATE person:test SET age = 18, enabled = NONE, name = NONE, scored = [1,1,2,2,3,3,4,4,5,5];
+		UPDATE person:test SET age = 18, enabled = true, name = NONE, scored = [1,1,2,2,3,3,4,4,5,5];
+		UPDATE person:test SET age = 18, enabled = true, name = 'Tobie Morgan Hitchcock', scores = [1,1,2,2,3,3,4,4,5,5];
 	";
 	let dbs = Datastore::new("memory").await?;
 	let ses = Session::for_kv().with_ns("test").with_db("test");
 	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
-	assert_eq!(res.len(), 8);
+	assert_eq!(res.len(), 9);
 	//
 	let tmp = res.remove(0).result;
 	assert!(tmp.is_ok());
@@ -166,6 +167,12 @@ async fn strict_typing_defined() -> Result<(), Error> {
 	));
 	//
 	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "Found '18' for field `age`, with record `person:test`, but expected a int"
+	));
+	//
+	let tmp = res.remove(0).result;
 	assert!(matches!(
 		tmp.err(),
 		Some(e) if e.to_string() == "Found NONE for field `enabled`, with record `person:test`, but expected a bool | int"
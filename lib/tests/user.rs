@@ -0,0 +1,127 @@
+mod parse;
+use parse::Parse;
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::iam::signin::signin;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::{Object, Role, Value};
+
+#[tokio::test]
+async fn user_role_viewer() -> Result<(), Error> {
+	let sql = "
+		CREATE person:test SET name = 'Tobie';
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 1);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let sql = "
+		SELECT * FROM person;
+		INFO FOR DB;
+		CREATE person:other;
+		UPDATE person:test SET name = 'Jaime';
+		DELETE person:test;
+		DEFINE TABLE other;
+		REMOVE TABLE person;
+	";
+	let ses = Session::for_db("test", "test").with_rl(Role::Viewer);
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 7);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				id: person:test,
+				name: 'Tobie',
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	for _ in 0..5 {
+		let tmp = res.remove(0).result;
+		assert!(matches!(tmp, Err(Error::QueryPermissions)));
+	}
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn user_role_editor() -> Result<(), Error> {
+	let sql = "
+		CREATE person:test SET name = 'Tobie';
+		DEFINE TABLE other;
+		DEFINE USER test ON DATABASE PASSWORD 'test' ROLES VIEWER;
+		DEFINE TOKEN test ON DATABASE TYPE HS512 VALUE 'secret';
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_db("test", "test").with_rl(Role::Editor);
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 4);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::QueryPermissions)));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::QueryPermissions)));
+	//
+	Ok(())
+}
+
+fn vars(sql: &str) -> Object {
+	match Value::parse(sql) {
+		Value::Object(v) => v,
+		_ => unreachable!(),
+	}
+}
+
+#[tokio::test]
+async fn user_signin_with_role() -> Result<(), Error> {
+	let sql = "
+		DEFINE USER root ON ROOT PASSWORD 'root' ROLES EDITOR;
+		DEFINE USER test ON DATABASE PASSWORD 'test' ROLES VIEWER;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	// Sign in as the root user
+	let mut ses = Session::default();
+	let cred = vars("{ user: 'root', pass: 'root' }");
+	signin(&dbs, false, None, &mut ses, cred).await?;
+	assert!(ses.au.is_kv());
+	assert_eq!(ses.rl, Role::Editor);
+	// Sign in as the database user
+	let mut ses = Session::default();
+	let cred = vars("{ ns: 'test', db: 'test', user: 'test', pass: 'test' }");
+	signin(&dbs, false, None, &mut ses, cred).await?;
+	assert!(ses.au.is_db());
+	assert_eq!(ses.rl, Role::Viewer);
+	// Sign in with the wrong password
+	let mut ses = Session::default();
+	let cred = vars("{ user: 'root', pass: 'wrong' }");
+	let res = signin(&dbs, false, None, &mut ses, cred).await;
+	assert!(matches!(res, Err(Error::InvalidAuth)));
+	//
+	Ok(())
+}
@@ -0,0 +1,158 @@
+mod parse;
+use parse::Parse;
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::Value;
+
+#[tokio::test]
+async fn select_where_knn_using_index() -> Result<(), Error> {
+	let sql = r"
+		CREATE pts:1 SET point = [1, 2, 3, 4];
+		CREATE pts:2 SET point = [4, 5, 6, 7];
+		CREATE pts:3 SET point = [8, 9, 10, 11];
+		DEFINE INDEX mt_pts ON pts FIELDS point VECTOR DIMENSION 4 DIST EUCLIDEAN;
+		SELECT id FROM pts WHERE point <|2|> [2, 3, 4, 5];
+		SELECT id FROM pts WHERE point <|2|> [9, 9, 9, 9] AND id != pts:3;
+		UPDATE pts:3 SET point = [2, 3, 4, 5];
+		SELECT id FROM pts WHERE point <|1|> [2, 3, 4, 5];
+		DELETE pts:3;
+		SELECT id FROM pts WHERE point <|1|> [2, 3, 4, 5];
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 10);
+	//
+	for _ in 0..4 {
+		let _ = res.remove(0).result?;
+	}
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: pts:1 }, { id: pts:2 }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: pts:2 }]");
+	assert_eq!(tmp, val);
+	//
+	let _ = res.remove(0).result?;
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: pts:3 }]");
+	assert_eq!(tmp, val);
+	//
+	let _ = res.remove(0).result?;
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: pts:1 }]");
+	assert_eq!(tmp, val);
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_where_knn_without_index() -> Result<(), Error> {
+	let sql = r"
+		CREATE pts:1 SET point = [1, 2, 3, 4];
+		CREATE pts:2 SET point = [4, 5, 6, 7];
+		CREATE pts:3 SET point = [8, 9, 10, 11];
+		CREATE pts:4 SET point = 'none';
+		SELECT id FROM pts WHERE point <|2|> [2, 3, 4, 5];
+		SELECT id FROM pts WHERE point <|2|> [9, 9, 9, 9] AND id != pts:3;
+		SELECT id FROM pts WHERE point <|1|> [2, 3, 4, 5] EXPLAIN;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 7);
+	//
+	for _ in 0..4 {
+		let _ = res.remove(0).result?;
+	}
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: pts:1 }, { id: pts:2 }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: pts:2 }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				detail: { table: 'pts' },
+				operation: 'Iterate Table',
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_where_knn_cosine() -> Result<(), Error> {
+	let sql = r"
+		DEFINE INDEX mt_pts ON pts FIELDS point VECTOR DIMENSION 2 DIST COSINE;
+		CREATE pts:1 SET point = [1, 0];
+		CREATE pts:2 SET point = [10, 1];
+		CREATE pts:3 SET point = [0, 1];
+		SELECT id FROM pts WHERE point <|2|> [1, 0.2];
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 5);
+	//
+	for _ in 0..4 {
+		let _ = res.remove(0).result?;
+	}
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: pts:2 }, { id: pts:1 }]");
+	assert_eq!(tmp, val);
+	Ok(())
+}
+
+#[tokio::test]
+async fn vector_index_errors() -> Result<(), Error> {
+	let sql = r"
+		DEFINE INDEX mt_pts ON pts FIELDS point VECTOR DIMENSION 3;
+		CREATE pts:1 SET point = [1, 2];
+		CREATE pts:2 SET point = [1, 'two', 3];
+		SELECT id FROM pts WHERE other <|2|> [1, 2, 3];
+		INFO FOR TABLE pts;
+	";
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 5);
+	//
+	let _ = res.remove(0).result?;
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "Incorrect vector dimension (2). Expected a vector of 3 dimension."
+	));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp.err(),
+		Some(e) if e.to_string() == "The value '[1, 'two', 3]' is not a valid vector"
+	));
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			ev: {},
+			fd: {},
+			ft: {},
+			ix: { mt_pts: 'DEFINE INDEX mt_pts ON pts FIELDS point VECTOR DIMENSION 3 DIST EUCLIDEAN' },
+		}",
+	);
+	assert_eq!(tmp, val);
+	Ok(())
+}
@@ -0,0 +1,270 @@
+mod parse;
+use parse::Parse;
+use std::time::Duration;
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::Datetime;
+use surrealdb::sql::Value;
+
+async fn now() -> String {
+	tokio::time::sleep(Duration::from_millis(10)).await;
+	let now = Datetime::default().to_raw();
+	tokio::time::sleep(Duration::from_millis(10)).await;
+	now
+}
+
+#[tokio::test]
+async fn select_record_version() -> Result<(), Error> {
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let t0 = now().await;
+	dbs.execute("CREATE person:tobie SET name = 'Tobie'", &ses, None, false).await?;
+	let t1 = now().await;
+	dbs.execute("UPDATE person:tobie SET name = 'Jaime'", &ses, None, false).await?;
+	let t2 = now().await;
+	dbs.execute("DELETE person:tobie", &ses, None, false).await?;
+	let t3 = now().await;
+	let sql = format!(
+		"
+		SELECT * FROM person:tobie VERSION d\"{t0}\";
+		SELECT * FROM person:tobie VERSION d\"{t1}\";
+		SELECT * FROM person:tobie VERSION d\"{t2}\";
+		SELECT * FROM person:tobie VERSION d\"{t3}\";
+		SELECT * FROM person:tobie;
+	"
+	);
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 5);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:tobie, name: 'Tobie' }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:tobie, name: 'Jaime' }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_table_version() -> Result<(), Error> {
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let sql = "
+		DEFINE INDEX idx_age ON person FIELDS age;
+		CREATE person:1 SET age = 10;
+		CREATE person:2 SET age = 20;
+		CREATE person:3 SET age = 30;
+	";
+	dbs.execute(sql, &ses, None, false).await?;
+	let t1 = now().await;
+	let sql = "
+		UPDATE person:1 SET age = 40;
+		DELETE person:2;
+		CREATE person:4 SET age = 50;
+	";
+	dbs.execute(sql, &ses, None, false).await?;
+	let sql = format!(
+		"
+		SELECT * FROM person VERSION '{t1}';
+		SELECT id FROM person WHERE age > 15 VERSION '{t1}';
+		SELECT id FROM person WHERE age > 15 ORDER BY id;
+	"
+	);
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{ id: person:1, age: 10 },
+			{ id: person:2, age: 20 },
+			{ id: person:3, age: 30 }
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:2 }, { id: person:3 }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:1 }, { id: person:3 }, { id: person:4 }]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_table_version_after() -> Result<(), Error> {
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let sql = "
+		CREATE person:1 SET age = 10;
+		CREATE person:2 SET age = 20;
+		CREATE person:3 SET age = 30;
+	";
+	dbs.execute(sql, &ses, None, false).await?;
+	let t1 = now().await;
+	let sql = "
+		DELETE person:2;
+		CREATE person:4 SET age = 40;
+	";
+	dbs.execute(sql, &ses, None, false).await?;
+	let sql = format!(
+		"
+		SELECT id FROM person LIMIT 1 AFTER person:1 VERSION '{t1}';
+		SELECT id FROM person AFTER person:1;
+	"
+	);
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:2 }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:3 }, { id: person:4 }]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_range_version() -> Result<(), Error> {
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let sql = "
+		CREATE person:1 SET age = 10;
+		CREATE person:2 SET age = 20;
+		CREATE person:3 SET age = 30;
+		RELATE person:1->knows->person:2;
+	";
+	dbs.execute(sql, &ses, None, false).await?;
+	let t1 = now().await;
+	let sql = "
+		UPDATE person:1 SET age = 40;
+		DELETE person:2;
+	";
+	dbs.execute(sql, &ses, None, false).await?;
+	let sql = format!(
+		"
+		SELECT * FROM person:1..=2 VERSION '{t1}';
+		SELECT * FROM person:1..3 AFTER person:1 VERSION '{t1}';
+		SELECT * FROM person:1->knows VERSION '{t1}';
+	"
+	);
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{ id: person:1, age: 10 },
+			{ id: person:2, age: 20 }
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:2, age: 20 }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::VersionUnsupported)));
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_table_version_batches() -> Result<(), Error> {
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	// Write more versions of a single record than are fetched in one batch
+	let sql: String = (1..=1005).map(|i| format!("UPDATE counter:1 SET n = {i};")).collect();
+	dbs.execute(&sql, &ses, None, false).await?;
+	dbs.execute("CREATE counter:2 SET n = 1; CREATE counter:3 SET n = 1;", &ses, None, false)
+		.await?;
+	let t1 = now().await;
+	dbs.execute("UPDATE counter SET n += 1", &ses, None, false).await?;
+	let sql = format!("SELECT * FROM counter VERSION '{t1}'");
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 1);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{ id: counter:1, n: 1005 },
+			{ id: counter:2, n: 1 },
+			{ id: counter:3, n: 1 }
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_version_retention() -> Result<(), Error> {
+	let dbs = Datastore::new("memory").await?;
+	let ses = Session::for_kv().with_ns("test").with_db("test");
+	let sql = "
+		DEFINE TABLE person RETENTION 100ms;
+		CREATE person:tobie SET name = 'Tobie';
+	";
+	dbs.execute(sql, &ses, None, false).await?;
+	let t1 = now().await;
+	tokio::time::sleep(Duration::from_millis(200)).await;
+	dbs.execute("UPDATE person:tobie SET name = 'Jaime'", &ses, None, false).await?;
+	let t2 = now().await;
+	tokio::time::sleep(Duration::from_millis(200)).await;
+	dbs.execute("UPDATE person:tobie SET name = 'Tobie'", &ses, None, false).await?;
+	let sql = format!(
+		"
+		SELECT * FROM person:tobie VERSION '{t1}';
+		SELECT * FROM person:tobie VERSION '{t2}';
+		INFO FOR DB;
+	"
+	);
+	let res = &mut dbs.execute(&sql, &ses, None, false).await?;
+	assert_eq!(res.len(), 3);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:tobie, name: 'Jaime' }]");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"{
+			az: {},
+			dl: {},
+			dt: {},
+			fc: {},
+			pa: {},
+			sc: {},
+			tb: { person: 'DEFINE TABLE person SCHEMALESS RETENTION 100ms' },
+		}",
+	);
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
@@ -1,11 +1,23 @@
 use once_cell::sync::OnceCell;
 use std::net::SocketAddr;
+use std::time::Duration;
 
 pub static CF: OnceCell<Config> = OnceCell::new();
 
 #[derive(Clone, Debug)]
 pub struct Config {
 	pub strict: bool,
+	pub sync_log: bool,
+	pub query_log: Option<String>,
+	pub query_log_slow: Duration,
+	pub query_log_all: bool,
+	pub script_memory_limit: Option<usize>,
+	pub script_stack_size: Option<usize>,
+	pub script_timeout: Option<Duration>,
+	pub script_deny_fetch: bool,
+	pub script_fetch_hosts: Option<Vec<String>>,
+	pub script_deny_os: bool,
+	pub jwks_sources: Option<Vec<String>>,
 	pub bind: SocketAddr,
 	pub path: String,
 	pub user: String,
@@ -28,9 +40,43 @@ pub fn init(matches: &clap::ArgMatches) {
 	let key = matches.value_of("web-key").map(|v| v.to_owned());
 	// Check if database strict mode is enabled
 	let strict = matches.is_present("strict");
+	// Check if the sync log is enabled
+	let sync_log = matches.is_present("sync-log");
+	// Parse the query log options
+	let query_log = matches.value_of("query-log").map(|v| v.to_owned());
+	let query_log_slow = matches
+		.value_of("query-log-slow")
+		.and_then(|v| surrealdb::sql::Duration::try_from(v).ok())
+		.map(|v| *v)
+		.unwrap();
+	let query_log_all = matches.is_present("query-log-all");
+	// Parse the embedded script function options
+	let script_memory_limit = matches.value_of("script-memory-limit").and_then(|v| v.parse().ok());
+	let script_stack_size = matches.value_of("script-stack-size").and_then(|v| v.parse().ok());
+	let script_timeout = matches
+		.value_of("script-timeout")
+		.and_then(|v| surrealdb::sql::Duration::try_from(v).ok())
+		.map(|v| *v);
+	let script_deny_fetch = matches.is_present("script-deny-fetch");
+	let script_fetch_hosts =
+		matches.values_of("script-fetch-hosts").map(|v| v.map(|v| v.to_owned()).collect());
+	let script_deny_os = matches.is_present("script-deny-os");
+	// Parse the sources which key sets can be loaded from
+	let jwks_sources = matches.values_of("jwks-sources").map(|v| v.map(|v| v.to_owned()).collect());
 	// Store the new config object
 	let _ = CF.set(Config {
 		strict,
+		sync_log,
+		query_log,
+		query_log_slow,
+		query_log_all,
+		script_memory_limit,
+		script_stack_size,
+		script_timeout,
+		script_deny_fetch,
+		script_fetch_hosts,
+		script_deny_os,
+		jwks_sources,
 		bind,
 		path,
 		user,
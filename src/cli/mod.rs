@@ -101,6 +101,20 @@ fn key_valid(v: &str) -> Result<(), String> {
 	}
 }
 
+fn duration_valid(v: &str) -> Result<(), String> {
+	match surrealdb::sql::Duration::try_from(v) {
+		Ok(_) => Ok(()),
+		_ => Err(String::from("Provide a valid duration, for example 100ms or 1s")),
+	}
+}
+
+fn size_valid(v: &str) -> Result<(), String> {
+	match v.parse::<usize>() {
+		Ok(v) if v > 0 => Ok(()),
+		_ => Err(String::from("Provide a valid size in bytes, for example 2000000")),
+	}
+}
+
 fn log_valid(v: &str) -> Result<String, String> {
 	match v {
 		// Don't show any logs at all
@@ -244,6 +258,104 @@ pub fn init() -> ExitCode {
 					.takes_value(false)
 					.help("Whether strict mode is enabled on this database instance"),
 			)
+			.arg(
+				Arg::new("sync-log")
+					.env("SURREAL_SYNC_LOG")
+					.long("sync-log")
+					.required(false)
+					.takes_value(false)
+					.help("Whether committed writes are recorded, so that replicas can follow this database instance"),
+			)
+			.arg(
+				Arg::new("query-log")
+					.env("SURREAL_QUERY_LOG")
+					.long("query-log")
+					.takes_value(true)
+					.forbid_empty_values(true)
+					.validator(file_valid)
+					.help("Path to a file where executed statements are logged as JSON lines"),
+			)
+			.arg(
+				Arg::new("query-log-slow")
+					.env("SURREAL_QUERY_LOG_SLOW")
+					.long("query-log-slow")
+					.takes_value(true)
+					.forbid_empty_values(true)
+					.validator(duration_valid)
+					.default_value("1s")
+					.help("Statements which take at least this long are written to the query log"),
+			)
+			.arg(
+				Arg::new("query-log-all")
+					.env("SURREAL_QUERY_LOG_ALL")
+					.long("query-log-all")
+					.required(false)
+					.takes_value(false)
+					.help("Whether every statement is written to the query log, for auditing"),
+			)
+			.arg(
+				Arg::new("script-memory-limit")
+					.env("SURREAL_SCRIPT_MEMORY_LIMIT")
+					.long("script-memory-limit")
+					.takes_value(true)
+					.forbid_empty_values(true)
+					.validator(size_valid)
+					.help("The maximum memory size, in bytes, of the runtime for embedded script functions"),
+			)
+			.arg(
+				Arg::new("script-stack-size")
+					.env("SURREAL_SCRIPT_STACK_SIZE")
+					.long("script-stack-size")
+					.takes_value(true)
+					.forbid_empty_values(true)
+					.validator(size_valid)
+					.help("The maximum stack size, in bytes, of the runtime for embedded script functions"),
+			)
+			.arg(
+				Arg::new("script-timeout")
+					.env("SURREAL_SCRIPT_TIMEOUT")
+					.long("script-timeout")
+					.takes_value(true)
+					.forbid_empty_values(true)
+					.validator(duration_valid)
+					.help("The maximum time that an embedded script function can run for"),
+			)
+			.arg(
+				Arg::new("script-deny-fetch")
+					.env("SURREAL_SCRIPT_DENY_FETCH")
+					.long("script-deny-fetch")
+					.required(false)
+					.takes_value(false)
+					.help("Whether embedded script functions are denied the use of the fetch function"),
+			)
+			.arg(
+				Arg::new("script-fetch-hosts")
+					.env("SURREAL_SCRIPT_FETCH_HOSTS")
+					.long("script-fetch-hosts")
+					.takes_value(true)
+					.forbid_empty_values(true)
+					.use_value_delimiter(true)
+					.multiple_values(true)
+					.help("The hosts which embedded script functions can fetch, separated by commas"),
+			)
+			.arg(
+				Arg::new("script-deny-os")
+					.env("SURREAL_SCRIPT_DENY_OS")
+					.long("script-deny-os")
+					.required(false)
+					.takes_value(false)
+					.help("Whether embedded script functions are denied importing the os module"),
+			)
+			.arg(
+				Arg::new("jwks-sources")
+					.env("SURREAL_JWKS_SOURCES")
+					.long("jwks-sources")
+					.takes_value(true)
+					.forbid_empty_values(true)
+					.use_value_delimiter(true)
+					.multiple_values(true)
+					.help("The URLs and file paths which JWKS tokens can load key sets from, separated by commas"),
+			)
 			.arg(
 				Arg::new("log")
 					.short('l')
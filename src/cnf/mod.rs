@@ -28,6 +28,9 @@ pub const APP_ENDPOINT: &str = "https://surrealdb.com/app";
 /// How many concurrent tasks can be handled in a WebSocket
 pub const MAX_CONCURRENT_CALLS: usize = 24;
 
+/// How many records are sent in each message when streaming query results
+pub const STREAM_PAGE_SIZE: usize = 100;
+
 /// Specifies the frequency with which ping messages should be sent to the client
 pub const WEBSOCKET_PING_FREQUENCY: Duration = Duration::from_secs(5);
 
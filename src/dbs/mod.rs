@@ -1,6 +1,9 @@
 use crate::cli::CF;
 use crate::err::Error;
 use once_cell::sync::OnceCell;
+use std::fs::OpenOptions;
+use surrealdb::dbs::QueryLog;
+use surrealdb::dbs::ScriptOptions;
 use surrealdb::kvs::Datastore;
 
 pub static DB: OnceCell<Datastore> = OnceCell::new();
@@ -15,8 +18,69 @@ pub async fn init() -> Result<(), Error> {
 		true => info!(target: LOG, "Database strict mode is enabled"),
 		false => info!(target: LOG, "Database strict mode is disabled"),
 	};
+	// Log sync log options
+	if opt.sync_log {
+		info!(target: LOG, "Database sync log is enabled");
+	}
 	// Parse and setup the desired kv datastore
-	let dbs = Datastore::new(&opt.path).await?;
+	let dbs = Datastore::new(&opt.path).await?.with_notifications();
+	// Record committed writes for replicas
+	let dbs = match opt.sync_log {
+		true => dbs.with_sync_log(),
+		false => dbs,
+	};
+	// Log slow or all executed statements
+	let dbs = match &opt.query_log {
+		Some(path) => {
+			// Log query log options
+			match opt.query_log_all {
+				true => info!(target: LOG, "Query log is enabled for all statements"),
+				false => info!(
+					target: LOG,
+					"Query log is enabled for statements slower than {:?}", opt.query_log_slow
+				),
+			};
+			// Open the query log file for appending
+			let file = OpenOptions::new().create(true).append(true).open(path)?;
+			// Setup the query log
+			let log = QueryLog::new(file).with_slow(opt.query_log_slow).with_all(opt.query_log_all);
+			dbs.with_query_log(log)
+		}
+		None => dbs,
+	};
+	// Setup the embedded script function limits and capabilities
+	let mut script = ScriptOptions::default();
+	if let Some(v) = opt.script_memory_limit {
+		script = script.with_memory_limit(v);
+	}
+	if let Some(v) = opt.script_stack_size {
+		script = script.with_stack_size(v);
+	}
+	if let Some(v) = opt.script_timeout {
+		info!(target: LOG, "Embedded script functions are limited to {:?}", v);
+		script = script.with_timeout(v);
+	}
+	if opt.script_deny_fetch {
+		info!(target: LOG, "Embedded script functions are denied the fetch function");
+		script = script.with_fetch(false);
+	}
+	if let Some(v) = &opt.script_fetch_hosts {
+		info!(target: LOG, "Embedded script functions can only fetch {}", v.join(", "));
+		script = script.with_fetch_hosts(v.clone());
+	}
+	if opt.script_deny_os {
+		info!(target: LOG, "Embedded script functions are denied the os module");
+		script = script.with_os(false);
+	}
+	let dbs = dbs.with_script_options(script);
+	// Setup the sources which key sets can be loaded from
+	let dbs = match &opt.jwks_sources {
+		Some(v) => {
+			info!(target: LOG, "JWKS tokens can load key sets from {}", v.join(", "));
+			dbs.with_jwks_sources(v.clone())
+		}
+		None => dbs,
+	};
 	// Store database instance
 	let _ = DB.set(dbs);
 	// All ok
@@ -1,11 +1,8 @@
-use base64::DecodeError as Base64Error;
-use jsonwebtoken::errors::Error as JWTError;
 use reqwest::Error as ReqwestError;
 use serde_cbor::error::Error as CborError;
 use serde_json::error::Error as JsonError;
 use serde_pack::encode::Error as PackError;
 use std::io::Error as IoError;
-use std::string::FromUtf8Error as Utf8Error;
 use surrealdb::Error as SurrealError;
 use thiserror::Error;
 
@@ -56,26 +53,11 @@ impl From<Error> for String {
 	}
 }
 
-impl From<Base64Error> for Error {
-	fn from(_: Base64Error) -> Error {
-		Error::InvalidAuth
-	}
-}
-
-impl From<Utf8Error> for Error {
-	fn from(_: Utf8Error) -> Error {
-		Error::InvalidAuth
-	}
-}
-
-impl From<JWTError> for Error {
-	fn from(_: JWTError) -> Error {
-		Error::InvalidAuth
-	}
-}
-
 impl From<surrealdb::error::Db> for Error {
 	fn from(error: surrealdb::error::Db) -> Error {
-		Error::Db(error.into())
+		match error {
+			surrealdb::error::Db::InvalidAuth => Error::InvalidAuth,
+			error => Error::Db(error.into()),
+		}
 	}
 }
@@ -1,9 +1,6 @@
-pub mod base;
 pub mod clear;
-pub mod parse;
 pub mod signin;
 pub mod signup;
-pub mod token;
 pub mod verify;
 
 use crate::cli::CF;
@@ -0,0 +1,26 @@
+use once_cell::sync::Lazy;
+use surrealdb::kvs::metrics::{header, Gauge, METRICS};
+use warp::http;
+use warp::Filter;
+
+/// The number of currently connected WebSockets
+pub static CONNECTIONS: Lazy<Gauge> = Lazy::new(Gauge::default);
+
+#[allow(opaque_hidden_inferred_bound)]
+pub fn config() -> impl Filter<Extract = impl warp::Reply, Error = warp::Rejection> + Clone {
+	warp::path("metrics").and(warp::path::end()).and(warp::get()).and_then(handler)
+}
+
+async fn handler() -> Result<impl warp::Reply, warp::Rejection> {
+	// Render the datastore metrics
+	let mut val = METRICS.render();
+	// Render the server metrics
+	header(&mut val, "surrealdb_rpc_connections", "gauge", "Active WebSocket connections");
+	CONNECTIONS.render(&mut val, "surrealdb_rpc_connections");
+	// Return the response
+	Ok(warp::reply::with_header(
+		warp::reply::with_status(val, http::StatusCode::OK),
+		http::header::CONTENT_TYPE,
+		"text/plain; version=0.0.4",
+	))
+}
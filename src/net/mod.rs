@@ -7,6 +7,7 @@ mod index;
 mod input;
 mod key;
 mod log;
+mod metrics;
 mod output;
 mod params;
 mod rpc;
@@ -34,6 +35,8 @@ pub async fn init() -> Result<(), Error> {
 		.or(status::config())
 		// Health endpoint
 		.or(health::config())
+		// Metrics endpoint
+		.or(metrics::config())
 		// Signup endpoint
 		.or(signup::config())
 		// Signin endpoint
@@ -65,6 +68,9 @@ pub async fn init() -> Result<(), Error> {
 	// Trace requests
 	let net = net.with(warp::trace::request());
 
+	// Send live query notifications to WebSockets
+	tokio::spawn(rpc::notifications());
+
 	// Get local copy of options
 	let opt = CF.get().unwrap();
 
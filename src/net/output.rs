@@ -1,5 +1,6 @@
 use http::header::{HeaderValue, CONTENT_TYPE};
 use http::StatusCode;
+use hyper::Body;
 use serde::Serialize;
 
 pub enum Output {
@@ -10,6 +11,7 @@ pub enum Output {
 	Cbor(Vec<u8>), // CBOR
 	Pack(Vec<u8>), // MessagePack
 	Full(Vec<u8>), // Full type serialization
+	Ndjson(Body),  // Streamed JSON lines
 }
 
 pub fn none() -> Output {
@@ -60,6 +62,10 @@ where
 	}
 }
 
+pub fn ndjson(body: Body) -> Output {
+	Output::Ndjson(body)
+}
+
 impl warp::Reply for Output {
 	fn into_response(self) -> warp::reply::Response {
 		match self {
@@ -93,6 +99,12 @@ impl warp::Reply for Output {
 				res.headers_mut().insert(CONTENT_TYPE, con);
 				res
 			}
+			Output::Ndjson(v) => {
+				let mut res = warp::reply::Response::new(v);
+				let con = HeaderValue::from_static("application/x-ndjson");
+				res.headers_mut().insert(CONTENT_TYPE, con);
+				res
+			}
 			Output::None => StatusCode::OK.into_response(),
 			Output::Fail => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
 		}
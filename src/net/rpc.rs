@@ -2,9 +2,11 @@ use crate::cli::CF;
 use crate::cnf::MAX_CONCURRENT_CALLS;
 use crate::cnf::PKG_NAME;
 use crate::cnf::PKG_VERSION;
+use crate::cnf::STREAM_PAGE_SIZE;
 use crate::cnf::WEBSOCKET_PING_FREQUENCY;
 use crate::dbs::DB;
 use crate::err::Error;
+use crate::net::metrics::CONNECTIONS;
 use crate::net::session;
 use crate::net::LOG;
 use crate::rpc::args::Take;
@@ -20,6 +22,9 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use surrealdb::channel;
 use surrealdb::channel::Sender;
+use surrealdb::dbs::Chunk;
+use surrealdb::dbs::QueryType;
+use surrealdb::dbs::Response;
 use surrealdb::dbs::Session;
 use surrealdb::sql::Array;
 use surrealdb::sql::Object;
@@ -32,8 +37,10 @@ use warp::ws::{Message, WebSocket, Ws};
 use warp::Filter;
 
 type WebSockets = RwLock<HashMap<Uuid, Sender<Message>>>;
+type LiveQueries = RwLock<HashMap<Uuid, (Uuid, Output)>>;
 
 static WEBSOCKETS: Lazy<WebSockets> = Lazy::new(WebSockets::default);
+static LIVE_QUERIES: Lazy<LiveQueries> = Lazy::new(LiveQueries::default);
 
 #[allow(opaque_hidden_inferred_bound)]
 pub fn config() -> impl Filter<Extract = impl warp::Reply, Error = warp::Rejection> + Clone {
@@ -49,6 +56,28 @@ async fn socket(ws: WebSocket, session: Session) {
 	Rpc::serve(rpc, ws).await
 }
 
+/// Send live query notifications to the WebSockets which started the live queries
+pub async fn notifications() {
+	// Get a database reference
+	let kvs = DB.get().unwrap();
+	// Check if live query notifications are enabled
+	if let Some(chn) = kvs.notifications() {
+		// Wait for the next notification to send
+		while let Ok(notification) = chn.recv().await {
+			// Find the WebSocket which started the live query
+			let lq = LIVE_QUERIES.read().await.get(&notification.id).cloned();
+			if let Some((id, out)) = lq {
+				// Find the channel for sending messages to the WebSocket
+				let ws = WEBSOCKETS.read().await.get(&id).cloned();
+				if let Some(chn) = ws {
+					// Send the notification to the client
+					res::success(None, Value::from(notification)).send(out, chn).await;
+				}
+			}
+		}
+	}
+}
+
 pub struct Rpc {
 	session: Session,
 	format: Output,
@@ -162,6 +191,8 @@ impl Rpc {
 		trace!(target: LOG, "WebSocket {} connected", id);
 		// Store this WebSocket in the list of WebSockets
 		WEBSOCKETS.write().await.insert(id, chn);
+		// Record the active WebSocket
+		CONNECTIONS.inc();
 	}
 
 	async fn disconnected(rpc: Arc<RwLock<Rpc>>) {
@@ -171,6 +202,21 @@ impl Rpc {
 		trace!(target: LOG, "WebSocket {} disconnected", id);
 		// Remove this WebSocket from the list of WebSockets
 		WEBSOCKETS.write().await.remove(&id);
+		// Record the inactive WebSocket
+		CONNECTIONS.dec();
+		// Remove the live queries started on this WebSocket
+		let lqs: Vec<Uuid> = {
+			let mut lqs = LIVE_QUERIES.write().await;
+			let ids = lqs.iter().filter(|(_, (ws, _))| ws == &id).map(|(k, _)| k.clone()).collect();
+			lqs.retain(|_, (ws, _)| ws != &id);
+			ids
+		};
+		// Kill the live queries started on this WebSocket
+		for lq in lqs {
+			if let Err(err) = rpc.read().await.kill(lq.into()).await {
+				trace!(target: LOG, "Failed to kill live query on WebSocket {}: {}", id, err);
+			}
+		}
 	}
 
 	/// Call RPC methods from the WebSocket
@@ -266,8 +312,8 @@ impl Rpc {
 			},
 			// Setup a live query on a specific table
 			"live" => match params.needs_one() {
-				Ok(v) if v.is_table() => rpc.read().await.live(v).await,
-				Ok(v) if v.is_strand() => rpc.read().await.live(v).await,
+				Ok(v) if v.is_table() => rpc.read().await.live(v, out.clone()).await,
+				Ok(v) if v.is_strand() => rpc.read().await.live(v, out.clone()).await,
 				_ => return res::failure(id, Failure::INVALID_PARAMS).send(out, chn).await,
 			},
 			// Specify a connection-wide parameter
@@ -328,7 +374,7 @@ impl Rpc {
 			// Run a full SurrealQL query against the database
 			"query" => match params.needs_one_or_two() {
 				Ok((Value::Strand(s), o)) if o.is_none_or_null() => {
-					return match rpc.read().await.query(s).await {
+					return match rpc.read().await.query(s, out.clone()).await {
 						Ok(v) => res::success(id, v).send(out, chn).await,
 						Err(e) => {
 							res::failure(id, Failure::custom(e.to_string())).send(out, chn).await
@@ -336,7 +382,7 @@ impl Rpc {
 					};
 				}
 				Ok((Value::Strand(s), Value::Object(o))) => {
-					return match rpc.read().await.query_with(s, o).await {
+					return match rpc.read().await.query_with(s, o, out.clone()).await {
 						Ok(v) => res::success(id, v).send(out, chn).await,
 						Err(e) => {
 							res::failure(id, Failure::custom(e.to_string())).send(out, chn).await
@@ -345,6 +391,17 @@ impl Rpc {
 				}
 				_ => return res::failure(id, Failure::INVALID_PARAMS).send(out, chn).await,
 			},
+			// Run a full SurrealQL query, sending the records in pages
+			"stream" => match params.needs_one_or_two() {
+				Ok((Value::Strand(s), o)) if o.is_none_or_null() => {
+					let vars = Object::default();
+					return rpc.read().await.stream(id, s, vars, out, chn).await;
+				}
+				Ok((Value::Strand(s), Value::Object(o))) => {
+					return rpc.read().await.stream(id, s, o, out, chn).await;
+				}
+				_ => return res::failure(id, Failure::INVALID_PARAMS).send(out, chn).await,
+			},
 			_ => return res::failure(id, Failure::METHOD_NOT_FOUND).send(out, chn).await,
 		};
 		// Return the final response
@@ -461,19 +518,23 @@ impl Rpc {
 		let sql = "KILL $id";
 		// Specify the query parameters
 		let var = Some(map! {
-			String::from("id") => id,
+			String::from("id") => id.clone(),
 			=> &self.vars
 		});
 		// Execute the query on the database
 		let mut res = kvs.execute(sql, &self.session, var, opt.strict).await?;
 		// Extract the first query result
 		let res = res.remove(0).result?;
+		// Stop sending notifications for this live query
+		if let Value::Uuid(id) = id {
+			LIVE_QUERIES.write().await.remove(&id);
+		}
 		// Return the result to the client
 		Ok(res)
 	}
 
 	#[instrument(skip_all, name = "rpc live", fields(websocket=self.uuid.to_raw()))]
-	async fn live(&self, tb: Value) -> Result<Value, Error> {
+	async fn live(&self, tb: Value, out: Output) -> Result<Value, Error> {
 		// Get a database reference
 		let kvs = DB.get().unwrap();
 		// Get local copy of options
@@ -487,12 +548,23 @@ impl Rpc {
 		});
 		// Execute the query on the database
 		let mut res = kvs.execute(sql, &self.session, var, opt.strict).await?;
+		// Send notifications for the live query to this WebSocket
+		self.track(&res, out).await;
 		// Extract the first query result
 		let res = res.remove(0).result?;
 		// Return the result to the client
 		Ok(res)
 	}
 
+	/// Send notifications for any started live queries to this WebSocket
+	async fn track(&self, res: &[Response], out: Output) {
+		for r in res.iter().filter(|r| r.query_type == QueryType::Live) {
+			if let Ok(Value::Uuid(id)) = &r.result {
+				LIVE_QUERIES.write().await.insert(id.clone(), (self.uuid.clone(), out.clone()));
+			}
+		}
+	}
+
 	// ------------------------------
 	// Methods for selecting
 	// ------------------------------
@@ -682,7 +754,7 @@ impl Rpc {
 	// ------------------------------
 
 	#[instrument(skip_all, name = "rpc query", fields(websocket=self.uuid.to_raw()))]
-	async fn query(&self, sql: Strand) -> Result<impl Serialize, Error> {
+	async fn query(&self, sql: Strand, out: Output) -> Result<impl Serialize, Error> {
 		// Get a database reference
 		let kvs = DB.get().unwrap();
 		// Get local copy of options
@@ -691,12 +763,19 @@ impl Rpc {
 		let var = Some(self.vars.clone());
 		// Execute the query on the database
 		let res = kvs.execute(&sql, &self.session, var, opt.strict).await?;
+		// Send notifications for any live queries to this WebSocket
+		self.track(&res, out).await;
 		// Return the result to the client
 		Ok(res)
 	}
 
 	#[instrument(skip_all, name = "rpc query_with", fields(websocket=self.uuid.to_raw()))]
-	async fn query_with(&self, sql: Strand, mut vars: Object) -> Result<impl Serialize, Error> {
+	async fn query_with(
+		&self,
+		sql: Strand,
+		mut vars: Object,
+		out: Output,
+	) -> Result<impl Serialize, Error> {
 		// Get a database reference
 		let kvs = DB.get().unwrap();
 		// Get local copy of options
@@ -705,7 +784,88 @@ impl Rpc {
 		let var = Some(mrg! { vars.0, &self.vars });
 		// Execute the query on the database
 		let res = kvs.execute(&sql, &self.session, var, opt.strict).await?;
+		// Send notifications for any live queries to this WebSocket
+		self.track(&res, out).await;
 		// Return the result to the client
 		Ok(res)
 	}
+
+	/// Runs a query, sending the output records to the client in pages.
+	///
+	/// Each page is sent as a notification, without an `id`, and with a
+	/// `stream` field holding the id of the request. Its result contains
+	/// the index of the `query` which output the `records` in the page:
+	///
+	/// `{ "stream": 1, "result": { "query": 0, "records": [...] } }`
+	///
+	/// Once the query has completed, the response to the request is sent
+	/// with the output of each statement, as with the `query` method.
+	#[instrument(skip_all, name = "rpc stream", fields(websocket=self.uuid.to_raw()))]
+	async fn stream(
+		&self,
+		id: Option<Value>,
+		sql: Strand,
+		mut vars: Object,
+		out: Output,
+		chn: Sender<Message>,
+	) {
+		// Get a database reference
+		let kvs = DB.get().unwrap();
+		// Get local copy of options
+		let opt = CF.get().unwrap();
+		// Specify the query parameters
+		let var = Some(mrg! { vars.0, &self.vars });
+		// Create a channel for the streamed output
+		let (tx, rx) = channel::new(STREAM_PAGE_SIZE);
+		// Execute the query on the database
+		let run = kvs.execute_stream(&sql, &self.session, var, opt.strict, tx);
+		// Send the streamed records to the client in pages
+		let send = async {
+			// The responses of each statement
+			let mut res: Vec<Response> = vec![];
+			// The current page of records
+			let mut page: Vec<Value> = vec![];
+			// The statement which output the current page
+			let mut query = 0;
+			// Process each streamed chunk
+			while let Ok(chunk) = rx.recv().await {
+				// Send the page once it is complete
+				let full = match &chunk {
+					Chunk::Record(i, _) => *i != query || page.len() >= STREAM_PAGE_SIZE,
+					Chunk::Response(..) => true,
+				};
+				if full && !page.is_empty() {
+					let records = Value::from(std::mem::take(&mut page));
+					let msg = Value::from(map! {
+						String::from("query") => Value::from(query),
+						String::from("records") => records,
+					});
+					res::page(id.clone(), msg).send(out.clone(), chn.clone()).await;
+				}
+				// Store the record or response
+				match chunk {
+					Chunk::Record(i, v) => {
+						query = i;
+						page.push(v);
+					}
+					Chunk::Response(_, v) => res.push(v),
+				}
+			}
+			res
+		};
+		// Wait for the query and the pages to complete
+		let (run, res) = futures::join!(run, send);
+		// Send the final response to the client
+		match run {
+			Ok(()) => {
+				// Send notifications for any live queries to this WebSocket
+				self.track(&res, out.clone()).await;
+				// Send the responses of each statement
+				res::success(id, res).send(out, chn).await
+			}
+			Err(e) => {
+				res::failure(id, Failure::custom(Error::from(e).to_string())).send(out, chn).await
+			}
+		}
+	}
 }
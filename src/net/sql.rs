@@ -1,4 +1,5 @@
 use crate::cli::CF;
+use crate::cnf::STREAM_PAGE_SIZE;
 use crate::dbs::DB;
 use crate::err::Error;
 use crate::net::input::bytes_to_utf8;
@@ -7,7 +8,11 @@ use crate::net::params::Params;
 use crate::net::session;
 use bytes::Bytes;
 use futures::{SinkExt, StreamExt};
+use hyper::Body;
+use serde::Serialize;
 use serde_json::Value as Json;
+use std::convert::Infallible;
+use surrealdb::channel;
 use surrealdb::dbs::Response;
 use surrealdb::dbs::Session;
 use surrealdb::sql;
@@ -44,6 +49,13 @@ fn json(res: Vec<Response>) -> Json {
 	sql::to_value(res).unwrap().into()
 }
 
+fn line<T: Serialize>(val: T) -> Vec<u8> {
+	let val: Json = sql::to_value(val).unwrap().into();
+	let mut out = serde_json::to_vec(&val).unwrap();
+	out.push(b'\n');
+	out
+}
+
 async fn handler(
 	output: String,
 	sql: Bytes,
@@ -56,6 +68,10 @@ async fn handler(
 	let opt = CF.get().unwrap();
 	// Convert the received sql query
 	let sql = bytes_to_utf8(&sql)?;
+	// Stream the results as JSON lines
+	if output == "application/x-ndjson" {
+		return stream(sql, params, session, opt.strict);
+	}
 	// Execute the received sql query
 	match db.execute(sql, &session, params.parse().into(), opt.strict).await {
 		// Convert the response to JSON
@@ -74,6 +90,39 @@ async fn handler(
 	}
 }
 
+fn stream(
+	sql: &str,
+	params: Params,
+	session: Session,
+	strict: bool,
+) -> Result<output::Output, warp::Rejection> {
+	// Get a database reference
+	let db = DB.get().unwrap();
+	// Parse the received sql query
+	let ast = sql::parse(sql).map_err(|e| warp::reject::custom(Error::from(e)))?;
+	// Create a channel for the streamed output
+	let (chn, rcv) = channel::new(STREAM_PAGE_SIZE);
+	// Execute the query in the background
+	let task = tokio::spawn(async move {
+		db.process_stream(ast, &session, params.parse().into(), strict, chn).await
+	});
+	// Send each chunk of output as a line of JSON
+	let lines = rcv.map(line);
+	// Send any query set error as a final line
+	let error = futures::stream::once(task).filter_map(|res| async move {
+		match res {
+			Ok(Err(e)) => Some(line(map! {
+				"status" => "ERR".to_owned(),
+				"detail" => e.to_string(),
+			})),
+			_ => None,
+		}
+	});
+	// Stream the response body
+	let body = Body::wrap_stream(lines.chain(error).map(Ok::<_, Infallible>));
+	Ok(output::ndjson(body))
+}
+
 async fn socket(ws: WebSocket, session: Session) {
 	// Split the WebSocket connection
 	let (mut tx, mut rx) = ws.split();
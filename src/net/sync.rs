@@ -1,22 +1,107 @@
-use warp::http;
+use crate::dbs::DB;
+use crate::err::Error;
+use crate::net::output;
+use crate::net::session;
+use bytes::Bytes;
+use hyper::body::Body;
+use serde::Deserialize;
+use surrealdb::dbs::Session;
+use surrealdb::sql::Role;
 use warp::Filter;
 
+const MAX: u64 = 1024 * 1024 * 1024 * 4; // 4 GiB
+
+#[derive(Default, Deserialize, Debug, Clone)]
+struct Query {
+	pub since: Option<u64>,
+}
+
 #[allow(opaque_hidden_inferred_bound)]
 pub fn config() -> impl Filter<Extract = impl warp::Reply, Error = warp::Rejection> + Clone {
 	// Set base path
 	let base = warp::path("sync").and(warp::path::end());
 	// Set save method
-	let save = base.and(warp::get()).and_then(save);
+	let save = base.and(warp::get()).and(warp::query()).and(session::build()).and_then(save);
 	// Set load method
-	let load = base.and(warp::post()).and_then(load);
+	let load = base
+		.and(warp::post())
+		.and(warp::body::content_length_limit(MAX))
+		.and(warp::body::bytes())
+		.and(session::build())
+		.and_then(load);
 	// Specify route
 	save.or(load)
 }
 
-pub async fn load() -> Result<impl warp::Reply, warp::Rejection> {
-	Ok(warp::reply::with_status("Load", http::StatusCode::OK))
+/// Only root owners can export or replace the whole datastore
+fn allowed(session: &Session) -> bool {
+	session.au.is_kv() && session.rl == Role::Owner
+}
+
+async fn load(body: Bytes, session: Session) -> Result<impl warp::Reply, warp::Rejection> {
+	// Check the permissions
+	match allowed(&session) {
+		true => {
+			// Get the datastore reference
+			let db = DB.get().unwrap();
+			// Load the sync data into the datastore
+			match db.load(&body).await {
+				// Return the sync log position of the source
+				Ok(Some(seq)) => Ok(output::text(seq.to_string())),
+				Ok(None) => Ok(output::none()),
+				// There was an error when loading the data
+				Err(err) => Err(warp::reject::custom(Error::from(err))),
+			}
+		}
+		// There was an error with permissions
+		_ => Err(warp::reject::custom(Error::InvalidAuth)),
+	}
 }
 
-pub async fn save() -> Result<impl warp::Reply, warp::Rejection> {
-	Ok(warp::reply::with_status("Save", http::StatusCode::OK))
+async fn save(query: Query, session: Session) -> Result<impl warp::Reply, warp::Rejection> {
+	// Check the permissions
+	match allowed(&session) {
+		true => {
+			// Get the datastore reference
+			let db = DB.get().unwrap();
+			// Create a chunked response
+			let (mut chn, bdy) = Body::channel();
+			// Create a new bounded channel
+			let (snd, rcv) = surrealdb::channel::new(1);
+			// Spawn a new datastore sync
+			tokio::spawn(db.sync(query.since, snd));
+			// Process all processed values
+			tokio::spawn(async move {
+				while let Ok(v) = rcv.recv().await {
+					let _ = chn.send_data(Bytes::from(v)).await;
+				}
+			});
+			// Return the chunked body
+			Ok(warp::reply::Response::new(bdy))
+		}
+		// There was an error with permissions
+		_ => Err(warp::reject::custom(Error::InvalidAuth)),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::net::fail;
+	use warp::http::StatusCode;
+	use warp::Reply;
+
+	#[tokio::test]
+	async fn viewers_are_forbidden() {
+		let ses = Session::for_kv().with_rl(Role::Viewer);
+		// Viewers can not load sync data
+		let err = load(Bytes::new(), ses.clone()).await.err().unwrap();
+		let res = fail::recover(err).await.unwrap().into_response();
+		assert_eq!(res.status(), StatusCode::FORBIDDEN);
+		// Viewers can not save sync data
+		let err = save(Query::default(), ses).await.err().unwrap();
+		let res = fail::recover(err).await.unwrap().into_response();
+		assert_eq!(res.status(), StatusCode::FORBIDDEN);
+	}
 }
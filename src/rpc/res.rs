@@ -18,6 +18,8 @@ pub enum Output {
 pub struct Response<T> {
 	#[serde(skip_serializing_if = "Option::is_none")]
 	id: Option<Value>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	stream: Option<Value>,
 	#[serde(flatten)]
 	content: Content<T>,
 }
@@ -110,6 +112,7 @@ impl Failure {
 pub fn success<S: Serialize>(id: Option<Value>, val: S) -> Response<S> {
 	Response {
 		id,
+		stream: None,
 		content: Content::Success(val),
 	}
 }
@@ -118,6 +121,16 @@ pub fn success<S: Serialize>(id: Option<Value>, val: S) -> Response<S> {
 pub fn failure(id: Option<Value>, err: Failure) -> Response<Value> {
 	Response {
 		id,
+		stream: None,
 		content: Content::Failure(err),
 	}
 }
+
+/// Create a JSON RPC notification for a page of a streamed request
+pub fn page<S: Serialize>(stream: Option<Value>, val: S) -> Response<S> {
+	Response {
+		id: None,
+		stream,
+		content: Content::Success(val),
+	}
+}